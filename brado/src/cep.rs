@@ -0,0 +1,654 @@
+//! Utilitários para validação de Código de Endereçamento Postal (CEP).
+
+use crate::common::{
+    check_format, check_mask, get_digits, get_symbols,
+    random_element_from_vector_with_rng, to_decimal, Document, MaskError, Uf,
+    ValidationError,
+};
+use crate::docs::DocKind;
+use rand::{Rng, RngCore};
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+const CEP_SIZE: usize = 8;
+const PREFIX_SIZE: usize = 5;
+
+/// Faixas oficiais de CEP de cada Unidade Federativa, definidas pelos cinco
+/// primeiros dígitos (prefixo inicial e prefixo final, inclusivos).
+const UF_RANGES: [(Uf, u32, u32); 30] = [
+    (Uf::Sp, 1000, 19999),
+    (Uf::Rj, 20000, 28999),
+    (Uf::Es, 29000, 29999),
+    (Uf::Mg, 30000, 39999),
+    (Uf::Ba, 40000, 48999),
+    (Uf::Se, 49000, 49999),
+    (Uf::Pe, 50000, 56999),
+    (Uf::Al, 57000, 57999),
+    (Uf::Pb, 58000, 58999),
+    (Uf::Rn, 59000, 59999),
+    (Uf::Ce, 60000, 63999),
+    (Uf::Pi, 64000, 64999),
+    (Uf::Ma, 65000, 65999),
+    (Uf::Pa, 66000, 68899),
+    (Uf::Ap, 68900, 68999),
+    (Uf::Am, 69000, 69299),
+    (Uf::Rr, 69300, 69399),
+    (Uf::Am, 69400, 69899),
+    (Uf::Ac, 69900, 69999),
+    (Uf::Df, 70000, 72799),
+    (Uf::Go, 72800, 72999),
+    (Uf::Df, 73000, 73699),
+    (Uf::Go, 73700, 76799),
+    (Uf::Ro, 76800, 76999),
+    (Uf::To, 77000, 77999),
+    (Uf::Mt, 78000, 78899),
+    (Uf::Ms, 79000, 79999),
+    (Uf::Pr, 80000, 87999),
+    (Uf::Sc, 88000, 89999),
+    (Uf::Rs, 90000, 99999),
+];
+
+/// Realiza validação de CEP, máscarado ou não.
+/// Retorna `true` se o argumento `doc` for um CEP válido, caso contrário,
+/// retorna `false`.
+///
+/// ## Exemplos
+///
+/// CEPs válidos:
+/// ```
+/// use brado::cep;
+///
+/// let result = cep::validate("01310100"); // true
+/// assert!(result);
+///
+/// let result = cep::validate("01310-100"); // true
+/// assert!(result);
+/// ```
+///
+/// CEPs inválidos:
+/// ```
+/// use brado::cep;
+///
+/// let result = cep::validate("00310100"); // false
+/// assert!(!result);
+///
+/// let result = cep::validate("013101-00"); // false
+/// assert!(!result);
+/// ```
+pub fn validate(doc: &str) -> bool {
+    check(doc).is_ok()
+}
+
+/// Realiza validação de CEP, máscarado ou não, informando o motivo da falha.
+/// Retorna `Ok(())` se o argumento `doc` for um CEP válido, caso contrário,
+/// retorna o erro de validação (`ValidationError`) correspondente.
+///
+/// Além do formato, o CEP deve pertencer à faixa oficial de alguma Unidade
+/// Federativa (prefixos de 01000 a 99999).
+///
+/// ## Exemplos
+///
+/// CEPs válidos:
+/// ```
+/// use brado::cep;
+///
+/// let result = cep::check("01310100"); // Ok(())
+/// assert!(result.is_ok());
+///
+/// let result = cep::check("01310-100"); // Ok(())
+/// assert!(result.is_ok());
+/// ```
+///
+/// CEPs inválidos:
+/// ```
+/// use brado::cep;
+/// use brado::common::ValidationError;
+///
+/// let result = cep::check("00310-100");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::InvalidPrefix {
+///         found: String::from("00310"),
+///     }),
+/// );
+///
+/// let result = cep::check("0131010");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::InvalidLength {
+///         expected: 8,
+///         found: 7,
+///     }),
+/// );
+/// ```
+pub fn check(doc: &str) -> Result<(), ValidationError> {
+    let digits: Vec<u16> = check_format(doc, CEP_SIZE, is_masked, to_decimal)?;
+
+    match uf_from_digits(&digits) {
+        Some(_) => Ok(()),
+        None => Err(invalid_prefix(&digits)),
+    }
+}
+
+fn prefix(digits: &[u16]) -> u32 {
+    digits[..PREFIX_SIZE]
+        .iter()
+        .fold(0, |acc, d| acc * 10 + *d as u32)
+}
+
+fn invalid_prefix(digits: &[u16]) -> ValidationError {
+    ValidationError::InvalidPrefix {
+        found: format!("{:05}", prefix(digits)),
+    }
+}
+
+fn uf_from_digits(digits: &[u16]) -> Option<Uf> {
+    let prefix: u32 = prefix(digits);
+
+    UF_RANGES
+        .iter()
+        .find(|(_, start, end)| (*start..=*end).contains(&prefix))
+        .map(|(uf, _, _)| *uf)
+}
+
+/// Verifica se o argumento `doc` pode ser um CEP sem símbolos.
+/// Se for, retorna `true`, caso contrário, retorna `false`.
+///
+/// ## Exemplos
+///
+/// CEPs válidos:
+/// ```
+/// use brado::cep;
+///
+/// let result = cep::is_bare("01310100"); // true
+/// assert!(result);
+///
+/// let result = cep::is_bare("01310-100"); // false
+/// assert!(!result);
+/// ```
+///
+/// CEPs inválidos:
+/// ```
+/// use brado::cep;
+///
+/// let result = cep::is_bare("00310100"); // true
+/// assert!(result);
+/// ```
+pub fn is_bare(doc: &str) -> bool {
+    doc.chars().count() == CEP_SIZE
+        && get_digits(doc, to_decimal).len() == CEP_SIZE
+}
+
+/// Verifica se o argumento `doc` pode ser um CEP com símbolos.
+/// Se for, retorna `true`, caso contrário, retorna `false`.
+///
+/// ## Exemplos
+///
+/// CEPs válidos:
+/// ```
+/// use brado::cep;
+///
+/// let result = cep::is_masked("01310-100"); // true
+/// assert!(result);
+///
+/// let result = cep::is_masked("01310100"); // false
+/// assert!(!result);
+/// ```
+///
+/// CEPs inválidos:
+/// ```
+/// use brado::cep;
+///
+/// let result = cep::is_masked("00310-100"); // true
+/// assert!(result);
+/// ```
+pub fn is_masked(doc: &str) -> bool {
+    let symbols: Vec<(usize, char)> = get_symbols(doc, to_decimal);
+    let digits: Vec<u16> = get_digits(doc, to_decimal);
+
+    if symbols.len() != 1 || digits.len() != CEP_SIZE {
+        return false;
+    }
+
+    symbols[0] == (5, '-')
+}
+
+/// Aplica máscara de CEP no argumento `doc` e retorna resultado.
+/// O argumento deve ser uma string sem símbolos, caso contrário, deve lançar
+/// erro.
+///
+/// ## Exemplos
+///
+/// Documento de 8 dígitos sem máscara:
+/// ```
+/// use brado::cep;
+///
+/// let result = match cep::mask("01310100") { // Ok("01310-100")
+///     Ok(doc) => doc,
+///     Err(e) => panic!("{}", e),
+/// };
+/// assert!(cep::is_masked(&result)); // true
+/// ```
+///
+/// Documento de 8 dígitos com máscara:
+/// ```should_panic
+/// use brado::cep;
+///
+/// let result = match cep::mask("01310-100") { // It panics!
+///     Ok(doc) => doc,
+///     Err(e) => panic!("{}", e),
+/// };
+/// ```
+pub fn mask(doc: &str) -> Result<String, MaskError> {
+    check_mask(doc, DocKind::Cep, CEP_SIZE, is_masked, to_decimal)?;
+
+    let masked_doc: String = format!("{}-{}", &doc[0..5], &doc[5..8]);
+
+    Ok(masked_doc)
+}
+
+/// Remove os símbolos do argumento `doc` e retorna o CEP sem máscara.
+///
+/// ## Exemplo
+/// ```
+/// use brado::cep;
+///
+/// let result = cep::unmask("01310-100"); // "01310100"
+/// assert_eq!(result, "01310100");
+/// ```
+pub fn unmask(doc: &str) -> String {
+    crate::common::unmask(doc, to_decimal)
+}
+
+/// Gera e retorna um CEP aleatório sem máscara.
+///
+/// ## Exemplo
+/// ```
+/// use brado::cep;
+///
+/// let result = cep::generate(); // "01310100"
+/// assert!(cep::is_bare(&result)); // true
+/// ```
+pub fn generate() -> String {
+    generate_with_rng(&mut rand::thread_rng())
+}
+
+/// Gera e retorna um CEP aleatório sem máscara a partir do gerador de
+/// números aleatórios `rng`. Geradores com a mesma semente produzem o mesmo
+/// CEP.
+///
+/// ## Exemplo
+/// ```
+/// use brado::cep;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let result = cep::generate_with_rng(&mut StdRng::seed_from_u64(42));
+/// assert!(cep::is_bare(&result)); // true
+///
+/// let other = cep::generate_with_rng(&mut StdRng::seed_from_u64(42));
+/// assert_eq!(result, other);
+/// ```
+pub fn generate_with_rng<R>(rng: &mut R) -> String
+where
+    R: Rng + ?Sized,
+{
+    let uf: Uf = random_element_from_vector_with_rng(Uf::ALL, rng);
+
+    generate_for_uf_with_rng(uf, rng)
+}
+
+/// Gera e retorna um CEP aleatório com máscara.
+///
+/// ## Exemplo
+/// ```
+/// use brado::cep;
+///
+/// let result = cep::generate_masked(); // "01310-100"
+/// assert!(cep::is_masked(&result)); // true
+/// ```
+pub fn generate_masked() -> String {
+    generate_masked_with_rng(&mut rand::thread_rng())
+}
+
+/// Gera e retorna um CEP aleatório com máscara a partir do gerador de
+/// números aleatórios `rng`. Geradores com a mesma semente produzem o mesmo
+/// CEP.
+///
+/// ## Exemplo
+/// ```
+/// use brado::cep;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let result = cep::generate_masked_with_rng(&mut rng);
+/// assert!(cep::is_masked(&result)); // true
+/// ```
+pub fn generate_masked_with_rng<R>(rng: &mut R) -> String
+where
+    R: Rng + ?Sized,
+{
+    mask(&generate_with_rng(rng)).expect("Invalid CEP!")
+}
+
+/// Retorna as faixas oficiais de CEP da Unidade Federativa `uf`, como
+/// intervalos de números de 8 dígitos (sem máscara).
+///
+/// ## Exemplo
+/// ```
+/// use brado::cep;
+/// use brado::common::Uf;
+///
+/// let result = cep::ranges(Uf::Sp); // [01000000..=19999999]
+/// assert_eq!(result, vec![1000000..=19999999]);
+///
+/// let result = cep::ranges(Uf::Df); // [70000000..=72799999, ...]
+/// assert_eq!(result, vec![70000000..=72799999, 73000000..=73699999]);
+/// ```
+pub fn ranges(uf: Uf) -> Vec<RangeInclusive<u32>> {
+    UF_RANGES
+        .iter()
+        .filter(|(range_uf, _, _)| *range_uf == uf)
+        .map(|(_, start, end)| (start * 1000)..=(end * 1000 + 999))
+        .collect()
+}
+
+/// Retorna a Unidade Federativa a que pertence o CEP `doc`, máscarado ou
+/// não. Retorna `None` caso o CEP seja inválido.
+///
+/// ## Exemplos
+/// ```
+/// use brado::cep;
+/// use brado::common::Uf;
+///
+/// let result = cep::uf("01310-100"); // Some(Uf::Sp)
+/// assert_eq!(result, Some(Uf::Sp));
+///
+/// let result = cep::uf("70040-010"); // Some(Uf::Df)
+/// assert_eq!(result, Some(Uf::Df));
+///
+/// let result = cep::uf("00310-100"); // None
+/// assert_eq!(result, None);
+/// ```
+pub fn uf(doc: &str) -> Option<Uf> {
+    check(doc).ok()?;
+
+    uf_from_digits(&get_digits(doc, to_decimal))
+}
+
+/// Verifica se o CEP `doc`, máscarado ou não, é válido e pertence à Unidade
+/// Federativa `uf`.
+/// Retorna `true` se for, caso contrário, retorna `false`.
+///
+/// ## Exemplos
+/// ```
+/// use brado::cep;
+/// use brado::common::Uf;
+///
+/// let result = cep::validate_for_uf(Uf::Sp, "01310-100"); // true
+/// assert!(result);
+///
+/// let result = cep::validate_for_uf(Uf::Rj, "01310-100"); // false
+/// assert!(!result);
+/// ```
+pub fn validate_for_uf(
+    uf: Uf,
+    doc: &str,
+) -> bool {
+    check_for_uf(uf, doc).is_ok()
+}
+
+/// Verifica se o CEP `doc`, máscarado ou não, é válido e pertence à Unidade
+/// Federativa `uf`, informando o motivo da falha.
+/// Retorna `Ok(())` se for, caso contrário, retorna o erro de validação
+/// (`ValidationError`) correspondente.
+///
+/// ## Exemplos
+/// ```
+/// use brado::cep;
+/// use brado::common::{Uf, ValidationError};
+///
+/// let result = cep::check_for_uf(Uf::Sp, "01310-100"); // Ok(())
+/// assert!(result.is_ok());
+///
+/// let result = cep::check_for_uf(Uf::Rj, "01310-100");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::InvalidPrefix {
+///         found: String::from("01310"),
+///     }),
+/// );
+/// ```
+pub fn check_for_uf(
+    uf: Uf,
+    doc: &str,
+) -> Result<(), ValidationError> {
+    check(doc)?;
+
+    let digits: Vec<u16> = get_digits(doc, to_decimal);
+
+    match uf_from_digits(&digits) {
+        Some(found) if found == uf => Ok(()),
+        _ => Err(invalid_prefix(&digits)),
+    }
+}
+
+/// Gera e retorna um CEP aleatório sem máscara pertencente à Unidade
+/// Federativa `uf`.
+///
+/// ## Exemplo
+/// ```
+/// use brado::cep;
+/// use brado::common::Uf;
+///
+/// let result = cep::generate_for_uf(Uf::Sp);
+/// assert!(cep::validate(&result)); // true
+/// assert_eq!(cep::uf(&result), Some(Uf::Sp));
+/// ```
+pub fn generate_for_uf(uf: Uf) -> String {
+    generate_for_uf_with_rng(uf, &mut rand::thread_rng())
+}
+
+/// Gera e retorna um CEP aleatório sem máscara pertencente à Unidade
+/// Federativa `uf`, a partir do gerador de números aleatórios `rng`.
+///
+/// ## Exemplo
+/// ```
+/// use brado::cep;
+/// use brado::common::Uf;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let result = cep::generate_for_uf_with_rng(Uf::Am, &mut rng);
+/// assert_eq!(cep::uf(&result), Some(Uf::Am));
+/// ```
+pub fn generate_for_uf_with_rng<R>(
+    uf: Uf,
+    rng: &mut R,
+) -> String
+where
+    R: Rng + ?Sized,
+{
+    let range: RangeInclusive<u32> =
+        random_element_from_vector_with_rng(&ranges(uf), rng);
+
+    format!("{:08}", rng.gen_range(range))
+}
+
+/// CEP validado, armazenado sem máscara.
+///
+/// Só pode ser construído a partir de um CEP válido, máscarado ou não, por
+/// meio de `FromStr` ou `TryFrom<&str>`. É exibido (`Display`) com máscara.
+///
+/// ## Exemplo
+/// ```
+/// use brado::cep::Cep;
+/// use brado::common::Uf;
+///
+/// let doc: Cep = "01310-100".parse().unwrap();
+/// assert_eq!(doc.as_bare(), "01310100");
+/// assert_eq!(doc.to_masked(), "01310-100");
+/// assert_eq!(doc.to_string(), "01310-100");
+/// assert_eq!(doc.uf(), Uf::Sp);
+///
+/// let result = "00310-100".parse::<Cep>();
+/// assert!(result.is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Cep(String);
+
+impl Cep {
+    /// Retorna o CEP sem máscara.
+    pub fn as_bare(&self) -> &str {
+        &self.0
+    }
+
+    /// Retorna o CEP com máscara.
+    pub fn to_masked(&self) -> String {
+        mask(&self.0).expect("Invalid CEP!")
+    }
+
+    /// Retorna a Unidade Federativa a que pertence o CEP.
+    pub fn uf(&self) -> Uf {
+        uf(&self.0).expect("Invalid CEP!")
+    }
+}
+
+impl FromStr for Cep {
+    type Err = ValidationError;
+
+    fn from_str(doc: &str) -> Result<Self, Self::Err> {
+        check(doc)?;
+        Ok(Cep(unmask(doc)))
+    }
+}
+
+impl TryFrom<&str> for Cep {
+    type Error = ValidationError;
+
+    fn try_from(doc: &str) -> Result<Self, Self::Error> {
+        doc.parse()
+    }
+}
+
+impl fmt::Display for Cep {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(f, "{}", self.to_masked())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Cep {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_bare())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Cep {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let doc: String = String::deserialize(deserializer)?;
+        doc.parse().map_err(de::Error::custom)
+    }
+}
+
+/// Tipo marcador do CEP.
+/// Implementa o trait `Document` a partir das funções deste módulo.
+///
+/// ## Exemplo
+/// ```
+/// use brado::cep::CepDocument;
+/// use brado::common::Document;
+///
+/// let result = CepDocument.validate("01310-100"); // true
+/// assert!(result);
+///
+/// let result = CepDocument.bare_size(); // 8
+/// assert_eq!(result, 8);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CepDocument;
+
+impl Document for CepDocument {
+    fn kind(&self) -> DocKind {
+        DocKind::Cep
+    }
+
+    fn bare_size(&self) -> usize {
+        CEP_SIZE
+    }
+
+    fn check(
+        &self,
+        doc: &str,
+    ) -> Result<(), ValidationError> {
+        check(doc)
+    }
+
+    fn is_bare(
+        &self,
+        doc: &str,
+    ) -> bool {
+        is_bare(doc)
+    }
+
+    fn is_masked(
+        &self,
+        doc: &str,
+    ) -> bool {
+        is_masked(doc)
+    }
+
+    fn mask(
+        &self,
+        doc: &str,
+    ) -> Result<String, MaskError> {
+        mask(doc)
+    }
+
+    fn unmask(
+        &self,
+        doc: &str,
+    ) -> String {
+        unmask(doc)
+    }
+
+    fn generate(&self) -> String {
+        generate()
+    }
+
+    fn generate_masked(&self) -> String {
+        generate_masked()
+    }
+
+    fn generate_with_rng(
+        &self,
+        rng: &mut dyn RngCore,
+    ) -> String {
+        generate_with_rng(rng)
+    }
+
+    fn generate_masked_with_rng(
+        &self,
+        rng: &mut dyn RngCore,
+    ) -> String {
+        generate_masked_with_rng(rng)
+    }
+}
@@ -1,11 +1,32 @@
 //! Utilitários para validação de Certidões de Nascimento, Casamento e Óbito.
 
 use crate::common::{
-    get_digits, get_symbols, random_decimal_vector, to_decimal,
+    check_digits, check_format, check_mask, current_year, get_digits,
+    get_symbols, random_decimal_vector_with_rng,
+    random_element_from_vector_with_rng, to_decimal, Document, MaskError,
+    ValidationError,
 };
+use crate::docs::DocKind;
+use rand::{Rng, RngCore};
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
 
 const CERTIDAO_SIZE: usize = 32;
 
+/// Quantidade de dígitos do código do cartório (CNS da serventia).
+const CARTORIO_SIZE: usize = 6;
+
+/// Código de acervo próprio do cartório.
+const ACERVO_PROPRIO: &str = "01";
+
+/// Código de serviço do Registro Civil das Pessoas Naturais (RCPN).
+const RCPN_SERVICO: &str = "55";
+
+/// Primeiro ano de registro utilizado na geração de Certidões aleatórias.
+const FIRST_GENERATED_YEAR: u16 = 1900;
+
 /// Realiza validação de Certidão, máscarada ou não.
 /// Retorna `true` se o argumento `doc` for uma Certidão válido, caso contrário,
 /// retorna `false`.
@@ -34,21 +55,122 @@ const CERTIDAO_SIZE: usize = 32;
 /// assert!(!result);
 /// ```
 pub fn validate(doc: &str) -> bool {
-    let size: usize = doc.chars().count();
+    check(doc).is_ok()
+}
 
-    if size != CERTIDAO_SIZE && !is_masked(doc) {
-        return false;
-    }
+/// Realiza validação de Certidão, máscarada ou não, informando o motivo da
+/// falha.
+/// Retorna `Ok(())` se o argumento `doc` for uma Certidão válida, caso
+/// contrário, retorna o erro de validação (`ValidationError`) correspondente.
+///
+/// ## Exemplos
+///
+/// Certidões válidas:
+/// ```
+/// use brado::certidao;
+///
+/// let result = certidao::check("21924201552023106304243115818536"); // Ok(())
+/// assert!(result.is_ok());
+///
+/// let result = certidao::check("219242 01 55 2023 1 06304 243 1158185-36"); // Ok(())
+/// assert!(result.is_ok());
+/// ```
+///
+/// Certidões inválidas:
+/// ```
+/// use brado::certidao;
+/// use brado::common::ValidationError;
+///
+/// let result = certidao::check("21924201552023106304243115818535");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::CheckDigitMismatch {
+///         expected: String::from("36"),
+///         found: String::from("35"),
+///     }),
+/// );
+///
+/// let result = certidao::check("219242 01 55 2023 1 06304 243 1158185:36");
+/// assert_eq!(result, Err(ValidationError::InvalidMask));
+///
+/// let result = certidao::check("219242 01 55 2023 0 06304 243 1158185-36");
+/// assert_eq!(result, Err(ValidationError::InvalidBookType { found: 0 }));
+///
+/// let result = certidao::check("219242 01 55 9023 1 06304 243 1158185-36");
+/// assert_eq!(result, Err(ValidationError::InvalidYear { found: 9023 }));
+/// ```
+pub fn check(doc: &str) -> Result<(), ValidationError> {
+    let digits: Vec<u16> =
+        check_format(doc, CERTIDAO_SIZE, is_masked, to_decimal)?;
 
-    let digits: Vec<u16> = get_digits(doc, to_decimal);
+    check_structure(&digits)?;
 
-    if digits.len() != CERTIDAO_SIZE {
-        return false;
+    let (d30, d31): (u16, u16) = generate_digits(&digits[..30]);
+
+    check_digits(&[d30, d31], &digits[30..])
+}
+
+/// Calcula os dígitos verificadores de uma Certidão a partir dos 30
+/// primeiros dígitos (`base`), sem símbolos.
+/// Retorna os 2 dígitos verificadores, ou o erro correspondente
+/// (`ValidationError`) caso a base seja inválida.
+///
+/// ## Exemplos
+///
+/// Bases válidas:
+/// ```
+/// use brado::certidao;
+///
+/// let base = "219242015520231063042431158185";
+/// let result = certidao::compute_check_digits(base); // Ok("36")
+/// assert_eq!(result, Ok(String::from("36")));
+///
+/// let doc = format!("{}{}", base, result.unwrap());
+/// assert!(certidao::validate(&doc));
+/// ```
+///
+/// Bases inválidas:
+/// ```
+/// use brado::certidao;
+/// use brado::common::ValidationError;
+///
+/// let base = "2192420155202310630424311581";
+/// let result = certidao::compute_check_digits(base);
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::InvalidLength {
+///         expected: 30,
+///         found: 28,
+///     }),
+/// );
+/// ```
+pub fn compute_check_digits(base: &str) -> Result<String, ValidationError> {
+    let digits: Vec<u16> =
+        check_format(base, CERTIDAO_SIZE - 2, |_| false, to_decimal)?;
+
+    check_structure(&digits)?;
+
+    let (d30, d31): (u16, u16) = generate_digits(&digits);
+
+    Ok(format!("{}{}", d30, d31))
+}
+
+fn to_number(doc_slice: &[u16]) -> u16 {
+    doc_slice.iter().fold(0, |acc, d| acc * 10 + d)
+}
+
+fn check_structure(doc_slice: &[u16]) -> Result<(), ValidationError> {
+    let book_type: u16 = doc_slice[14];
+    if CertidaoKind::from_digit(book_type).is_none() {
+        return Err(ValidationError::InvalidBookType { found: book_type });
     }
 
-    let (d30, d31): (u16, u16) = generate_digits(&digits[..30]);
+    let year: u16 = to_number(&doc_slice[10..14]);
+    if year > current_year() {
+        return Err(ValidationError::InvalidYear { found: year });
+    }
 
-    (d30, d31) == (digits[30], digits[31])
+    Ok(())
 }
 
 fn generate_digits(doc_slice: &[u16]) -> (u16, u16) {
@@ -179,10 +301,8 @@ pub fn is_masked(doc: &str) -> bool {
 ///     Err(e) => panic!("{}", e),
 /// };
 /// ```
-pub fn mask(doc: &str) -> Result<String, &'static str> {
-    if !is_bare(doc) {
-        return Err("The given string cannot be masked as Certidão!");
-    }
+pub fn mask(doc: &str) -> Result<String, MaskError> {
+    check_mask(doc, DocKind::Certidao, CERTIDAO_SIZE, is_masked, to_decimal)?;
 
     let masked_doc: String = format!(
         "{} {} {} {} {} {} {} {}-{}",
@@ -200,6 +320,19 @@ pub fn mask(doc: &str) -> Result<String, &'static str> {
     Ok(masked_doc)
 }
 
+/// Remove os símbolos do argumento `doc` e retorna a Certidão sem máscara.
+///
+/// ## Exemplo
+/// ```
+/// use brado::certidao;
+///
+/// let result = certidao::unmask("219242 01 55 2023 1 06304 243 1158185-36"); // "21924201552023106304243115818536"
+/// assert_eq!(result, "21924201552023106304243115818536");
+/// ```
+pub fn unmask(doc: &str) -> String {
+    crate::common::unmask(doc, to_decimal)
+}
+
 /// Gera e retorna uma Certidão aleatório sem máscara.
 ///
 /// ## Exemplo
@@ -210,7 +343,53 @@ pub fn mask(doc: &str) -> Result<String, &'static str> {
 /// assert!(certidao::is_bare(&result)); // true
 /// ```
 pub fn generate() -> String {
-    let mut certidao: Vec<u16> = random_decimal_vector(30);
+    generate_with_rng(&mut rand::thread_rng())
+}
+
+/// Gera e retorna uma Certidão aleatória sem máscara a partir do gerador de
+/// números aleatórios `rng`. Geradores com a mesma semente produzem a mesma
+/// Certidão.
+///
+/// ## Exemplo
+/// ```
+/// use brado::certidao;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let result = certidao::generate_with_rng(&mut StdRng::seed_from_u64(42));
+/// assert!(certidao::is_bare(&result)); // true
+///
+/// let other = certidao::generate_with_rng(&mut StdRng::seed_from_u64(42));
+/// assert_eq!(result, other);
+/// ```
+pub fn generate_with_rng<R>(rng: &mut R) -> String
+where
+    R: Rng + ?Sized,
+{
+    let kind: CertidaoKind =
+        random_element_from_vector_with_rng(CertidaoKind::ALL, rng);
+    let year: u16 = rng.gen_range(FIRST_GENERATED_YEAR..=current_year());
+    let cartorio: Vec<u16> = random_decimal_vector_with_rng(CARTORIO_SIZE, rng);
+
+    generate_from(kind, year, &cartorio, rng)
+}
+
+fn generate_from<R>(
+    kind: CertidaoKind,
+    year: u16,
+    cartorio: &[u16],
+    rng: &mut R,
+) -> String
+where
+    R: Rng + ?Sized,
+{
+    let mut certidao: Vec<u16> = cartorio.to_vec();
+    certidao.extend(get_digits(ACERVO_PROPRIO, to_decimal));
+    certidao.extend(get_digits(RCPN_SERVICO, to_decimal));
+    certidao.extend([year / 1000, year / 100 % 10, year / 10 % 10, year % 10]);
+    certidao.push(kind.digit());
+    // Livro, folha e termo.
+    certidao.extend(random_decimal_vector_with_rng(15, rng));
     certidao.push(generate_digit(&certidao));
     certidao.push(generate_digit(&certidao));
 
@@ -231,5 +410,480 @@ pub fn generate() -> String {
 /// assert!(certidao::is_masked(&result)); // true
 /// ```
 pub fn generate_masked() -> String {
-    mask(&generate()).expect("Invalid Certidão!")
+    generate_masked_with_rng(&mut rand::thread_rng())
+}
+
+/// Gera e retorna uma Certidão aleatória com máscara a partir do gerador de
+/// números aleatórios `rng`. Geradores com a mesma semente produzem a mesma
+/// Certidão.
+///
+/// ## Exemplo
+/// ```
+/// use brado::certidao;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let result = certidao::generate_masked_with_rng(&mut rng);
+/// assert!(certidao::is_masked(&result)); // true
+/// ```
+pub fn generate_masked_with_rng<R>(rng: &mut R) -> String
+where
+    R: Rng + ?Sized,
+{
+    mask(&generate_with_rng(rng)).expect("Invalid Certidão!")
+}
+
+/// Gera e retorna uma Certidão sem máscara do tipo de livro `kind`,
+/// registrada no ano `year` pelo cartório de CNS `cartorio` (6 dígitos, sem
+/// máscara). O acervo (01, próprio) e o serviço (55, RCPN) são preenchidos de
+/// forma coerente, e apenas o livro, a folha e o termo são aleatórios.
+/// Retorna o erro de validação (`ValidationError`) correspondente caso o
+/// cartório seja inválido ou o ano seja posterior ao ano corrente.
+///
+/// ## Exemplos
+/// ```
+/// use brado::certidao::{self, CertidaoKind};
+/// use brado::common::ValidationError;
+///
+/// let result = certidao::generate_for(CertidaoKind::Obito, 2020, "219242");
+/// let parts = certidao::parse(&result.unwrap()).unwrap();
+/// assert_eq!(parts.kind, CertidaoKind::Obito);
+/// assert_eq!(parts.ano, 2020);
+/// assert_eq!(parts.cartorio, "219242");
+///
+/// let result = certidao::generate_for(CertidaoKind::Obito, 2020, "2192");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::InvalidLength {
+///         expected: 6,
+///         found: 4,
+///     }),
+/// );
+/// ```
+pub fn generate_for(
+    kind: CertidaoKind,
+    year: u16,
+    cartorio: &str,
+) -> Result<String, ValidationError> {
+    generate_for_with_rng(kind, year, cartorio, &mut rand::thread_rng())
+}
+
+/// Gera e retorna uma Certidão sem máscara do tipo de livro `kind`,
+/// registrada no ano `year` pelo cartório de CNS `cartorio`, a partir do
+/// gerador de números aleatórios `rng`.
+///
+/// ## Exemplo
+/// ```
+/// use brado::certidao::{self, CertidaoKind};
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let kind = CertidaoKind::Casamento;
+/// let result =
+///     certidao::generate_for_with_rng(kind, 2010, "123456", &mut rng);
+/// assert!(certidao::validate(&result.unwrap())); // true
+/// ```
+pub fn generate_for_with_rng<R>(
+    kind: CertidaoKind,
+    year: u16,
+    cartorio: &str,
+    rng: &mut R,
+) -> Result<String, ValidationError>
+where
+    R: Rng + ?Sized,
+{
+    let cartorio: Vec<u16> =
+        check_format(cartorio, CARTORIO_SIZE, |_| false, to_decimal)?;
+
+    if year > current_year() {
+        return Err(ValidationError::InvalidYear { found: year });
+    }
+
+    Ok(generate_from(kind, year, &cartorio, rng))
+}
+
+/// Tipos de livro do Registro Civil, codificados no 15º dígito da matrícula
+/// da Certidão.
+///
+/// ## Exemplo
+/// ```
+/// use brado::certidao::CertidaoKind;
+///
+/// let result = CertidaoKind::Obito.digit(); // 4
+/// assert_eq!(result, 4);
+///
+/// let result = CertidaoKind::from_digit(2); // Some(CertidaoKind::Casamento)
+/// assert_eq!(result, Some(CertidaoKind::Casamento));
+///
+/// let result = CertidaoKind::Casamento.livro(); // "B"
+/// assert_eq!(result, "B");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CertidaoKind {
+    /// Livro A (1): Nascimento.
+    Nascimento,
+    /// Livro B (2): Casamento.
+    Casamento,
+    /// Livro B Auxiliar (3): Casamento religioso para efeitos civis.
+    CasamentoReligioso,
+    /// Livro C (4): Óbito.
+    Obito,
+    /// Livro C Auxiliar (5): Natimorto.
+    Natimorto,
+    /// Livro D (6): Proclamas.
+    Proclamas,
+    /// Livro E (7): Demais atos do registro civil.
+    DemaisAtos,
+    /// Livro E (8): Emancipação.
+    Emancipacao,
+    /// Livro E (9): Interdição.
+    Interdicao,
+}
+
+impl CertidaoKind {
+    /// Todos os tipos de livro.
+    pub const ALL: &'static [CertidaoKind] = &[
+        CertidaoKind::Nascimento,
+        CertidaoKind::Casamento,
+        CertidaoKind::CasamentoReligioso,
+        CertidaoKind::Obito,
+        CertidaoKind::Natimorto,
+        CertidaoKind::Proclamas,
+        CertidaoKind::DemaisAtos,
+        CertidaoKind::Emancipacao,
+        CertidaoKind::Interdicao,
+    ];
+
+    /// Retorna o dígito que identifica o tipo de livro na matrícula.
+    pub fn digit(&self) -> u16 {
+        match self {
+            CertidaoKind::Nascimento => 1,
+            CertidaoKind::Casamento => 2,
+            CertidaoKind::CasamentoReligioso => 3,
+            CertidaoKind::Obito => 4,
+            CertidaoKind::Natimorto => 5,
+            CertidaoKind::Proclamas => 6,
+            CertidaoKind::DemaisAtos => 7,
+            CertidaoKind::Emancipacao => 8,
+            CertidaoKind::Interdicao => 9,
+        }
+    }
+
+    /// Retorna o tipo de livro identificado pelo dígito `digit`, ou `None`
+    /// caso o dígito não corresponda a um tipo de livro.
+    pub fn from_digit(digit: u16) -> Option<CertidaoKind> {
+        match digit {
+            1 => Some(CertidaoKind::Nascimento),
+            2 => Some(CertidaoKind::Casamento),
+            3 => Some(CertidaoKind::CasamentoReligioso),
+            4 => Some(CertidaoKind::Obito),
+            5 => Some(CertidaoKind::Natimorto),
+            6 => Some(CertidaoKind::Proclamas),
+            7 => Some(CertidaoKind::DemaisAtos),
+            8 => Some(CertidaoKind::Emancipacao),
+            9 => Some(CertidaoKind::Interdicao),
+            _ => None,
+        }
+    }
+
+    /// Retorna o nome do livro do Registro Civil.
+    pub fn livro(&self) -> &'static str {
+        match self {
+            CertidaoKind::Nascimento => "A",
+            CertidaoKind::Casamento => "B",
+            CertidaoKind::CasamentoReligioso => "B Auxiliar",
+            CertidaoKind::Obito => "C",
+            CertidaoKind::Natimorto => "C Auxiliar",
+            CertidaoKind::Proclamas => "D",
+            CertidaoKind::DemaisAtos => "E",
+            CertidaoKind::Emancipacao => "E",
+            CertidaoKind::Interdicao => "E",
+        }
+    }
+
+    /// Retorna a descrição do tipo de registro.
+    pub fn description(&self) -> &'static str {
+        match self {
+            CertidaoKind::Nascimento => "Nascimento",
+            CertidaoKind::Casamento => "Casamento",
+            CertidaoKind::CasamentoReligioso => {
+                "Casamento religioso para efeitos civis"
+            }
+            CertidaoKind::Obito => "Óbito",
+            CertidaoKind::Natimorto => "Natimorto",
+            CertidaoKind::Proclamas => "Proclamas",
+            CertidaoKind::DemaisAtos => "Demais atos do registro civil",
+            CertidaoKind::Emancipacao => "Emancipação",
+            CertidaoKind::Interdicao => "Interdição",
+        }
+    }
+}
+
+/// Campos da matrícula de uma Certidão, todos sem máscara.
+///
+/// ## Exemplo
+/// ```
+/// use brado::certidao::{self, CertidaoKind};
+///
+/// let parts = certidao::parse("219242 01 55 2023 1 06304 243 1158185-36")
+///     .unwrap();
+/// assert_eq!(parts.cartorio, "219242");
+/// assert_eq!(parts.acervo, "01");
+/// assert_eq!(parts.servico, "55");
+/// assert_eq!(parts.ano, 2023);
+/// assert_eq!(parts.kind, CertidaoKind::Nascimento);
+/// assert_eq!(parts.livro, "06304");
+/// assert_eq!(parts.folha, "243");
+/// assert_eq!(parts.termo, "1158185");
+/// assert_eq!(parts.dv, "36");
+/// assert!(parts.is_rcpn());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CertidaoParts {
+    /// Código Nacional da Serventia (CNS) do cartório (6 dígitos).
+    pub cartorio: String,
+    /// Código do acervo (2 dígitos): 01 para acervo próprio e 02 para
+    /// acervos incorporados.
+    pub acervo: String,
+    /// Código do serviço (2 dígitos): 55 para o Registro Civil das Pessoas
+    /// Naturais.
+    pub servico: String,
+    /// Ano do registro.
+    pub ano: u16,
+    /// Tipo de livro.
+    pub kind: CertidaoKind,
+    /// Número do livro (5 dígitos).
+    pub livro: String,
+    /// Número da folha (3 dígitos).
+    pub folha: String,
+    /// Número do termo (7 dígitos).
+    pub termo: String,
+    /// Dígitos verificadores.
+    pub dv: String,
+}
+
+impl CertidaoParts {
+    /// Verifica se o serviço é o Registro Civil das Pessoas Naturais (`55`).
+    pub fn is_rcpn(&self) -> bool {
+        self.servico == RCPN_SERVICO
+    }
+}
+
+/// Decompõe uma Certidão válida, máscarada ou não, nos campos da matrícula
+/// (`CertidaoParts`).
+/// Retorna o erro de validação (`ValidationError`) correspondente caso o
+/// argumento `doc` não seja uma Certidão válida.
+///
+/// ## Exemplos
+/// ```
+/// use brado::certidao::{self, CertidaoKind};
+/// use brado::common::ValidationError;
+///
+/// let parts = certidao::parse("21924201552023106304243115818536").unwrap();
+/// assert_eq!(parts.kind, CertidaoKind::Nascimento);
+/// assert_eq!(parts.ano, 2023);
+///
+/// let result = certidao::parse("21924201552023006304243115818536");
+/// assert_eq!(result, Err(ValidationError::InvalidBookType { found: 0 }));
+/// ```
+pub fn parse(doc: &str) -> Result<CertidaoParts, ValidationError> {
+    check(doc)?;
+
+    let digits: Vec<u16> = get_digits(doc, to_decimal);
+    let field = |range: std::ops::Range<usize>| -> String {
+        digits[range].iter().map(|d| d.to_string()).collect()
+    };
+
+    Ok(CertidaoParts {
+        cartorio: field(0..6),
+        acervo: field(6..8),
+        servico: field(8..10),
+        ano: to_number(&digits[10..14]),
+        kind: CertidaoKind::from_digit(digits[14]).expect("Invalid book type!"),
+        livro: field(15..20),
+        folha: field(20..23),
+        termo: field(23..30),
+        dv: field(30..32),
+    })
+}
+
+/// Certidão validada, armazenada sem máscara.
+///
+/// Só pode ser construída a partir de uma Certidão válida, máscarada ou não,
+/// por meio de `FromStr` ou `TryFrom<&str>`. É exibida (`Display`) com
+/// máscara.
+///
+/// ## Exemplo
+/// ```
+/// use brado::certidao::Certidao;
+///
+/// let doc: Certidao = "219242 01 55 2023 1 06304 243 1158185-36".parse().unwrap();
+/// assert_eq!(doc.as_bare(), "21924201552023106304243115818536");
+/// assert_eq!(doc.to_masked(), "219242 01 55 2023 1 06304 243 1158185-36");
+/// assert_eq!(doc.to_string(), "219242 01 55 2023 1 06304 243 1158185-36");
+///
+/// let result = "219242 01 55 2023 1 06304 243 1158185-35".parse::<Certidao>();
+/// assert!(result.is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Certidao(String);
+
+impl Certidao {
+    /// Retorna a Certidão sem máscara.
+    pub fn as_bare(&self) -> &str {
+        &self.0
+    }
+
+    /// Retorna a Certidão com máscara.
+    pub fn to_masked(&self) -> String {
+        mask(&self.0).expect("Invalid Certidão!")
+    }
+
+    /// Retorna os campos da matrícula da Certidão.
+    pub fn parts(&self) -> CertidaoParts {
+        parse(&self.0).expect("Invalid Certidão!")
+    }
+
+    /// Retorna o tipo de livro da Certidão.
+    pub fn kind(&self) -> CertidaoKind {
+        self.parts().kind
+    }
+}
+
+impl FromStr for Certidao {
+    type Err = ValidationError;
+
+    fn from_str(doc: &str) -> Result<Self, Self::Err> {
+        check(doc)?;
+        Ok(Certidao(unmask(doc)))
+    }
+}
+
+impl TryFrom<&str> for Certidao {
+    type Error = ValidationError;
+
+    fn try_from(doc: &str) -> Result<Self, Self::Error> {
+        doc.parse()
+    }
+}
+
+impl fmt::Display for Certidao {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(f, "{}", self.to_masked())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Certidao {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_bare())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Certidao {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let doc: String = String::deserialize(deserializer)?;
+        doc.parse().map_err(de::Error::custom)
+    }
+}
+
+/// Tipo marcador da Certidão.
+/// Implementa o trait `Document` a partir das funções deste módulo.
+///
+/// ## Exemplo
+/// ```
+/// use brado::common::Document;
+/// use brado::certidao::CertidaoDocument;
+///
+/// let result = CertidaoDocument.validate("219242 01 55 2023 1 06304 243 1158185-36"); // true
+/// assert!(result);
+///
+/// let result = CertidaoDocument.bare_size(); // 32
+/// assert_eq!(result, 32);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CertidaoDocument;
+
+impl Document for CertidaoDocument {
+    fn kind(&self) -> DocKind {
+        DocKind::Certidao
+    }
+
+    fn bare_size(&self) -> usize {
+        CERTIDAO_SIZE
+    }
+
+    fn check(
+        &self,
+        doc: &str,
+    ) -> Result<(), ValidationError> {
+        check(doc)
+    }
+
+    fn is_bare(
+        &self,
+        doc: &str,
+    ) -> bool {
+        is_bare(doc)
+    }
+
+    fn is_masked(
+        &self,
+        doc: &str,
+    ) -> bool {
+        is_masked(doc)
+    }
+
+    fn mask(
+        &self,
+        doc: &str,
+    ) -> Result<String, MaskError> {
+        mask(doc)
+    }
+
+    fn unmask(
+        &self,
+        doc: &str,
+    ) -> String {
+        unmask(doc)
+    }
+
+    fn generate(&self) -> String {
+        generate()
+    }
+
+    fn generate_masked(&self) -> String {
+        generate_masked()
+    }
+
+    fn generate_with_rng(
+        &self,
+        rng: &mut dyn RngCore,
+    ) -> String {
+        generate_with_rng(rng)
+    }
+
+    fn generate_masked_with_rng(
+        &self,
+        rng: &mut dyn RngCore,
+    ) -> String {
+        generate_masked_with_rng(rng)
+    }
 }
@@ -0,0 +1,893 @@
+//! Utilitários para validação de Número de Identificação do Veículo (chassi
+//! ou VIN), conforme as normas ISO 3779 e ISO 3780.
+
+use crate::common::{
+    check_format, check_mask, current_year, get_digits, get_symbols,
+    random_element_from_vector_with_rng, random_string_from_alphabet_with_rng,
+    Document, MaskError, ValidationError,
+};
+use crate::docs::DocKind;
+use rand::{Rng, RngCore};
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+const CHASSI_SIZE: usize = 17;
+const CHECK_DIGIT_POSITION: usize = 8;
+const YEAR_POSITION: usize = 9;
+const FIRST_YEAR: u16 = 1980;
+const ALPHABET: &str = "0123456789ABCDEFGHJKLMNPRSTUVWXYZ";
+const YEAR_CODES: &str = "ABCDEFGHJKLMNPRSTVWXY123456789";
+const BRAZIL_CODES: &str = "ABCDE3456789";
+
+/// Converte um caractere do chassi em seu valor numérico (transliteração da
+/// ISO 3779). As letras I, O e Q não são permitidas.
+fn transliterate(c: char) -> Option<u16> {
+    match c {
+        '0'..='9' => Some(c as u16 - '0' as u16),
+        'A'..='H' => Some(c as u16 - 'A' as u16 + 1),
+        'J'..='N' => Some(c as u16 - 'J' as u16 + 1),
+        'P' => Some(7),
+        'R' => Some(9),
+        'S'..='Z' => Some(c as u16 - 'S' as u16 + 2),
+        _ => None,
+    }
+}
+
+/// Realiza validação de chassi, máscarado ou não.
+/// Retorna `true` se o argumento `doc` for um chassi válido, caso contrário,
+/// retorna `false`.
+///
+/// ## Exemplos
+///
+/// Chassis válidos:
+/// ```
+/// use brado::chassi;
+///
+/// let result = chassi::validate("9BWZZZ372VT004251"); // true
+/// assert!(result);
+///
+/// let result = chassi::validate("9BW ZZZ372 VT004251"); // true
+/// assert!(result);
+/// ```
+///
+/// Chassis inválidos:
+/// ```
+/// use brado::chassi;
+///
+/// let result = chassi::validate("9BWZZZ373VT004251"); // false
+/// assert!(!result);
+///
+/// let result = chassi::validate("9BWZZZ372VT0O4251"); // false
+/// assert!(!result);
+/// ```
+pub fn validate(doc: &str) -> bool {
+    check(doc).is_ok()
+}
+
+/// Realiza validação de chassi, máscarado ou não, informando o motivo da
+/// falha.
+/// Retorna `Ok(())` se o argumento `doc` for um chassi válido, caso
+/// contrário, retorna o erro de validação (`ValidationError`) correspondente.
+///
+/// O chassi possui 17 caracteres alfanuméricos maiúsculos, exceto as letras
+/// I, O e Q, e o dígito verificador (0 a 9 ou X) ocupa a 9ª posição. O dígito
+/// verificador é obrigatório na América do Norte, mas nem todos os
+/// fabricantes brasileiros o utilizam; para validar apenas a estrutura do
+/// chassi, utilize `check_structure`.
+///
+/// ## Exemplos
+///
+/// Chassis válidos:
+/// ```
+/// use brado::chassi;
+///
+/// let result = chassi::check("9BWZZZ372VT004251"); // Ok(())
+/// assert!(result.is_ok());
+///
+/// let result = chassi::check("9BW ZZZ372 VT004251"); // Ok(())
+/// assert!(result.is_ok());
+/// ```
+///
+/// Chassis inválidos:
+/// ```
+/// use brado::chassi;
+/// use brado::common::ValidationError;
+///
+/// let result = chassi::check("9BWZZZ373VT004251");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::CheckDigitMismatch {
+///         expected: String::from("2"),
+///         found: String::from("3"),
+///     }),
+/// );
+///
+/// let result = chassi::check("9BWZZZ372VT0O4251");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::InvalidCharacter {
+///         position: 12,
+///         character: 'O',
+///     }),
+/// );
+/// ```
+pub fn check(doc: &str) -> Result<(), ValidationError> {
+    let values: Vec<u16> =
+        check_format(doc, CHASSI_SIZE, is_masked, transliterate)?;
+
+    let base: Vec<u16> = [
+        &values[..CHECK_DIGIT_POSITION],
+        &values[CHECK_DIGIT_POSITION + 1..],
+    ]
+    .concat();
+    let expected: char = generate_digit(&base);
+    let found: char = unmask(doc)
+        .chars()
+        .nth(CHECK_DIGIT_POSITION)
+        .expect("Invalid chassi!");
+
+    if expected == found {
+        return Ok(());
+    }
+
+    Err(ValidationError::CheckDigitMismatch {
+        expected: expected.to_string(),
+        found: found.to_string(),
+    })
+}
+
+/// Realiza validação da estrutura de chassi, máscarado ou não, sem verificar
+/// o dígito verificador.
+/// Retorna `true` se o argumento `doc` possuir a estrutura de um chassi, caso
+/// contrário, retorna `false`.
+///
+/// ## Exemplos
+///
+/// Chassis válidos:
+/// ```
+/// use brado::chassi;
+///
+/// let result = chassi::validate_structure("9BWZZZ373VT004251"); // true
+/// assert!(result);
+///
+/// let result = chassi::validate_structure("9BD17164G95302640"); // true
+/// assert!(result);
+/// ```
+///
+/// Chassis inválidos:
+/// ```
+/// use brado::chassi;
+///
+/// let result = chassi::validate_structure("9BWZZZ372VT0O4251"); // false
+/// assert!(!result);
+/// ```
+pub fn validate_structure(doc: &str) -> bool {
+    check_structure(doc).is_ok()
+}
+
+/// Realiza validação da estrutura de chassi, máscarado ou não, sem verificar
+/// o dígito verificador, informando o motivo da falha.
+/// Retorna `Ok(())` se o argumento `doc` possuir a estrutura de um chassi,
+/// caso contrário, retorna o erro de validação (`ValidationError`)
+/// correspondente.
+///
+/// Aceita chassis de fabricantes que não utilizam o dígito verificador da
+/// ISO 3779 na 9ª posição, comum em veículos fabricados no Brasil.
+///
+/// ## Exemplos
+///
+/// Chassis válidos:
+/// ```
+/// use brado::chassi;
+///
+/// let result = chassi::check_structure("9BD17164G95302640"); // Ok(())
+/// assert!(result.is_ok());
+/// ```
+///
+/// Chassis inválidos:
+/// ```
+/// use brado::chassi;
+/// use brado::common::ValidationError;
+///
+/// let result = chassi::check_structure("9BWZZZ372VT0O4251");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::InvalidCharacter {
+///         position: 12,
+///         character: 'O',
+///     }),
+/// );
+/// ```
+pub fn check_structure(doc: &str) -> Result<(), ValidationError> {
+    check_format(doc, CHASSI_SIZE, is_masked, transliterate)?;
+
+    Ok(())
+}
+
+/// Calcula o dígito verificador de um chassi a partir dos 16 caracteres
+/// restantes (`base`), sem símbolos, isto é, o chassi sem a 9ª posição.
+/// Retorna o dígito verificador (0 a 9 ou X), ou o erro correspondente
+/// (`ValidationError`) caso a base seja inválida.
+///
+/// ## Exemplos
+///
+/// Bases válidas:
+/// ```
+/// use brado::chassi;
+///
+/// let base = "9BWZZZ37VT004251";
+/// let result = chassi::compute_check_digits(base); // Ok("2")
+/// assert_eq!(result, Ok(String::from("2")));
+///
+/// let doc = format!("{}{}{}", &base[..8], result.unwrap(), &base[8..]);
+/// assert!(chassi::validate(&doc)); // "9BWZZZ372VT004251"
+/// ```
+///
+/// Bases inválidas:
+/// ```
+/// use brado::chassi;
+/// use brado::common::ValidationError;
+///
+/// let result = chassi::compute_check_digits("9BWZZZ37VT00425");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::InvalidLength {
+///         expected: 16,
+///         found: 15,
+///     }),
+/// );
+/// ```
+pub fn compute_check_digits(base: &str) -> Result<String, ValidationError> {
+    let values: Vec<u16> =
+        check_format(base, CHASSI_SIZE - 1, |_| false, transliterate)?;
+
+    Ok(generate_digit(&values).to_string())
+}
+
+fn generate_digit(base: &[u16]) -> char {
+    let multipliers: [u16; 16] =
+        [8, 7, 6, 5, 4, 3, 2, 10, 9, 8, 7, 6, 5, 4, 3, 2];
+
+    let sum: u16 = base
+        .iter()
+        .enumerate()
+        .map(|(i, x)| x * multipliers[i])
+        .sum();
+
+    match sum % 11 {
+        10 => 'X',
+        rest => char::from_digit(rest as u32, 10).expect("Invalid digit!"),
+    }
+}
+
+/// Verifica se o argumento `doc` pode ser um chassi sem símbolos.
+/// Se for, retorna `true`, caso contrário, retorna `false`.
+///
+/// ## Exemplos
+///
+/// Chassis válidos:
+/// ```
+/// use brado::chassi;
+///
+/// let result = chassi::is_bare("9BWZZZ372VT004251"); // true
+/// assert!(result);
+///
+/// let result = chassi::is_bare("9BW ZZZ372 VT004251"); // false
+/// assert!(!result);
+/// ```
+///
+/// Chassis inválidos:
+/// ```
+/// use brado::chassi;
+///
+/// let result = chassi::is_bare("9BWZZZ373VT004251"); // true
+/// assert!(result);
+/// ```
+pub fn is_bare(doc: &str) -> bool {
+    doc.chars().count() == CHASSI_SIZE
+        && get_digits(doc, transliterate).len() == CHASSI_SIZE
+}
+
+/// Verifica se o argumento `doc` pode ser um chassi com símbolos, isto é,
+/// com o WMI, o VDS e o VIS separados por espaços.
+/// Se for, retorna `true`, caso contrário, retorna `false`.
+///
+/// ## Exemplos
+///
+/// Chassis válidos:
+/// ```
+/// use brado::chassi;
+///
+/// let result = chassi::is_masked("9BW ZZZ372 VT004251"); // true
+/// assert!(result);
+///
+/// let result = chassi::is_masked("9BWZZZ372VT004251"); // false
+/// assert!(!result);
+/// ```
+///
+/// Chassis inválidos:
+/// ```
+/// use brado::chassi;
+///
+/// let result = chassi::is_masked("9BW ZZZ373 VT004251"); // true
+/// assert!(result);
+/// ```
+pub fn is_masked(doc: &str) -> bool {
+    let symbols: Vec<(usize, char)> = get_symbols(doc, transliterate);
+    let digits: Vec<u16> = get_digits(doc, transliterate);
+
+    if symbols.len() != 2 || digits.len() != CHASSI_SIZE {
+        return false;
+    }
+
+    symbols[0] == (3, ' ') && symbols[1] == (10, ' ')
+}
+
+/// Aplica máscara de chassi no argumento `doc` e retorna resultado,
+/// separando o WMI (3 caracteres), o VDS (6 caracteres) e o VIS (8
+/// caracteres) por espaços.
+/// O argumento deve ser uma string sem símbolos, caso contrário, deve lançar
+/// erro.
+///
+/// ## Exemplos
+///
+/// Documento de 17 caracteres sem máscara:
+/// ```
+/// use brado::chassi;
+///
+/// let result = match chassi::mask("9BWZZZ372VT004251") { // Ok("9BW ZZZ372 VT004251")
+///     Ok(doc) => doc,
+///     Err(e) => panic!("{}", e),
+/// };
+/// assert!(chassi::is_masked(&result)); // true
+/// ```
+///
+/// Documento de 17 caracteres com máscara:
+/// ```should_panic
+/// use brado::chassi;
+///
+/// let result = match chassi::mask("9BW ZZZ372 VT004251") { // It panics!
+///     Ok(doc) => doc,
+///     Err(e) => panic!("{}", e),
+/// };
+/// ```
+pub fn mask(doc: &str) -> Result<String, MaskError> {
+    check_mask(doc, DocKind::Chassi, CHASSI_SIZE, is_masked, transliterate)?;
+
+    let masked_doc: String =
+        format!("{} {} {}", &doc[0..3], &doc[3..9], &doc[9..17]);
+
+    Ok(masked_doc)
+}
+
+/// Remove os símbolos do argumento `doc` e retorna o chassi sem máscara.
+///
+/// ## Exemplo
+/// ```
+/// use brado::chassi;
+///
+/// let result = chassi::unmask("9BW ZZZ372 VT004251"); // "9BWZZZ372VT004251"
+/// assert_eq!(result, "9BWZZZ372VT004251");
+/// ```
+pub fn unmask(doc: &str) -> String {
+    doc.chars()
+        .filter(|c| transliterate(*c).is_some())
+        .collect()
+}
+
+/// Gera e retorna um chassi aleatório sem máscara, de fabricante brasileiro.
+///
+/// ## Exemplo
+/// ```
+/// use brado::chassi;
+///
+/// let result = chassi::generate(); // "9BWZZZ372VT004251"
+/// assert!(chassi::is_bare(&result)); // true
+/// ```
+pub fn generate() -> String {
+    generate_with_rng(&mut rand::thread_rng())
+}
+
+/// Gera e retorna um chassi aleatório sem máscara, de fabricante brasileiro,
+/// a partir do gerador de números aleatórios `rng`. Geradores com a mesma
+/// semente produzem o mesmo chassi.
+///
+/// ## Exemplo
+/// ```
+/// use brado::chassi;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let result = chassi::generate_with_rng(&mut StdRng::seed_from_u64(42));
+/// assert!(chassi::is_bare(&result)); // true
+///
+/// let other = chassi::generate_with_rng(&mut StdRng::seed_from_u64(42));
+/// assert_eq!(result, other);
+/// ```
+pub fn generate_with_rng<R>(rng: &mut R) -> String
+where
+    R: Rng + ?Sized,
+{
+    let year_codes: Vec<char> = YEAR_CODES.chars().collect();
+    let year_code: char = random_element_from_vector_with_rng(&year_codes, rng);
+
+    generate_from(year_code, rng)
+}
+
+/// Gera e retorna um chassi aleatório com máscara.
+///
+/// ## Exemplo
+/// ```
+/// use brado::chassi;
+///
+/// let result = chassi::generate_masked(); // "9BW ZZZ372 VT004251"
+/// assert!(chassi::is_masked(&result)); // true
+/// ```
+pub fn generate_masked() -> String {
+    generate_masked_with_rng(&mut rand::thread_rng())
+}
+
+/// Gera e retorna um chassi aleatório com máscara a partir do gerador de
+/// números aleatórios `rng`. Geradores com a mesma semente produzem o mesmo
+/// chassi.
+///
+/// ## Exemplo
+/// ```
+/// use brado::chassi;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let result = chassi::generate_masked_with_rng(&mut rng);
+/// assert!(chassi::is_masked(&result)); // true
+/// ```
+pub fn generate_masked_with_rng<R>(rng: &mut R) -> String
+where
+    R: Rng + ?Sized,
+{
+    mask(&generate_with_rng(rng)).expect("Invalid chassi!")
+}
+
+fn generate_from<R>(
+    year_code: char,
+    rng: &mut R,
+) -> String
+where
+    R: Rng + ?Sized,
+{
+    let alphabet: Vec<char> = ALPHABET.chars().collect();
+    let brazil_codes: Vec<char> = BRAZIL_CODES.chars().collect();
+    let numbers: Vec<char> = ('0'..='9').collect();
+
+    let base: String = [
+        String::from("9"),
+        random_string_from_alphabet_with_rng(1, &brazil_codes, rng),
+        random_string_from_alphabet_with_rng(6, &alphabet, rng),
+        year_code.to_string(),
+        random_string_from_alphabet_with_rng(1, &alphabet, rng),
+        random_string_from_alphabet_with_rng(6, &numbers, rng),
+    ]
+    .concat();
+    let check_digit: String =
+        compute_check_digits(&base).expect("Invalid chassi!");
+
+    format!(
+        "{}{}{}",
+        &base[..CHECK_DIGIT_POSITION],
+        check_digit,
+        &base[CHECK_DIGIT_POSITION..]
+    )
+}
+
+/// Regiões geográficas do fabricante, identificadas pelo primeiro caractere
+/// do WMI (ISO 3780).
+///
+/// ## Exemplo
+/// ```
+/// use brado::chassi::ManufacturerRegion;
+///
+/// let result = ManufacturerRegion::from_char('9');
+/// assert_eq!(result, Some(ManufacturerRegion::SouthAmerica));
+///
+/// let result = ManufacturerRegion::SouthAmerica.description();
+/// assert_eq!(result, "América do Sul");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ManufacturerRegion {
+    /// África (A a H).
+    Africa,
+    /// Ásia (J a R).
+    Asia,
+    /// Europa (S a Z).
+    Europe,
+    /// América do Norte (1 a 5).
+    NorthAmerica,
+    /// Oceania (6 e 7).
+    Oceania,
+    /// América do Sul (8, 9 e 0).
+    SouthAmerica,
+}
+
+impl ManufacturerRegion {
+    /// Todas as regiões geográficas.
+    pub const ALL: &'static [ManufacturerRegion] = &[
+        ManufacturerRegion::Africa,
+        ManufacturerRegion::Asia,
+        ManufacturerRegion::Europe,
+        ManufacturerRegion::NorthAmerica,
+        ManufacturerRegion::Oceania,
+        ManufacturerRegion::SouthAmerica,
+    ];
+
+    /// Retorna a região identificada pelo primeiro caractere do WMI `c`, ou
+    /// `None` caso o caractere não seja permitido no chassi.
+    pub fn from_char(c: char) -> Option<ManufacturerRegion> {
+        transliterate(c)?;
+
+        match c {
+            'A'..='H' => Some(ManufacturerRegion::Africa),
+            'J'..='R' => Some(ManufacturerRegion::Asia),
+            'S'..='Z' => Some(ManufacturerRegion::Europe),
+            '1'..='5' => Some(ManufacturerRegion::NorthAmerica),
+            '6' | '7' => Some(ManufacturerRegion::Oceania),
+            _ => Some(ManufacturerRegion::SouthAmerica),
+        }
+    }
+
+    /// Retorna o nome da região.
+    pub fn description(&self) -> &'static str {
+        match self {
+            ManufacturerRegion::Africa => "África",
+            ManufacturerRegion::Asia => "Ásia",
+            ManufacturerRegion::Europe => "Europa",
+            ManufacturerRegion::NorthAmerica => "América do Norte",
+            ManufacturerRegion::Oceania => "Oceania",
+            ManufacturerRegion::SouthAmerica => "América do Sul",
+        }
+    }
+}
+
+/// Extrai o identificador mundial do fabricante (WMI), formado pelos três
+/// primeiros caracteres de um chassi, máscarado ou não.
+/// Retorna `None` caso o argumento `doc` não possua a estrutura de um chassi
+/// (ver `check_structure`).
+///
+/// ## Exemplos
+/// ```
+/// use brado::chassi;
+///
+/// let result = chassi::wmi("9BW ZZZ372 VT004251"); // Some("9BW")
+/// assert_eq!(result, Some(String::from("9BW")));
+///
+/// let result = chassi::wmi("9BD17164G95302640"); // Some("9BD")
+/// assert_eq!(result, Some(String::from("9BD")));
+///
+/// let result = chassi::wmi("9BWZZZ372VT0O4251"); // None
+/// assert_eq!(result, None);
+/// ```
+pub fn wmi(doc: &str) -> Option<String> {
+    check_structure(doc).ok()?;
+
+    Some(unmask(doc).chars().take(3).collect())
+}
+
+/// Identifica a região geográfica do fabricante (`ManufacturerRegion`) de um
+/// chassi, máscarado ou não.
+/// Retorna `None` caso o argumento `doc` não possua a estrutura de um chassi
+/// (ver `check_structure`).
+///
+/// ## Exemplos
+/// ```
+/// use brado::chassi::{self, ManufacturerRegion};
+///
+/// let result = chassi::region("9BWZZZ372VT004251");
+/// assert_eq!(result, Some(ManufacturerRegion::SouthAmerica));
+///
+/// let result = chassi::region("1M8GDM9AXKP042788");
+/// assert_eq!(result, Some(ManufacturerRegion::NorthAmerica));
+/// ```
+pub fn region(doc: &str) -> Option<ManufacturerRegion> {
+    check_structure(doc).ok()?;
+
+    ManufacturerRegion::from_char(unmask(doc).chars().next()?)
+}
+
+/// Verifica se um chassi, máscarado ou não, foi atribuído a um fabricante
+/// brasileiro, isto é, se o WMI começa com 9A a 9E ou 93 a 99.
+/// Retorna `None` caso o argumento `doc` não possua a estrutura de um chassi
+/// (ver `check_structure`).
+///
+/// ## Exemplos
+/// ```
+/// use brado::chassi;
+///
+/// let result = chassi::is_brazilian("9BWZZZ372VT004251"); // Some(true)
+/// assert_eq!(result, Some(true));
+///
+/// let result = chassi::is_brazilian("9BD17164G95302640"); // Some(true)
+/// assert_eq!(result, Some(true));
+///
+/// let result = chassi::is_brazilian("1M8GDM9AXKP042788"); // Some(false)
+/// assert_eq!(result, Some(false));
+/// ```
+pub fn is_brazilian(doc: &str) -> Option<bool> {
+    check_structure(doc).ok()?;
+
+    let chars: Vec<char> = unmask(doc).chars().take(2).collect();
+
+    Some(chars[0] == '9' && BRAZIL_CODES.contains(chars[1]))
+}
+
+/// Extrai o ano-modelo de um chassi, máscarado ou não, codificado na 10ª
+/// posição. Os códigos se repetem a cada 30 anos (a partir de 1980), por
+/// isso é retornado o ano mais recente que não ultrapasse o ano seguinte ao
+/// ano corrente.
+/// Retorna `None` caso o argumento `doc` não possua a estrutura de um chassi
+/// (ver `check_structure`), a 10ª posição não seja um código de ano (U, Z
+/// e 0) ou o ano codificado seja posterior ao ano seguinte ao ano corrente
+/// (por exemplo, com o relógio do sistema incorreto).
+///
+/// ## Exemplos
+/// ```
+/// use brado::chassi;
+///
+/// let result = chassi::model_year("9BGKS48U9DG123456"); // Some(2013)
+/// assert_eq!(result, Some(2013));
+///
+/// let result = chassi::model_year("9BD17164G95302640"); // Some(2009)
+/// assert_eq!(result, Some(2009));
+///
+/// let result = chassi::model_year("9BWZZZ375ZT004251"); // None
+/// assert_eq!(result, None);
+/// ```
+pub fn model_year(doc: &str) -> Option<u16> {
+    check_structure(doc).ok()?;
+
+    let code: char = unmask(doc).chars().nth(YEAR_POSITION)?;
+    let offset: u16 = YEAR_CODES.chars().position(|c| c == code)? as u16;
+    let cycles: u16 = current_year()
+        .saturating_add(1)
+        .checked_sub(FIRST_YEAR + offset)?
+        / 30;
+
+    Some(FIRST_YEAR + offset + cycles * 30)
+}
+
+/// Gera e retorna um chassi aleatório sem máscara, de fabricante brasileiro,
+/// com o ano-modelo `year`.
+/// Retorna o erro de validação (`ValidationError`) correspondente caso o ano
+/// seja anterior a 1980 ou posterior ao ano seguinte ao ano corrente.
+///
+/// ## Exemplos
+/// ```
+/// use brado::chassi;
+/// use brado::common::ValidationError;
+///
+/// let result = chassi::generate_for_year(2020).unwrap();
+/// assert_eq!(chassi::model_year(&result), Some(2020));
+///
+/// let result = chassi::generate_for_year(1979);
+/// assert_eq!(result, Err(ValidationError::InvalidYear { found: 1979 }));
+/// ```
+pub fn generate_for_year(year: u16) -> Result<String, ValidationError> {
+    generate_for_year_with_rng(year, &mut rand::thread_rng())
+}
+
+/// Gera e retorna um chassi aleatório sem máscara, de fabricante brasileiro,
+/// com o ano-modelo `year`, a partir do gerador de números aleatórios `rng`.
+///
+/// ## Exemplo
+/// ```
+/// use brado::chassi;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let result = chassi::generate_for_year_with_rng(2010, &mut rng).unwrap();
+/// assert_eq!(chassi::model_year(&result), Some(2010));
+/// ```
+pub fn generate_for_year_with_rng<R>(
+    year: u16,
+    rng: &mut R,
+) -> Result<String, ValidationError>
+where
+    R: Rng + ?Sized,
+{
+    if year < FIRST_YEAR || year > current_year().saturating_add(1) {
+        return Err(ValidationError::InvalidYear { found: year });
+    }
+
+    let offset: usize = ((year - FIRST_YEAR) % 30) as usize;
+    let year_code: char =
+        YEAR_CODES.chars().nth(offset).expect("Invalid year!");
+
+    Ok(generate_from(year_code, rng))
+}
+
+/// Chassi validado, armazenado sem máscara.
+///
+/// Só pode ser construído a partir de um chassi válido, máscarado ou não,
+/// por meio de `FromStr` ou `TryFrom<&str>`. É exibido (`Display`) com
+/// máscara.
+///
+/// ## Exemplo
+/// ```
+/// use brado::chassi::{Chassi, ManufacturerRegion};
+///
+/// let doc: Chassi = "9BWZZZ372VT004251".parse().unwrap();
+/// assert_eq!(doc.as_bare(), "9BWZZZ372VT004251");
+/// assert_eq!(doc.to_masked(), "9BW ZZZ372 VT004251");
+/// assert_eq!(doc.to_string(), "9BW ZZZ372 VT004251");
+/// assert_eq!(doc.wmi(), "9BW");
+/// assert_eq!(doc.region(), ManufacturerRegion::SouthAmerica);
+///
+/// let result = "9BWZZZ373VT004251".parse::<Chassi>();
+/// assert!(result.is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Chassi(String);
+
+impl Chassi {
+    /// Retorna o chassi sem máscara.
+    pub fn as_bare(&self) -> &str {
+        &self.0
+    }
+
+    /// Retorna o chassi com máscara.
+    pub fn to_masked(&self) -> String {
+        mask(&self.0).expect("Invalid chassi!")
+    }
+
+    /// Retorna o identificador mundial do fabricante (WMI).
+    pub fn wmi(&self) -> String {
+        wmi(&self.0).expect("Invalid chassi!")
+    }
+
+    /// Retorna a região geográfica do fabricante.
+    pub fn region(&self) -> ManufacturerRegion {
+        region(&self.0).expect("Invalid chassi!")
+    }
+
+    /// Retorna o ano-modelo, ou `None` caso a 10ª posição não seja um código
+    /// de ano.
+    pub fn model_year(&self) -> Option<u16> {
+        model_year(&self.0)
+    }
+}
+
+impl FromStr for Chassi {
+    type Err = ValidationError;
+
+    fn from_str(doc: &str) -> Result<Self, Self::Err> {
+        check(doc)?;
+        Ok(Chassi(unmask(doc)))
+    }
+}
+
+impl TryFrom<&str> for Chassi {
+    type Error = ValidationError;
+
+    fn try_from(doc: &str) -> Result<Self, Self::Error> {
+        doc.parse()
+    }
+}
+
+impl fmt::Display for Chassi {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(f, "{}", self.to_masked())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Chassi {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_bare())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Chassi {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let doc: String = String::deserialize(deserializer)?;
+        doc.parse().map_err(de::Error::custom)
+    }
+}
+
+/// Tipo marcador do chassi.
+/// Implementa o trait `Document` a partir das funções deste módulo.
+///
+/// ## Exemplo
+/// ```
+/// use brado::chassi::ChassiDocument;
+/// use brado::common::Document;
+///
+/// let result = ChassiDocument.validate("9BW ZZZ372 VT004251"); // true
+/// assert!(result);
+///
+/// let result = ChassiDocument.bare_size(); // 17
+/// assert_eq!(result, 17);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ChassiDocument;
+
+impl Document for ChassiDocument {
+    fn kind(&self) -> DocKind {
+        DocKind::Chassi
+    }
+
+    fn bare_size(&self) -> usize {
+        CHASSI_SIZE
+    }
+
+    fn check(
+        &self,
+        doc: &str,
+    ) -> Result<(), ValidationError> {
+        check(doc)
+    }
+
+    fn is_bare(
+        &self,
+        doc: &str,
+    ) -> bool {
+        is_bare(doc)
+    }
+
+    fn is_masked(
+        &self,
+        doc: &str,
+    ) -> bool {
+        is_masked(doc)
+    }
+
+    fn mask(
+        &self,
+        doc: &str,
+    ) -> Result<String, MaskError> {
+        mask(doc)
+    }
+
+    fn unmask(
+        &self,
+        doc: &str,
+    ) -> String {
+        unmask(doc)
+    }
+
+    fn generate(&self) -> String {
+        generate()
+    }
+
+    fn generate_masked(&self) -> String {
+        generate_masked()
+    }
+
+    fn generate_with_rng(
+        &self,
+        rng: &mut dyn RngCore,
+    ) -> String {
+        generate_with_rng(rng)
+    }
+
+    fn generate_masked_with_rng(
+        &self,
+        rng: &mut dyn RngCore,
+    ) -> String {
+        generate_masked_with_rng(rng)
+    }
+}
@@ -1,8 +1,16 @@
 //! Utilitários para validação de Carteira Nacional de Habilitação (CNH).
 
 use crate::common::{
-    get_digits, get_symbols, is_repeated, random_decimal_vector, to_decimal,
+    check_digits, check_format, check_mask, get_digits, get_symbols,
+    is_repeated, random_decimal_vector_with_rng, to_decimal, Document,
+    MaskError, ValidationError,
 };
+use crate::docs::DocKind;
+use rand::{Rng, RngCore};
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
 
 const CNH_SIZE: usize = 11;
 
@@ -34,21 +42,114 @@ const CNH_SIZE: usize = 11;
 /// assert!(!result);
 /// ```
 pub fn validate(doc: &str) -> bool {
-    let size: usize = doc.chars().count();
-
-    if size != CNH_SIZE && !is_masked(doc) {
-        return false;
-    }
+    check(doc).is_ok()
+}
 
-    let digits: Vec<u16> = get_digits(doc, to_decimal);
+/// Realiza validação de CNH, máscarada ou não, informando o motivo da falha.
+/// Retorna `Ok(())` se o argumento `doc` for uma CNH válida, caso contrário,
+/// retorna o erro de validação (`ValidationError`) correspondente.
+///
+/// ## Exemplos
+///
+/// CNHs válidas:
+/// ```
+/// use brado::cnh;
+///
+/// let result = cnh::check("84718735264"); // Ok(())
+/// assert!(result.is_ok());
+///
+/// let result = cnh::check("847 187 352 64"); // Ok(())
+/// assert!(result.is_ok());
+/// ```
+///
+/// CNHs inválidas:
+/// ```
+/// use brado::cnh;
+/// use brado::common::ValidationError;
+///
+/// let result = cnh::check("84718735265");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::CheckDigitMismatch {
+///         expected: String::from("64"),
+///         found: String::from("65"),
+///     }),
+/// );
+///
+/// let result = cnh::check("847187352");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::InvalidLength {
+///         expected: 11,
+///         found: 9,
+///     }),
+/// );
+/// ```
+pub fn check(doc: &str) -> Result<(), ValidationError> {
+    let digits: Vec<u16> = check_format(doc, CNH_SIZE, is_masked, to_decimal)?;
 
-    if digits.len() != CNH_SIZE || is_repeated(&digits) {
-        return false;
+    if is_repeated(&digits) {
+        return Err(ValidationError::RepeatedDigits);
     }
 
     let (d10, d11): (u16, u16) = generate_digits(&digits[..9]);
 
-    (d10, d11) == (digits[9], digits[10])
+    check_digits(&[d10, d11], &digits[9..])
+}
+
+/// Calcula os dígitos verificadores de uma CNH a partir dos 9 primeiros
+/// dígitos (`base`), sem símbolos.
+/// Retorna os 2 dígitos verificadores, ou o erro correspondente
+/// (`ValidationError`) caso a base seja inválida.
+///
+/// ## Exemplos
+///
+/// Bases válidas:
+/// ```
+/// use brado::cnh;
+///
+/// let base = "847187352";
+/// let result = cnh::compute_check_digits(base); // Ok("64")
+/// assert_eq!(result, Ok(String::from("64")));
+///
+/// let doc = format!("{}{}", base, result.unwrap()); // "84718735264"
+/// assert!(cnh::validate(&doc));
+/// ```
+///
+/// Bases inválidas:
+/// ```
+/// use brado::common::ValidationError;
+/// use brado::cnh;
+///
+/// let result = cnh::compute_check_digits("84718735");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::InvalidLength {
+///         expected: 9,
+///         found: 8,
+///     }),
+/// );
+///
+/// let result = cnh::compute_check_digits("847 18735");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::InvalidCharacter {
+///         position: 3,
+///         character: ' ',
+///     }),
+/// );
+/// ```
+pub fn compute_check_digits(base: &str) -> Result<String, ValidationError> {
+    let digits: Vec<u16> =
+        check_format(base, CNH_SIZE - 2, |_| false, to_decimal)?;
+
+    let (d10, d11): (u16, u16) = generate_digits(&digits);
+
+    if is_repeated(&[&digits[..], &[d10, d11]].concat()) {
+        return Err(ValidationError::RepeatedDigits);
+    }
+
+    Ok(format!("{}{}", d10, d11))
 }
 
 fn generate_digits(doc_slice: &[u16]) -> (u16, u16) {
@@ -183,10 +284,8 @@ pub fn is_masked(doc: &str) -> bool {
 ///     Err(e) => panic!("{}", e),
 /// };
 /// ```
-pub fn mask(doc: &str) -> Result<String, &'static str> {
-    if !is_bare(doc) {
-        return Err("The given string cannot be masked as CNH!");
-    }
+pub fn mask(doc: &str) -> Result<String, MaskError> {
+    check_mask(doc, DocKind::Cnh, CNH_SIZE, is_masked, to_decimal)?;
 
     let masked_doc: String = format!(
         "{} {} {} {}",
@@ -199,6 +298,19 @@ pub fn mask(doc: &str) -> Result<String, &'static str> {
     Ok(masked_doc)
 }
 
+/// Remove os símbolos do argumento `doc` e retorna a CNH sem máscara.
+///
+/// ## Exemplo
+/// ```
+/// use brado::cnh;
+///
+/// let result = cnh::unmask("847 187 352 64"); // "84718735264"
+/// assert_eq!(result, "84718735264");
+/// ```
+pub fn unmask(doc: &str) -> String {
+    crate::common::unmask(doc, to_decimal)
+}
+
 /// Gera e retorna uma CNH aleatório sem máscara.
 ///
 /// ## Exemplo
@@ -209,7 +321,30 @@ pub fn mask(doc: &str) -> Result<String, &'static str> {
 /// assert!(cnh::is_bare(&result)); // true
 /// ```
 pub fn generate() -> String {
-    let mut cnh: Vec<u16> = random_decimal_vector(9);
+    generate_with_rng(&mut rand::thread_rng())
+}
+
+/// Gera e retorna uma CNH aleatória sem máscara a partir do gerador de
+/// números aleatórios `rng`. Geradores com a mesma semente produzem a mesma
+/// CNH.
+///
+/// ## Exemplo
+/// ```
+/// use brado::cnh;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let result = cnh::generate_with_rng(&mut StdRng::seed_from_u64(42));
+/// assert!(cnh::is_bare(&result)); // true
+///
+/// let other = cnh::generate_with_rng(&mut StdRng::seed_from_u64(42));
+/// assert_eq!(result, other);
+/// ```
+pub fn generate_with_rng<R>(rng: &mut R) -> String
+where
+    R: Rng + ?Sized,
+{
+    let mut cnh: Vec<u16> = random_decimal_vector_with_rng(9, rng);
     let (d10, dsc): (u16, u16) = generate_first_digit(&cnh);
     cnh.push(d10);
     let d11: u16 = generate_second_digit(&cnh, dsc);
@@ -231,5 +366,192 @@ pub fn generate() -> String {
 /// assert!(cnh::is_masked(&result)); // true
 /// ```
 pub fn generate_masked() -> String {
-    mask(&generate()).expect("Invalid CNH!")
+    generate_masked_with_rng(&mut rand::thread_rng())
+}
+
+/// Gera e retorna uma CNH aleatória com máscara a partir do gerador de
+/// números aleatórios `rng`. Geradores com a mesma semente produzem a mesma
+/// CNH.
+///
+/// ## Exemplo
+/// ```
+/// use brado::cnh;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let result = cnh::generate_masked_with_rng(&mut rng);
+/// assert!(cnh::is_masked(&result)); // true
+/// ```
+pub fn generate_masked_with_rng<R>(rng: &mut R) -> String
+where
+    R: Rng + ?Sized,
+{
+    mask(&generate_with_rng(rng)).expect("Invalid CNH!")
+}
+
+/// CNH validada, armazenada sem máscara.
+///
+/// Só pode ser construída a partir de uma CNH válida, máscarada ou não, por
+/// meio de `FromStr` ou `TryFrom<&str>`. É exibida (`Display`) com máscara.
+///
+/// ## Exemplo
+/// ```
+/// use brado::cnh::Cnh;
+///
+/// let doc: Cnh = "847 187 352 64".parse().unwrap();
+/// assert_eq!(doc.as_bare(), "84718735264");
+/// assert_eq!(doc.to_masked(), "847 187 352 64");
+/// assert_eq!(doc.to_string(), "847 187 352 64");
+///
+/// let result = "847 187 352 65".parse::<Cnh>();
+/// assert!(result.is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Cnh(String);
+
+impl Cnh {
+    /// Retorna a CNH sem máscara.
+    pub fn as_bare(&self) -> &str {
+        &self.0
+    }
+
+    /// Retorna a CNH com máscara.
+    pub fn to_masked(&self) -> String {
+        mask(&self.0).expect("Invalid CNH!")
+    }
+}
+
+impl FromStr for Cnh {
+    type Err = ValidationError;
+
+    fn from_str(doc: &str) -> Result<Self, Self::Err> {
+        check(doc)?;
+        Ok(Cnh(unmask(doc)))
+    }
+}
+
+impl TryFrom<&str> for Cnh {
+    type Error = ValidationError;
+
+    fn try_from(doc: &str) -> Result<Self, Self::Error> {
+        doc.parse()
+    }
+}
+
+impl fmt::Display for Cnh {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(f, "{}", self.to_masked())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Cnh {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_bare())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Cnh {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let doc: String = String::deserialize(deserializer)?;
+        doc.parse().map_err(de::Error::custom)
+    }
+}
+
+/// Tipo marcador da CNH.
+/// Implementa o trait `Document` a partir das funções deste módulo.
+///
+/// ## Exemplo
+/// ```
+/// use brado::common::Document;
+/// use brado::cnh::CnhDocument;
+///
+/// let result = CnhDocument.validate("847 187 352 64"); // true
+/// assert!(result);
+///
+/// let result = CnhDocument.bare_size(); // 11
+/// assert_eq!(result, 11);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CnhDocument;
+
+impl Document for CnhDocument {
+    fn kind(&self) -> DocKind {
+        DocKind::Cnh
+    }
+
+    fn bare_size(&self) -> usize {
+        CNH_SIZE
+    }
+
+    fn check(
+        &self,
+        doc: &str,
+    ) -> Result<(), ValidationError> {
+        check(doc)
+    }
+
+    fn is_bare(
+        &self,
+        doc: &str,
+    ) -> bool {
+        is_bare(doc)
+    }
+
+    fn is_masked(
+        &self,
+        doc: &str,
+    ) -> bool {
+        is_masked(doc)
+    }
+
+    fn mask(
+        &self,
+        doc: &str,
+    ) -> Result<String, MaskError> {
+        mask(doc)
+    }
+
+    fn unmask(
+        &self,
+        doc: &str,
+    ) -> String {
+        unmask(doc)
+    }
+
+    fn generate(&self) -> String {
+        generate()
+    }
+
+    fn generate_masked(&self) -> String {
+        generate_masked()
+    }
+
+    fn generate_with_rng(
+        &self,
+        rng: &mut dyn RngCore,
+    ) -> String {
+        generate_with_rng(rng)
+    }
+
+    fn generate_masked_with_rng(
+        &self,
+        rng: &mut dyn RngCore,
+    ) -> String {
+        generate_masked_with_rng(rng)
+    }
 }
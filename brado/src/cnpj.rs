@@ -1,9 +1,22 @@
 //! Utilitários para validação de Cadastro Nacional de Pessoa Jurídica (CNPJ).
 use crate::common::{
-    get_digits, get_symbols, random_string_from_alphabet, to_decimal,
+    check_format, check_mask, get_digits, get_symbols, is_repeated,
+    random_decimal_vector_with_rng, random_element_from_vector_with_rng,
+    random_string_from_alphabet_with_rng, to_decimal, Document, MaskError,
+    ValidationError,
 };
+use crate::docs::DocKind;
+use rand::{Rng, RngCore};
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
 
 const CNPJ_SIZE: usize = 14;
+const RAIZ_SIZE: usize = 8;
+const ORDEM_SIZE: usize = 4;
+const MATRIZ_ORDEM: &str = "0001";
+const NULL_ORDEM: &str = "0000";
 
 /// Converte um caractere em um dígito válido de CNPJ.
 ///
@@ -14,11 +27,32 @@ const CNPJ_SIZE: usize = 14;
 /// substituir os caracteres pelos valores respectivos
 /// da tabela ASCII e dele subtrair 48. Assim, '0'=0,
 /// '1'=1, ..., 'A'=17, 'B'=18, ...
+///
+/// Apenas dígitos e letras maiúsculas (`0-9A-Z`) são aceitos; qualquer outro
+/// caractere é tratado como símbolo.
 fn to_cnpj_digit(c: char) -> Option<u16> {
-    let n = c as u16;
-    match n >= 48 {
-        true => Some(n - 48),
-        false => to_decimal(c),
+    match c {
+        '0'..='9' | 'A'..='Z' => Some(c as u16 - 48),
+        _ => None,
+    }
+}
+
+/// Verifica se as posições dos dígitos verificadores (13 e 14) possuem apenas
+/// dígitos numéricos.
+fn check_verifier_characters(doc: &str) -> Result<(), ValidationError> {
+    let letter: Option<(usize, char)> = doc
+        .chars()
+        .enumerate()
+        .filter(|(_, c)| to_cnpj_digit(*c).is_some())
+        .skip(12)
+        .find(|(_, c)| !c.is_ascii_digit());
+
+    match letter {
+        Some((position, character)) => Err(ValidationError::InvalidCharacter {
+            position,
+            character,
+        }),
+        None => Ok(()),
     }
 }
 
@@ -50,27 +84,182 @@ fn to_cnpj_digit(c: char) -> Option<u16> {
 /// assert!(!result);
 /// ```
 pub fn validate(doc: &str) -> bool {
-    let size: usize = doc.chars().count();
+    check(doc).is_ok()
+}
 
-    if size != CNPJ_SIZE && !is_masked(doc) {
-        return false;
-    }
+/// Realiza validação de CNPJ, máscarado ou não, informando o motivo da falha.
+/// Retorna `Ok(())` se o argumento `doc` for um CNPJ válido, caso contrário,
+/// retorna o erro de validação (`ValidationError`) correspondente.
+///
+/// ## Exemplos
+///
+/// CNPJs válidos:
+/// ```
+/// use brado::cnpj;
+///
+/// let result = cnpj::check("05200851000100"); // Ok(())
+/// assert!(result.is_ok());
+///
+/// let result = cnpj::check("A5.200.851/0001-23"); // Ok(())
+/// assert!(result.is_ok());
+/// ```
+///
+/// CNPJs inválidos:
+/// ```
+/// use brado::cnpj;
+/// use brado::common::ValidationError;
+///
+/// let result = cnpj::check("05200851000101");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::CheckDigitMismatch {
+///         expected: String::from("00"),
+///         found: String::from("01"),
+///     }),
+/// );
+///
+/// let result = cnpj::check("00.000.000/0000-00");
+/// assert_eq!(result, Err(ValidationError::RepeatedDigits));
+///
+/// let result = cnpj::check("12abc34501de35");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::InvalidCharacter {
+///         position: 2,
+///         character: 'a',
+///     }),
+/// );
+///
+/// let result = cnpj::check("12ABC34501DE3A");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::InvalidCharacter {
+///         position: 13,
+///         character: 'A',
+///     }),
+/// );
+/// ```
+pub fn check(doc: &str) -> Result<(), ValidationError> {
+    let digits: Vec<u16> =
+        check_format(doc, CNPJ_SIZE, is_masked, to_cnpj_digit)?;
 
-    let digits: Vec<u16> = get_digits(doc, to_cnpj_digit);
+    check_verifier_characters(doc)?;
 
-    if digits.len() != CNPJ_SIZE {
-        return false;
+    if is_repeated(&digits) {
+        return Err(ValidationError::RepeatedDigits);
     }
 
-    for i in 0..=9 {
-        if digits.iter().filter(|&n| *n == i).count() == CNPJ_SIZE {
-            return false;
-        }
+    let (d13, d14): (u16, u16) = generate_digits(&digits[..12]);
+
+    if (d13, d14) == (digits[12], digits[13]) {
+        return Ok(());
     }
 
-    let (d13, d14): (u16, u16) = generate_digits(&digits[..12]);
+    let found: String = doc
+        .chars()
+        .filter(|c| to_cnpj_digit(*c).is_some())
+        .skip(12)
+        .collect();
+
+    Err(ValidationError::CheckDigitMismatch {
+        expected: format!("{}{}", d13, d14),
+        found,
+    })
+}
+
+/// Realiza validação de CNPJ, máscarado ou não, sem diferenciar letras
+/// maiúsculas de minúsculas.
+/// Retorna `true` se o argumento `doc`, convertido para maiúsculas, for um
+/// CNPJ válido, caso contrário, retorna `false`.
+///
+/// ## Exemplos
+/// ```
+/// use brado::cnpj;
+///
+/// let result = cnpj::validate_case_insensitive("12.abc.345/01de-35"); // true
+/// assert!(result);
+///
+/// let result = cnpj::validate("12.abc.345/01de-35"); // false
+/// assert!(!result);
+/// ```
+pub fn validate_case_insensitive(doc: &str) -> bool {
+    check_case_insensitive(doc).is_ok()
+}
+
+/// Realiza validação de CNPJ, máscarado ou não, sem diferenciar letras
+/// maiúsculas de minúsculas, informando o motivo da falha.
+/// Retorna `Ok(())` se o argumento `doc`, convertido para maiúsculas, for um
+/// CNPJ válido, caso contrário, retorna o erro de validação
+/// (`ValidationError`) correspondente.
+///
+/// ## Exemplos
+/// ```
+/// use brado::cnpj;
+/// use brado::common::ValidationError;
+///
+/// let result = cnpj::check_case_insensitive("12abc34501de35"); // Ok(())
+/// assert!(result.is_ok());
+///
+/// let result = cnpj::check_case_insensitive("12abc34501de36");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::CheckDigitMismatch {
+///         expected: String::from("35"),
+///         found: String::from("36"),
+///     }),
+/// );
+/// ```
+pub fn check_case_insensitive(doc: &str) -> Result<(), ValidationError> {
+    check(&doc.to_ascii_uppercase())
+}
+
+/// Calcula os dígitos verificadores de um CNPJ a partir dos 12 primeiros
+/// caracteres (`base`), sem símbolos. A base pode ser alfanumérica.
+/// Retorna os 2 dígitos verificadores, ou o erro correspondente
+/// (`ValidationError`) caso a base seja inválida.
+///
+/// ## Exemplos
+///
+/// Bases válidas:
+/// ```
+/// use brado::cnpj;
+///
+/// let base = "052008510001";
+/// let result = cnpj::compute_check_digits(base); // Ok("00")
+/// assert_eq!(result, Ok(String::from("00")));
+///
+/// let doc = format!("{}{}", base, result.unwrap()); // "05200851000100"
+/// assert!(cnpj::validate(&doc));
+///
+/// let result = cnpj::compute_check_digits("12ABC34501DE"); // Ok("35")
+/// assert_eq!(result, Ok(String::from("35")));
+/// ```
+///
+/// Bases inválidas:
+/// ```
+/// use brado::cnpj;
+/// use brado::common::ValidationError;
+///
+/// let result = cnpj::compute_check_digits("05200851");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::InvalidLength {
+///         expected: 12,
+///         found: 8,
+///     }),
+/// );
+/// ```
+pub fn compute_check_digits(base: &str) -> Result<String, ValidationError> {
+    let digits: Vec<u16> =
+        check_format(base, CNPJ_SIZE - 2, |_| false, to_cnpj_digit)?;
+
+    let (d13, d14): (u16, u16) = generate_digits(&digits);
+
+    if is_repeated(&[&digits[..], &[d13, d14]].concat()) {
+        return Err(ValidationError::RepeatedDigits);
+    }
 
-    (d13, d14) == (digits[12], digits[13])
+    Ok(format!("{}{}", d13, d14))
 }
 
 fn generate_digits(doc_slice: &[u16]) -> (u16, u16) {
@@ -155,7 +344,10 @@ pub fn is_bare(doc: &str) -> bool {
 /// assert!(result);
 /// ```
 pub fn is_masked(doc: &str) -> bool {
-    let div: usize = doc.chars().count() - 2;
+    let div: usize = match doc.char_indices().rev().nth(1) {
+        Some((i, _)) => i,
+        None => return false,
+    };
     let doc_slice1 = &doc[..div];
     let doc_slice2 = &doc[div..];
 
@@ -204,10 +396,8 @@ pub fn is_masked(doc: &str) -> bool {
 ///     Err(e) => panic!("{}", e),
 /// };
 /// ```
-pub fn mask(doc: &str) -> Result<String, &'static str> {
-    if !is_bare(doc) {
-        return Err("The given string cannot be masked as CNPJ!");
-    }
+pub fn mask(doc: &str) -> Result<String, MaskError> {
+    check_mask(doc, DocKind::Cnpj, CNPJ_SIZE, is_masked, to_cnpj_digit)?;
 
     let masked_doc: String = format!(
         "{}.{}.{}/{}-{}",
@@ -221,12 +411,34 @@ pub fn mask(doc: &str) -> Result<String, &'static str> {
     Ok(masked_doc)
 }
 
+/// Remove os símbolos do argumento `doc` e retorna o CNPJ sem máscara.
+///
+/// ## Exemplo
+/// ```
+/// use brado::cnpj;
+///
+/// let result = cnpj::unmask("05.200.851/0001-00"); // "05200851000100"
+/// assert_eq!(result, "05200851000100");
+/// ```
+pub fn unmask(doc: &str) -> String {
+    doc.chars()
+        .filter(|c| to_cnpj_digit(*c).is_some())
+        .collect()
+}
+
+fn letters() -> Vec<char> {
+    ('A'..='Z').collect()
+}
+
 fn alphabet() -> Vec<char> {
-    vec![
-        '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'A', 'B', 'C', 'D',
-        'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S',
-        'T', 'U', 'V', 'W', 'X', 'Y', 'Z',
-    ]
+    ('0'..='9').chain(letters()).collect()
+}
+
+fn with_check_digits(base: String) -> String {
+    let digits: Vec<u16> = get_digits(&base, to_cnpj_digit);
+    let (d13, d14): (u16, u16) = generate_digits(&digits);
+
+    [base, d13.to_string(), d14.to_string()].concat()
 }
 
 /// Gera e retorna um CNPJ aleatório sem máscara.
@@ -239,11 +451,33 @@ fn alphabet() -> Vec<char> {
 /// assert!(cnpj::is_bare(&result)); // true
 /// ```
 pub fn generate() -> String {
-    let cnpj: String = random_string_from_alphabet(12, &alphabet());
-    let digits: Vec<u16> = get_digits(&cnpj, to_cnpj_digit);
-    let (d13, d14): (u16, u16) = generate_digits(&digits);
+    generate_with_rng(&mut rand::thread_rng())
+}
 
-    [cnpj, d13.to_string(), d14.to_string()].concat()
+/// Gera e retorna um CNPJ aleatório sem máscara a partir do gerador de
+/// números aleatórios `rng`. Geradores com a mesma semente produzem o mesmo
+/// CNPJ.
+///
+/// ## Exemplo
+/// ```
+/// use brado::cnpj;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let result = cnpj::generate_with_rng(&mut StdRng::seed_from_u64(42));
+/// assert!(cnpj::is_bare(&result)); // true
+///
+/// let other = cnpj::generate_with_rng(&mut StdRng::seed_from_u64(42));
+/// assert_eq!(result, other);
+/// ```
+pub fn generate_with_rng<R>(rng: &mut R) -> String
+where
+    R: Rng + ?Sized,
+{
+    let cnpj: String =
+        random_string_from_alphabet_with_rng(12, &alphabet(), rng);
+
+    with_check_digits(cnpj)
 }
 
 /// Gera e retorna um CNPJ aleatório com máscara.
@@ -256,5 +490,503 @@ pub fn generate() -> String {
 /// assert!(cnpj::is_masked(&result)); // true
 /// ```
 pub fn generate_masked() -> String {
-    mask(&generate()).expect("Invalid CNPJ!")
+    generate_masked_with_rng(&mut rand::thread_rng())
+}
+
+/// Gera e retorna um CNPJ aleatório com máscara a partir do gerador de
+/// números aleatórios `rng`. Geradores com a mesma semente produzem o mesmo
+/// CNPJ.
+///
+/// ## Exemplo
+/// ```
+/// use brado::cnpj;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let result = cnpj::generate_masked_with_rng(&mut rng);
+/// assert!(cnpj::is_masked(&result)); // true
+/// ```
+pub fn generate_masked_with_rng<R>(rng: &mut R) -> String
+where
+    R: Rng + ?Sized,
+{
+    mask(&generate_with_rng(rng)).expect("Invalid CNPJ!")
+}
+
+/// Formatos de CNPJ: numérico (apenas dígitos) ou alfanumérico (com ao
+/// menos uma letra na raiz ou no número de ordem).
+///
+/// ## Exemplo
+/// ```
+/// use brado::cnpj::{self, CnpjFormat};
+///
+/// let result = cnpj::detect_format("05.200.851/0001-00");
+/// assert_eq!(result, Some(CnpjFormat::Numeric));
+///
+/// let result = cnpj::detect_format("12.ABC.345/01DE-35");
+/// assert_eq!(result, Some(CnpjFormat::Alphanumeric));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CnpjFormat {
+    /// CNPJ composto apenas por dígitos.
+    Numeric,
+    /// CNPJ com ao menos uma letra nos 12 primeiros caracteres.
+    Alphanumeric,
+}
+
+/// Identifica o formato (`CnpjFormat`) de um CNPJ válido, máscarado ou não.
+/// Retorna `None` caso o argumento `doc` não seja um CNPJ válido.
+///
+/// ## Exemplos
+/// ```
+/// use brado::cnpj::{self, CnpjFormat};
+///
+/// let result = cnpj::detect_format("05200851000100");
+/// assert_eq!(result, Some(CnpjFormat::Numeric));
+///
+/// let result = cnpj::detect_format("12ABC34501DE35");
+/// assert_eq!(result, Some(CnpjFormat::Alphanumeric));
+///
+/// let result = cnpj::detect_format("05200851000101");
+/// assert_eq!(result, None);
+/// ```
+pub fn detect_format(doc: &str) -> Option<CnpjFormat> {
+    check(doc).ok()?;
+
+    match unmask(doc).chars().all(|c| c.is_ascii_digit()) {
+        true => Some(CnpjFormat::Numeric),
+        false => Some(CnpjFormat::Alphanumeric),
+    }
+}
+
+/// Gera e retorna um CNPJ numérico (apenas dígitos) aleatório sem máscara.
+///
+/// ## Exemplo
+/// ```
+/// use brado::cnpj::{self, CnpjFormat};
+///
+/// let result = cnpj::generate_numeric(); // "05200851000100"
+/// assert_eq!(cnpj::detect_format(&result), Some(CnpjFormat::Numeric));
+/// ```
+pub fn generate_numeric() -> String {
+    generate_numeric_with_rng(&mut rand::thread_rng())
+}
+
+/// Gera e retorna um CNPJ numérico (apenas dígitos) aleatório sem máscara a
+/// partir do gerador de números aleatórios `rng`.
+///
+/// ## Exemplo
+/// ```
+/// use brado::cnpj::{self, CnpjFormat};
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let result = cnpj::generate_numeric_with_rng(&mut rng);
+/// assert_eq!(cnpj::detect_format(&result), Some(CnpjFormat::Numeric));
+/// ```
+pub fn generate_numeric_with_rng<R>(rng: &mut R) -> String
+where
+    R: Rng + ?Sized,
+{
+    let cnpj: String = random_decimal_vector_with_rng(12, rng)
+        .iter()
+        .map(|d| d.to_string())
+        .collect::<Vec<String>>()
+        .concat();
+
+    with_check_digits(cnpj)
+}
+
+/// Gera e retorna um CNPJ alfanumérico aleatório sem máscara, com ao menos
+/// uma letra nos 12 primeiros caracteres.
+///
+/// ## Exemplo
+/// ```
+/// use brado::cnpj::{self, CnpjFormat};
+///
+/// let result = cnpj::generate_alphanumeric(); // "12ABC34501DE35"
+/// assert_eq!(cnpj::detect_format(&result), Some(CnpjFormat::Alphanumeric));
+/// ```
+pub fn generate_alphanumeric() -> String {
+    generate_alphanumeric_with_rng(&mut rand::thread_rng())
+}
+
+/// Gera e retorna um CNPJ alfanumérico aleatório sem máscara, com ao menos
+/// uma letra nos 12 primeiros caracteres, a partir do gerador de números
+/// aleatórios `rng`.
+///
+/// ## Exemplo
+/// ```
+/// use brado::cnpj::{self, CnpjFormat};
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let result = cnpj::generate_alphanumeric_with_rng(&mut rng);
+/// assert_eq!(cnpj::detect_format(&result), Some(CnpjFormat::Alphanumeric));
+/// ```
+pub fn generate_alphanumeric_with_rng<R>(rng: &mut R) -> String
+where
+    R: Rng + ?Sized,
+{
+    let mut cnpj: Vec<char> =
+        random_string_from_alphabet_with_rng(12, &alphabet(), rng)
+            .chars()
+            .collect();
+
+    if !cnpj.iter().any(|c| c.is_ascii_alphabetic()) {
+        let idx: usize = rng.gen_range(0..cnpj.len());
+        cnpj[idx] = random_element_from_vector_with_rng(&letters(), rng);
+    }
+
+    with_check_digits(cnpj.into_iter().collect())
+}
+
+/// Partes de um CNPJ: raiz (8 caracteres), número de ordem (4 caracteres) e
+/// dígitos verificadores (2 dígitos), todos sem máscara.
+///
+/// A raiz identifica a empresa e o número de ordem identifica o
+/// estabelecimento: `0001` corresponde à matriz e os demais às filiais.
+///
+/// ## Exemplo
+/// ```
+/// use brado::cnpj;
+///
+/// let parts = cnpj::parse("05.200.851/0001-00").unwrap();
+/// assert_eq!(parts.raiz, "05200851");
+/// assert_eq!(parts.ordem, "0001");
+/// assert_eq!(parts.dv, "00");
+/// assert!(parts.is_matriz());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CnpjParts {
+    /// Raiz do CNPJ, comum a todos os estabelecimentos da empresa.
+    pub raiz: String,
+    /// Número de ordem do estabelecimento.
+    pub ordem: String,
+    /// Dígitos verificadores.
+    pub dv: String,
+}
+
+impl CnpjParts {
+    /// Verifica se o número de ordem corresponde à matriz (`0001`).
+    pub fn is_matriz(&self) -> bool {
+        self.ordem == MATRIZ_ORDEM
+    }
+}
+
+/// Decompõe um CNPJ válido, máscarado ou não, em raiz, número de ordem e
+/// dígitos verificadores (`CnpjParts`).
+/// Retorna o erro de validação (`ValidationError`) correspondente caso o
+/// argumento `doc` não seja um CNPJ válido.
+///
+/// ## Exemplos
+/// ```
+/// use brado::cnpj;
+/// use brado::common::ValidationError;
+///
+/// let parts = cnpj::parse("12.ABC.345/01DE-35").unwrap();
+/// assert_eq!(parts.raiz, "12ABC345");
+/// assert_eq!(parts.ordem, "01DE");
+/// assert_eq!(parts.dv, "35");
+/// assert!(!parts.is_matriz());
+///
+/// let result = cnpj::parse("05.200.851/0001-01");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::CheckDigitMismatch {
+///         expected: String::from("00"),
+///         found: String::from("01"),
+///     }),
+/// );
+/// ```
+pub fn parse(doc: &str) -> Result<CnpjParts, ValidationError> {
+    check(doc)?;
+
+    let chars: Vec<char> = unmask(doc).chars().collect();
+
+    Ok(CnpjParts {
+        raiz: chars[..8].iter().collect(),
+        ordem: chars[8..12].iter().collect(),
+        dv: chars[12..].iter().collect(),
+    })
+}
+
+/// Verifica se o argumento `doc` é um CNPJ válido, máscarado ou não, de uma
+/// matriz (número de ordem `0001`).
+///
+/// ## Exemplos
+/// ```
+/// use brado::cnpj;
+///
+/// let result = cnpj::is_matriz("05.200.851/0001-00"); // true
+/// assert!(result);
+///
+/// let result = cnpj::is_matriz("05.200.851/0002-82"); // false
+/// assert!(!result);
+///
+/// let result = cnpj::is_matriz("05.200.851/0001-01"); // false
+/// assert!(!result);
+/// ```
+pub fn is_matriz(doc: &str) -> bool {
+    matches!(parse(doc), Ok(parts) if parts.is_matriz())
+}
+
+/// Constrói e retorna um CNPJ sem máscara a partir da raiz `raiz`
+/// (8 caracteres) e do número de ordem `ordem` (4 caracteres), ambos sem
+/// máscara, calculando os dígitos verificadores. A raiz e o número de ordem
+/// podem ser alfanuméricos.
+/// Retorna o erro de validação (`ValidationError`) correspondente caso a raiz
+/// ou o número de ordem sejam inválidos. O número de ordem `0000` não é
+/// atribuído a nenhum estabelecimento e é rejeitado
+/// (`ValidationError::InvalidPrefix`).
+///
+/// ## Exemplos
+/// ```
+/// use brado::cnpj;
+/// use brado::common::ValidationError;
+///
+/// let result = cnpj::branch("05200851", "0002"); // Ok("05200851000282")
+/// assert_eq!(result, Ok(String::from("05200851000282")));
+///
+/// let result = cnpj::branch("12ABC345", "0001"); // Ok("12ABC345000188")
+/// assert_eq!(result, Ok(String::from("12ABC345000188")));
+///
+/// let result = cnpj::branch("05200851", "2");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::InvalidLength {
+///         expected: 4,
+///         found: 1,
+///     }),
+/// );
+///
+/// let result = cnpj::branch("05200851", "0000");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::InvalidPrefix {
+///         found: String::from("0000"),
+///     }),
+/// );
+/// ```
+pub fn branch(
+    raiz: &str,
+    ordem: &str,
+) -> Result<String, ValidationError> {
+    check_format(raiz, RAIZ_SIZE, |_| false, to_cnpj_digit)?;
+    check_format(ordem, ORDEM_SIZE, |_| false, to_cnpj_digit)?;
+    if ordem == NULL_ORDEM {
+        return Err(ValidationError::InvalidPrefix {
+            found: ordem.to_string(),
+        });
+    }
+
+    let base: String = [raiz, ordem].concat();
+    let check_digits: String = compute_check_digits(&base)?;
+
+    Ok([base, check_digits].concat())
+}
+
+/// CNPJ validado, armazenado sem máscara.
+///
+/// Só pode ser construído a partir de um CNPJ válido, máscarado ou não, por
+/// meio de `FromStr` ou `TryFrom<&str>`. É exibido (`Display`) com máscara.
+///
+/// ## Exemplo
+/// ```
+/// use brado::cnpj::Cnpj;
+///
+/// let doc: Cnpj = "05.200.851/0001-00".parse().unwrap();
+/// assert_eq!(doc.as_bare(), "05200851000100");
+/// assert_eq!(doc.to_masked(), "05.200.851/0001-00");
+/// assert_eq!(doc.to_string(), "05.200.851/0001-00");
+///
+/// let result = "05.200.851/0001-01".parse::<Cnpj>();
+/// assert!(result.is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Cnpj(String);
+
+impl Cnpj {
+    /// Retorna o CNPJ sem máscara.
+    pub fn as_bare(&self) -> &str {
+        &self.0
+    }
+
+    /// Retorna o CNPJ com máscara.
+    pub fn to_masked(&self) -> String {
+        mask(&self.0).expect("Invalid CNPJ!")
+    }
+
+    /// Retorna a raiz, o número de ordem e os dígitos verificadores do CNPJ.
+    pub fn parts(&self) -> CnpjParts {
+        parse(&self.0).expect("Invalid CNPJ!")
+    }
+
+    /// Verifica se o CNPJ é de uma matriz (número de ordem `0001`).
+    pub fn is_matriz(&self) -> bool {
+        self.parts().is_matriz()
+    }
+
+    /// Retorna o formato do CNPJ (numérico ou alfanumérico).
+    pub fn format(&self) -> CnpjFormat {
+        detect_format(&self.0).expect("Invalid CNPJ!")
+    }
+
+    /// Retorna o CNPJ do estabelecimento de número de ordem `ordem` da mesma
+    /// empresa (mesma raiz).
+    ///
+    /// ## Exemplo
+    /// ```
+    /// use brado::cnpj::Cnpj;
+    ///
+    /// let matriz: Cnpj = "05.200.851/0001-00".parse().unwrap();
+    /// let filial: Cnpj = matriz.branch("0002").unwrap();
+    /// assert_eq!(filial.to_string(), "05.200.851/0002-82");
+    /// assert_eq!(filial.parts().raiz, matriz.parts().raiz);
+    /// ```
+    pub fn branch(
+        &self,
+        ordem: &str,
+    ) -> Result<Cnpj, ValidationError> {
+        branch(&self.parts().raiz, ordem).map(Cnpj)
+    }
+}
+
+impl FromStr for Cnpj {
+    type Err = ValidationError;
+
+    fn from_str(doc: &str) -> Result<Self, Self::Err> {
+        check(doc)?;
+        Ok(Cnpj(unmask(doc)))
+    }
+}
+
+impl TryFrom<&str> for Cnpj {
+    type Error = ValidationError;
+
+    fn try_from(doc: &str) -> Result<Self, Self::Error> {
+        doc.parse()
+    }
+}
+
+impl fmt::Display for Cnpj {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(f, "{}", self.to_masked())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Cnpj {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_bare())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Cnpj {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let doc: String = String::deserialize(deserializer)?;
+        doc.parse().map_err(de::Error::custom)
+    }
+}
+
+/// Tipo marcador do CNPJ.
+/// Implementa o trait `Document` a partir das funções deste módulo.
+///
+/// ## Exemplo
+/// ```
+/// use brado::common::Document;
+/// use brado::cnpj::CnpjDocument;
+///
+/// let result = CnpjDocument.validate("05.200.851/0001-00"); // true
+/// assert!(result);
+///
+/// let result = CnpjDocument.bare_size(); // 14
+/// assert_eq!(result, 14);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CnpjDocument;
+
+impl Document for CnpjDocument {
+    fn kind(&self) -> DocKind {
+        DocKind::Cnpj
+    }
+
+    fn bare_size(&self) -> usize {
+        CNPJ_SIZE
+    }
+
+    fn check(
+        &self,
+        doc: &str,
+    ) -> Result<(), ValidationError> {
+        check(doc)
+    }
+
+    fn is_bare(
+        &self,
+        doc: &str,
+    ) -> bool {
+        is_bare(doc)
+    }
+
+    fn is_masked(
+        &self,
+        doc: &str,
+    ) -> bool {
+        is_masked(doc)
+    }
+
+    fn mask(
+        &self,
+        doc: &str,
+    ) -> Result<String, MaskError> {
+        mask(doc)
+    }
+
+    fn unmask(
+        &self,
+        doc: &str,
+    ) -> String {
+        unmask(doc)
+    }
+
+    fn generate(&self) -> String {
+        generate()
+    }
+
+    fn generate_masked(&self) -> String {
+        generate_masked()
+    }
+
+    fn generate_with_rng(
+        &self,
+        rng: &mut dyn RngCore,
+    ) -> String {
+        generate_with_rng(rng)
+    }
+
+    fn generate_masked_with_rng(
+        &self,
+        rng: &mut dyn RngCore,
+    ) -> String {
+        generate_masked_with_rng(rng)
+    }
 }
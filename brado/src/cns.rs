@@ -1,9 +1,16 @@
 //! Utilitários para validação de Cartão Nacional de Saúde (CNS).
 
 use crate::common::{
-    get_digits, get_symbols, random_decimal_vector, random_element_from_vector,
-    to_decimal,
+    check_digits, check_format, check_mask, get_digits, get_symbols,
+    random_decimal_vector_with_rng, random_element_from_vector_with_rng,
+    to_decimal, Document, MaskError, ValidationError,
 };
+use crate::docs::DocKind;
+use rand::{Rng, RngCore};
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
 
 const CNS_SIZE: usize = 15;
 
@@ -35,19 +42,117 @@ const CNS_SIZE: usize = 15;
 /// assert!(!result);
 /// ```
 pub fn validate(doc: &str) -> bool {
-    let size: usize = doc.chars().count();
+    check(doc).is_ok()
+}
 
-    if size != CNS_SIZE && !is_masked(doc) {
-        return false;
+/// Realiza validação de CNS, máscarado ou não, informando o motivo da falha.
+/// Retorna `Ok(())` se o argumento `doc` for um CNS válido, caso contrário,
+/// retorna o erro de validação (`ValidationError`) correspondente.
+///
+/// ## Exemplos
+///
+/// CNSs válidos:
+/// ```
+/// use brado::cns;
+///
+/// let result = cns::check("144082627260004"); // Ok(())
+/// assert!(result.is_ok());
+///
+/// let result = cns::check("144 0826 2726 0004"); // Ok(())
+/// assert!(result.is_ok());
+/// ```
+///
+/// CNSs inválidos:
+/// ```
+/// use brado::cns;
+/// use brado::common::ValidationError;
+///
+/// let result = cns::check("144082627260005");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::CheckDigitMismatch {
+///         expected: String::from("0004"),
+///         found: String::from("0005"),
+///     }),
+/// );
+///
+/// let result = cns::check("812297346500001");
+/// assert_eq!(result, Err(ValidationError::InvalidChecksum));
+///
+/// let result = cns::check("344082627260004");
+/// assert_eq!(result, Err(ValidationError::InvalidFirstDigit { found: 3 }));
+/// ```
+pub fn check(doc: &str) -> Result<(), ValidationError> {
+    let digits: Vec<u16> = check_format(doc, CNS_SIZE, is_masked, to_decimal)?;
+
+    if is_first_digit_invalid(&digits[0]) {
+        return Err(ValidationError::InvalidFirstDigit { found: digits[0] });
     }
 
-    let digits: Vec<u16> = get_digits(doc, to_decimal);
+    check_checksum(&digits)
+}
 
-    if digits.len() != CNS_SIZE || is_first_digit_invalid(&digits[0]) {
-        return false;
+/// Calcula os dígitos finais de um CNS a partir dos primeiros dígitos
+/// (`base`), sem símbolos.
+///
+/// - CNS iniciado por 1 ou 2: a base possui 11 dígitos e são retornados os 4
+///   dígitos finais;
+/// - CNS iniciado por 7, 8 ou 9: a base possui 14 dígitos e é retornado o
+///   dígito final que completa a soma ponderada.
+///
+/// Retorna o erro correspondente (`ValidationError`) caso a base seja
+/// inválida ou não possa ser completada.
+///
+/// ## Exemplos
+///
+/// Bases válidas:
+/// ```
+/// use brado::cns;
+///
+/// let base = "14408262726";
+/// let result = cns::compute_check_digits(base); // Ok("0004")
+/// assert_eq!(result, Ok(String::from("0004")));
+///
+/// let doc = format!("{}{}", base, result.unwrap()); // "144082627260004"
+/// assert!(cns::validate(&doc));
+///
+/// let result = cns::compute_check_digits("81229734650000"); // Ok("0")
+/// assert_eq!(result, Ok(String::from("0")));
+/// ```
+///
+/// Bases inválidas:
+/// ```
+/// use brado::cns;
+/// use brado::common::ValidationError;
+///
+/// let result = cns::compute_check_digits("34408262726");
+/// assert_eq!(result, Err(ValidationError::InvalidFirstDigit { found: 3 }));
+///
+/// let result = cns::compute_check_digits("81229734650006");
+/// assert_eq!(result, Err(ValidationError::InvalidChecksum));
+/// ```
+pub fn compute_check_digits(base: &str) -> Result<String, ValidationError> {
+    let size: usize = match base.chars().next().and_then(to_decimal) {
+        Some(1 | 2) | None => CNS_SIZE - 4,
+        Some(7..=9) => CNS_SIZE - 1,
+        Some(found) => {
+            return Err(ValidationError::InvalidFirstDigit { found })
+        }
+    };
+
+    let digits: Vec<u16> = check_format(base, size, |_| false, to_decimal)?;
+
+    if size == CNS_SIZE - 4 {
+        return Ok(generate_last_four_digits(&digits)
+            .iter()
+            .map(|d| d.to_string())
+            .collect());
     }
 
-    validate_checksum(&digits)
+    match (11 - cns_sum(&digits) % 11) % 11 {
+        10 => Err(ValidationError::InvalidChecksum),
+        d => Ok(d.to_string()),
+    }
 }
 
 fn valid_first_digits() -> Vec<u16> {
@@ -62,16 +167,18 @@ fn is_first_digit_invalid(digit: &u16) -> bool {
     !is_first_digit_valid(digit)
 }
 
-fn validate_checksum(doc_slice: &[u16]) -> bool {
+fn check_checksum(doc_slice: &[u16]) -> Result<(), ValidationError> {
     if [1, 2].contains(&doc_slice[0]) {
-        let check_digits: Vec<u16> =
-            generate_last_four_digits(&doc_slice[..11]);
+        let expected: Vec<u16> = generate_last_four_digits(&doc_slice[..11]);
 
-        doc_slice[11..] == check_digits
+        check_digits(&expected, &doc_slice[11..])
     } else {
         let checksum: u16 = cns_sum(doc_slice);
 
-        checksum % 11 == 0
+        match checksum % 11 {
+            0 => Ok(()),
+            _ => Err(ValidationError::InvalidChecksum),
+        }
     }
 }
 
@@ -193,10 +300,8 @@ pub fn is_masked(doc: &str) -> bool {
 ///     Err(e) => panic!("{}", e),
 /// };
 /// ```
-pub fn mask(doc: &str) -> Result<String, &'static str> {
-    if !is_bare(doc) {
-        return Err("The given string cannot be masked as CNS!");
-    }
+pub fn mask(doc: &str) -> Result<String, MaskError> {
+    check_mask(doc, DocKind::Cns, CNS_SIZE, is_masked, to_decimal)?;
 
     let masked_doc: String = format!(
         "{} {} {} {}",
@@ -209,6 +314,19 @@ pub fn mask(doc: &str) -> Result<String, &'static str> {
     Ok(masked_doc)
 }
 
+/// Remove os símbolos do argumento `doc` e retorna o CNS sem máscara.
+///
+/// ## Exemplo
+/// ```
+/// use brado::cns;
+///
+/// let result = cns::unmask("144 0826 2726 0004"); // "144082627260004"
+/// assert_eq!(result, "144082627260004");
+/// ```
+pub fn unmask(doc: &str) -> String {
+    crate::common::unmask(doc, to_decimal)
+}
+
 /// Gera e retorna um CNS aleatório sem máscara.
 ///
 /// ## Exemplo
@@ -219,13 +337,47 @@ pub fn mask(doc: &str) -> Result<String, &'static str> {
 /// assert!(cns::is_bare(&result)); // true
 /// ```
 pub fn generate() -> String {
-    let first_digit: u16 = random_element_from_vector(&valid_first_digits());
+    generate_with_rng(&mut rand::thread_rng())
+}
 
+/// Gera e retorna um CNS aleatório sem máscara a partir do gerador de
+/// números aleatórios `rng`. Geradores com a mesma semente produzem o mesmo
+/// CNS.
+///
+/// ## Exemplo
+/// ```
+/// use brado::cns;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let result = cns::generate_with_rng(&mut StdRng::seed_from_u64(42));
+/// assert!(cns::is_bare(&result)); // true
+///
+/// let other = cns::generate_with_rng(&mut StdRng::seed_from_u64(42));
+/// assert_eq!(result, other);
+/// ```
+pub fn generate_with_rng<R>(rng: &mut R) -> String
+where
+    R: Rng + ?Sized,
+{
+    let first_digit: u16 =
+        random_element_from_vector_with_rng(&valid_first_digits(), rng);
+
+    generate_from_first_digit(first_digit, rng)
+}
+
+fn generate_from_first_digit<R>(
+    first_digit: u16,
+    rng: &mut R,
+) -> String
+where
+    R: Rng + ?Sized,
+{
     let cns: Vec<u16> = {
         if [1, 2].contains(&first_digit) {
-            generate_first_case(first_digit)
+            generate_first_case(first_digit, rng)
         } else {
-            generate_second_case(first_digit)
+            generate_second_case(first_digit, rng)
         }
     };
 
@@ -235,69 +387,443 @@ pub fn generate() -> String {
         .concat()
 }
 
-fn generate_first_case(first_digit: u16) -> Vec<u16> {
+fn generate_first_case<R>(
+    first_digit: u16,
+    rng: &mut R,
+) -> Vec<u16>
+where
+    R: Rng + ?Sized,
+{
     let mut cns: Vec<u16> = vec![first_digit];
-    cns.extend_from_slice(&random_decimal_vector(10));
+    cns.extend_from_slice(&random_decimal_vector_with_rng(10, rng));
     cns.extend_from_slice(&generate_last_four_digits(&cns));
 
     cns
 }
 
-fn generate_second_case(first_digit: u16) -> Vec<u16> {
+fn generate_second_case<R>(
+    first_digit: u16,
+    rng: &mut R,
+) -> Vec<u16>
+where
+    R: Rng + ?Sized,
+{
     let mut cns: Vec<u16> = vec![first_digit];
-    cns.extend_from_slice(&random_decimal_vector(14));
+    cns.extend_from_slice(&random_decimal_vector_with_rng(12, rng));
 
-    let checksum: u16 = cns_sum(&cns);
-    let rest: u16 = checksum % 11;
-    if rest == 0 {
-        return cns;
-    }
+    // Os dois últimos dígitos têm pesos 2 e 1: basta escolher `a` e `b` tais
+    // que `2a + b` complete a soma ponderada até um múltiplo de 11.
+    let target: u16 = (11 - cns_sum(&cns) % 11) % 11;
+    let candidates: Vec<(u16, u16)> = (0..10)
+        .map(|a| (a, (target + 22 - 2 * a) % 11))
+        .filter(|&(_, b)| b < 10)
+        .collect();
 
-    let diff: u16 = 11 - rest;
+    let (a, b): (u16, u16) = candidates[rng.gen_range(0..candidates.len())];
+    cns.extend_from_slice(&[a, b]);
 
-    let mut val: usize = diff as usize;
-    let mut idx: usize = 15 - val;
+    cns
+}
 
-    loop {
-        if val == 0 {
-            if validate_checksum(&cns) {
-                return cns;
-            } else {
-                let checksum: u16 = cns_sum(&cns);
+/// Gera e retorna um CNS aleatório com máscara.
+///
+/// ## Exemplo
+/// ```
+/// use brado::cns;
+///
+/// let result = cns::generate_masked(); // "144 0826 2726 0004"
+/// assert!(cns::is_masked(&result)); // true
+/// ```
+pub fn generate_masked() -> String {
+    generate_masked_with_rng(&mut rand::thread_rng())
+}
 
-                let diff: u16 = 15 - (checksum % 11);
+/// Gera e retorna um CNS aleatório com máscara a partir do gerador de
+/// números aleatórios `rng`. Geradores com a mesma semente produzem o mesmo
+/// CNS.
+///
+/// ## Exemplo
+/// ```
+/// use brado::cns;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let result = cns::generate_masked_with_rng(&mut rng);
+/// assert!(cns::is_masked(&result)); // true
+/// ```
+pub fn generate_masked_with_rng<R>(rng: &mut R) -> String
+where
+    R: Rng + ?Sized,
+{
+    mask(&generate_with_rng(rng)).expect("Invalid CNS!")
+}
 
-                val = diff as usize;
-                idx = 15 - diff as usize;
-                continue;
-            }
-        }
+/// Tipos de CNS.
+///
+/// O primeiro dígito do CNS distingue o cartão definitivo (1 ou 2), derivado
+/// do PIS/NIS do cidadão, do cartão provisório (7, 8 ou 9).
+///
+/// ## Exemplo
+/// ```
+/// use brado::cns::CnsKind;
+///
+/// let result = CnsKind::Definitive.first_digits(); // [1, 2]
+/// assert_eq!(result, &[1, 2]);
+///
+/// let result = CnsKind::from_first_digit(8); // Some(Provisional)
+/// assert_eq!(result, Some(CnsKind::Provisional));
+///
+/// let result = CnsKind::from_first_digit(3); // None
+/// assert_eq!(result, None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CnsKind {
+    /// Cartão definitivo (1 ou 2), derivado do PIS/NIS.
+    Definitive,
+    /// Cartão provisório (7, 8 ou 9).
+    Provisional,
+}
 
-        if 15 - idx > val {
-            idx += 1;
-            continue;
-        }
+impl CnsKind {
+    /// Todos os tipos de CNS.
+    pub const ALL: &'static [CnsKind] =
+        &[CnsKind::Definitive, CnsKind::Provisional];
 
-        if cns[idx] != 9 {
-            cns[idx] += 1;
-            val -= 15 - idx;
-        } else {
-            cns[idx] -= 1;
-            val += 15 - idx;
-            idx -= 1;
+    /// Retorna os primeiros dígitos possíveis para o tipo de CNS.
+    pub fn first_digits(&self) -> &'static [u16] {
+        match self {
+            CnsKind::Definitive => &[1, 2],
+            CnsKind::Provisional => &[7, 8, 9],
         }
     }
+
+    /// Retorna o tipo de CNS identificado pelo primeiro dígito `digit`, ou
+    /// `None` caso o dígito não seja um primeiro dígito válido.
+    pub fn from_first_digit(digit: u16) -> Option<CnsKind> {
+        CnsKind::ALL
+            .iter()
+            .find(|kind| kind.first_digits().contains(&digit))
+            .copied()
+    }
 }
 
-/// Gera e retorna um CNS aleatório com máscara.
+/// Retorna o tipo do CNS `doc`, máscarado ou não. Retorna `None` caso o CNS
+/// seja inválido.
+///
+/// ## Exemplos
+/// ```
+/// use brado::cns::{self, CnsKind};
+///
+/// let result = cns::kind("144 0826 2726 0004"); // Some(Definitive)
+/// assert_eq!(result, Some(CnsKind::Definitive));
+///
+/// let result = cns::kind("812297346500000"); // Some(Provisional)
+/// assert_eq!(result, Some(CnsKind::Provisional));
+///
+/// let result = cns::kind("144082627260005"); // None
+/// assert_eq!(result, None);
+/// ```
+pub fn kind(doc: &str) -> Option<CnsKind> {
+    check(doc).ok()?;
+
+    let digits: Vec<u16> = get_digits(doc, to_decimal);
+
+    CnsKind::from_first_digit(digits[0])
+}
+
+/// Gera e retorna um CNS definitivo (iniciado por 1 ou 2) aleatório sem
+/// máscara.
+///
+/// ## Exemplo
+/// ```
+/// use brado::cns::{self, CnsKind};
+///
+/// let result = cns::generate_definitive(); // "144082627260004"
+/// assert!(cns::validate(&result)); // true
+/// assert_eq!(cns::kind(&result), Some(CnsKind::Definitive));
+/// ```
+pub fn generate_definitive() -> String {
+    generate_definitive_with_rng(&mut rand::thread_rng())
+}
+
+/// Gera e retorna um CNS definitivo (iniciado por 1 ou 2) aleatório sem
+/// máscara, a partir do gerador de números aleatórios `rng`.
+///
+/// ## Exemplo
+/// ```
+/// use brado::cns::{self, CnsKind};
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let result = cns::generate_definitive_with_rng(&mut rng);
+/// assert_eq!(cns::kind(&result), Some(CnsKind::Definitive));
+/// ```
+pub fn generate_definitive_with_rng<R>(rng: &mut R) -> String
+where
+    R: Rng + ?Sized,
+{
+    generate_kind_with_rng(CnsKind::Definitive, rng)
+}
+
+/// Gera e retorna um CNS provisório (iniciado por 7, 8 ou 9) aleatório sem
+/// máscara.
+///
+/// ## Exemplo
+/// ```
+/// use brado::cns::{self, CnsKind};
+///
+/// let result = cns::generate_provisional(); // "812297346500000"
+/// assert!(cns::validate(&result)); // true
+/// assert_eq!(cns::kind(&result), Some(CnsKind::Provisional));
+/// ```
+pub fn generate_provisional() -> String {
+    generate_provisional_with_rng(&mut rand::thread_rng())
+}
+
+/// Gera e retorna um CNS provisório (iniciado por 7, 8 ou 9) aleatório sem
+/// máscara, a partir do gerador de números aleatórios `rng`.
 ///
 /// ## Exemplo
 /// ```
+/// use brado::cns::{self, CnsKind};
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let result = cns::generate_provisional_with_rng(&mut rng);
+/// assert_eq!(cns::kind(&result), Some(CnsKind::Provisional));
+/// ```
+pub fn generate_provisional_with_rng<R>(rng: &mut R) -> String
+where
+    R: Rng + ?Sized,
+{
+    generate_kind_with_rng(CnsKind::Provisional, rng)
+}
+
+fn generate_kind_with_rng<R>(
+    kind: CnsKind,
+    rng: &mut R,
+) -> String
+where
+    R: Rng + ?Sized,
+{
+    let first_digit: u16 =
+        random_element_from_vector_with_rng(kind.first_digits(), rng);
+
+    generate_from_first_digit(first_digit, rng)
+}
+
+/// Gera e retorna o CNS definitivo sem máscara derivado do PIS/NIS `nis`,
+/// máscarado ou não. Os 11 primeiros dígitos do CNS são os dígitos do NIS.
+///
+/// Retorna o erro de validação (`ValidationError`) correspondente caso o NIS
+/// seja inválido ou não seja iniciado por 1 ou 2.
+///
+/// ## Exemplos
+///
+/// NISs válidos:
+/// ```
+/// use brado::cns::{self, CnsKind};
+///
+/// let result = cns::from_nis("120.62470.39-0"); // Ok("120624703900004")
+/// assert_eq!(result, Ok(String::from("120624703900004")));
+/// assert_eq!(cns::kind(&result.unwrap()), Some(CnsKind::Definitive));
+/// ```
+///
+/// NISs inválidos:
+/// ```
 /// use brado::cns;
+/// use brado::common::ValidationError;
 ///
-/// let result = cns::generate_masked(); // "144 0826 2726 0004"
-/// assert!(cns::is_masked(&result)); // true
+/// let result = cns::from_nis("408.65658.04-7");
+/// assert_eq!(result, Err(ValidationError::InvalidFirstDigit { found: 4 }));
+///
+/// let result = cns::from_nis("120.62470.39-1");
+/// assert!(result.is_err());
 /// ```
-pub fn generate_masked() -> String {
-    mask(&generate()).expect("Invalid CNS!")
+pub fn from_nis(nis: &str) -> Result<String, ValidationError> {
+    crate::nis::check(nis)?;
+
+    let base: String = crate::nis::unmask(nis);
+    let first_digit: u16 = get_digits(&base, to_decimal)[0];
+
+    if CnsKind::from_first_digit(first_digit) != Some(CnsKind::Definitive) {
+        return Err(ValidationError::InvalidFirstDigit { found: first_digit });
+    }
+
+    Ok(format!("{}{}", base, compute_check_digits(&base)?))
+}
+
+/// CNS validado, armazenado sem máscara.
+///
+/// Só pode ser construído a partir de um CNS válido, máscarado ou não, por
+/// meio de `FromStr` ou `TryFrom<&str>`. É exibido (`Display`) com máscara.
+///
+/// ## Exemplo
+/// ```
+/// use brado::cns::Cns;
+///
+/// let doc: Cns = "144 0826 2726 0004".parse().unwrap();
+/// assert_eq!(doc.as_bare(), "144082627260004");
+/// assert_eq!(doc.to_masked(), "144 0826 2726 0004");
+/// assert_eq!(doc.to_string(), "144 0826 2726 0004");
+///
+/// let result = "144 0826 2726 0005".parse::<Cns>();
+/// assert!(result.is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Cns(String);
+
+impl Cns {
+    /// Retorna o CNS sem máscara.
+    pub fn as_bare(&self) -> &str {
+        &self.0
+    }
+
+    /// Retorna o CNS com máscara.
+    pub fn to_masked(&self) -> String {
+        mask(&self.0).expect("Invalid CNS!")
+    }
+
+    /// Retorna o tipo do CNS (definitivo ou provisório).
+    pub fn kind(&self) -> CnsKind {
+        kind(&self.0).expect("Invalid CNS!")
+    }
+}
+
+impl FromStr for Cns {
+    type Err = ValidationError;
+
+    fn from_str(doc: &str) -> Result<Self, Self::Err> {
+        check(doc)?;
+        Ok(Cns(unmask(doc)))
+    }
+}
+
+impl TryFrom<&str> for Cns {
+    type Error = ValidationError;
+
+    fn try_from(doc: &str) -> Result<Self, Self::Error> {
+        doc.parse()
+    }
+}
+
+impl fmt::Display for Cns {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(f, "{}", self.to_masked())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Cns {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_bare())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Cns {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let doc: String = String::deserialize(deserializer)?;
+        doc.parse().map_err(de::Error::custom)
+    }
+}
+
+/// Tipo marcador do CNS.
+/// Implementa o trait `Document` a partir das funções deste módulo.
+///
+/// ## Exemplo
+/// ```
+/// use brado::common::Document;
+/// use brado::cns::CnsDocument;
+///
+/// let result = CnsDocument.validate("144 0826 2726 0004"); // true
+/// assert!(result);
+///
+/// let result = CnsDocument.bare_size(); // 15
+/// assert_eq!(result, 15);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CnsDocument;
+
+impl Document for CnsDocument {
+    fn kind(&self) -> DocKind {
+        DocKind::Cns
+    }
+
+    fn bare_size(&self) -> usize {
+        CNS_SIZE
+    }
+
+    fn check(
+        &self,
+        doc: &str,
+    ) -> Result<(), ValidationError> {
+        check(doc)
+    }
+
+    fn is_bare(
+        &self,
+        doc: &str,
+    ) -> bool {
+        is_bare(doc)
+    }
+
+    fn is_masked(
+        &self,
+        doc: &str,
+    ) -> bool {
+        is_masked(doc)
+    }
+
+    fn mask(
+        &self,
+        doc: &str,
+    ) -> Result<String, MaskError> {
+        mask(doc)
+    }
+
+    fn unmask(
+        &self,
+        doc: &str,
+    ) -> String {
+        unmask(doc)
+    }
+
+    fn generate(&self) -> String {
+        generate()
+    }
+
+    fn generate_masked(&self) -> String {
+        generate_masked()
+    }
+
+    fn generate_with_rng(
+        &self,
+        rng: &mut dyn RngCore,
+    ) -> String {
+        generate_with_rng(rng)
+    }
+
+    fn generate_masked_with_rng(
+        &self,
+        rng: &mut dyn RngCore,
+    ) -> String {
+        generate_masked_with_rng(rng)
+    }
 }
@@ -1,10 +1,749 @@
 //! Funções comuns utilizadas na validação de documentos.
 
-use rand::Rng;
+use crate::docs::DocKind;
+use rand::{Rng, RngCore};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
+use std::error::Error;
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 const RADIX: u32 = 10;
 
+/// Erro de validação de documento.
+///
+/// Descreve o motivo pelo qual um documento foi considerado inválido pelas
+/// funções `check` de cada módulo.
+///
+/// ## Exemplo
+///
+/// ```
+/// use brado::common::ValidationError;
+/// use brado::cpf;
+///
+/// let result = cpf::check("639.292.470-10");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::CheckDigitMismatch {
+///         expected: String::from("11"),
+///         found: String::from("10"),
+///     }),
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ValidationError {
+    /// O documento não possui a quantidade esperada de dígitos.
+    InvalidLength { expected: usize, found: usize },
+    /// O documento sem máscara possui um caractere inválido na posição
+    /// `position`.
+    InvalidCharacter { position: usize, character: char },
+    /// O documento possui a quantidade correta de dígitos, mas os símbolos
+    /// não correspondem à máscara do documento.
+    InvalidMask,
+    /// Todos os dígitos do documento são iguais.
+    RepeatedDigits,
+    /// O primeiro dígito do documento não é permitido (CNS).
+    InvalidFirstDigit { found: u16 },
+    /// Os dígitos verificadores calculados não correspondem aos informados.
+    CheckDigitMismatch { expected: String, found: String },
+    /// A soma ponderada dos dígitos não satisfaz a regra do documento (CNS
+    /// provisório).
+    InvalidChecksum,
+    /// O código de estado informado no documento não existe (Título
+    /// Eleitoral).
+    InvalidStateCode { found: u16 },
+    /// O tipo de livro informado no documento não existe (Certidão).
+    InvalidBookType { found: u16 },
+    /// O ano informado no documento é posterior ao ano corrente (Certidão).
+    InvalidYear { found: u16 },
+    /// Um campo do documento (`found`) não corresponde a nenhum valor
+    /// permitido: prefixo da Inscrição Estadual, número de ordem do CNPJ,
+    /// faixa do CEP, DDD ou prefixo (0800 e 0300) do telefone.
+    InvalidPrefix { found: String },
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            ValidationError::InvalidLength { expected, found } => write!(
+                f,
+                "Invalid length: expected {} digits, found {}!",
+                expected, found,
+            ),
+            ValidationError::InvalidCharacter {
+                position,
+                character,
+            } => write!(
+                f,
+                "Invalid character '{}' at position {}!",
+                character, position,
+            ),
+            ValidationError::InvalidMask => write!(f, "Malformed mask!"),
+            ValidationError::RepeatedDigits => {
+                write!(f, "All digits are repeated!")
+            }
+            ValidationError::InvalidFirstDigit { found } => {
+                write!(f, "Invalid first digit: {}!", found)
+            }
+            ValidationError::CheckDigitMismatch { expected, found } => write!(
+                f,
+                "Check digit mismatch: expected {}, found {}!",
+                expected, found,
+            ),
+            ValidationError::InvalidChecksum => write!(f, "Invalid checksum!"),
+            ValidationError::InvalidStateCode { found } => {
+                write!(f, "Invalid state code: {:02}!", found)
+            }
+            ValidationError::InvalidBookType { found } => {
+                write!(f, "Invalid book type: {}!", found)
+            }
+            ValidationError::InvalidYear { found } => {
+                write!(f, "Invalid year: {:04}!", found)
+            }
+            ValidationError::InvalidPrefix { found } => {
+                write!(f, "Invalid prefix: {}!", found)
+            }
+        }
+    }
+}
+
+impl Error for ValidationError {}
+
+/// Erro ao aplicar máscara em um documento.
+///
+/// Descreve o motivo pelo qual a máscara não pôde ser aplicada e o tipo de
+/// documento (`DocKind`) correspondente à máscara.
+///
+/// ## Exemplo
+///
+/// ```
+/// use brado::common::MaskError;
+/// use brado::cpf;
+/// use brado::docs::DocKind;
+///
+/// let result = cpf::mask("639.292.470-11");
+/// assert_eq!(
+///     result,
+///     Err(MaskError::AlreadyMasked {
+///         kind: DocKind::Cpf,
+///     }),
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MaskError {
+    /// O documento já está mascarado.
+    AlreadyMasked { kind: DocKind },
+    /// O documento não possui a quantidade de caracteres do documento sem
+    /// máscara.
+    InvalidLength {
+        kind: DocKind,
+        expected: usize,
+        found: usize,
+    },
+    /// O documento possui um caractere inválido na posição `position`.
+    InvalidCharacter {
+        kind: DocKind,
+        position: usize,
+        character: char,
+    },
+}
+
+impl MaskError {
+    /// Retorna o tipo de documento (`DocKind`) que se tentou mascarar.
+    ///
+    /// ## Exemplo
+    ///
+    /// ```
+    /// use brado::cnpj;
+    /// use brado::docs::DocKind;
+    ///
+    /// let result = cnpj::mask("0520085100010").unwrap_err().kind();
+    /// assert_eq!(result, DocKind::Cnpj);
+    /// ```
+    pub fn kind(&self) -> DocKind {
+        match self {
+            MaskError::AlreadyMasked { kind } => *kind,
+            MaskError::InvalidLength { kind, .. } => *kind,
+            MaskError::InvalidCharacter { kind, .. } => *kind,
+        }
+    }
+}
+
+impl fmt::Display for MaskError {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(f, "The given string cannot be masked as {}: ", self.kind())?;
+
+        match self {
+            MaskError::AlreadyMasked { .. } => write!(f, "already masked!"),
+            MaskError::InvalidLength {
+                expected, found, ..
+            } => {
+                write!(f, "expected {} characters, found {}!", expected, found,)
+            }
+            MaskError::InvalidCharacter {
+                position,
+                character,
+                ..
+            } => write!(
+                f,
+                "invalid character '{}' at position {}!",
+                character, position,
+            ),
+        }
+    }
+}
+
+impl Error for MaskError {}
+
+/// Unidades Federativas (UF) do Brasil.
+///
+/// ## Exemplo
+///
+/// ```
+/// use brado::common::Uf;
+///
+/// let result = Uf::Sp.sigla(); // "SP"
+/// assert_eq!(result, "SP");
+///
+/// let result = Uf::from_sigla("rj"); // Some(Uf::Rj)
+/// assert_eq!(result, Some(Uf::Rj));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "UPPERCASE"))]
+pub enum Uf {
+    /// Acre.
+    Ac,
+    /// Alagoas.
+    Al,
+    /// Amapá.
+    Ap,
+    /// Amazonas.
+    Am,
+    /// Bahia.
+    Ba,
+    /// Ceará.
+    Ce,
+    /// Distrito Federal.
+    Df,
+    /// Espírito Santo.
+    Es,
+    /// Goiás.
+    Go,
+    /// Maranhão.
+    Ma,
+    /// Mato Grosso.
+    Mt,
+    /// Mato Grosso do Sul.
+    Ms,
+    /// Minas Gerais.
+    Mg,
+    /// Pará.
+    Pa,
+    /// Paraíba.
+    Pb,
+    /// Paraná.
+    Pr,
+    /// Pernambuco.
+    Pe,
+    /// Piauí.
+    Pi,
+    /// Rio de Janeiro.
+    Rj,
+    /// Rio Grande do Norte.
+    Rn,
+    /// Rio Grande do Sul.
+    Rs,
+    /// Rondônia.
+    Ro,
+    /// Roraima.
+    Rr,
+    /// Santa Catarina.
+    Sc,
+    /// São Paulo.
+    Sp,
+    /// Sergipe.
+    Se,
+    /// Tocantins.
+    To,
+}
+
+impl Uf {
+    /// Todas as Unidades Federativas, em ordem alfabética de nome.
+    ///
+    /// ## Exemplo
+    ///
+    /// ```
+    /// use brado::common::Uf;
+    ///
+    /// let result = Uf::ALL.len(); // 27
+    /// assert_eq!(result, 27);
+    /// ```
+    pub const ALL: &'static [Uf] = &[
+        Uf::Ac,
+        Uf::Al,
+        Uf::Ap,
+        Uf::Am,
+        Uf::Ba,
+        Uf::Ce,
+        Uf::Df,
+        Uf::Es,
+        Uf::Go,
+        Uf::Ma,
+        Uf::Mt,
+        Uf::Ms,
+        Uf::Mg,
+        Uf::Pa,
+        Uf::Pb,
+        Uf::Pr,
+        Uf::Pe,
+        Uf::Pi,
+        Uf::Rj,
+        Uf::Rn,
+        Uf::Rs,
+        Uf::Ro,
+        Uf::Rr,
+        Uf::Sc,
+        Uf::Sp,
+        Uf::Se,
+        Uf::To,
+    ];
+
+    /// Retorna a sigla da Unidade Federativa.
+    ///
+    /// ## Exemplo
+    ///
+    /// ```
+    /// use brado::common::Uf;
+    ///
+    /// let result = Uf::Df.sigla(); // "DF"
+    /// assert_eq!(result, "DF");
+    /// ```
+    pub fn sigla(&self) -> &'static str {
+        match self {
+            Uf::Ac => "AC",
+            Uf::Al => "AL",
+            Uf::Ap => "AP",
+            Uf::Am => "AM",
+            Uf::Ba => "BA",
+            Uf::Ce => "CE",
+            Uf::Df => "DF",
+            Uf::Es => "ES",
+            Uf::Go => "GO",
+            Uf::Ma => "MA",
+            Uf::Mt => "MT",
+            Uf::Ms => "MS",
+            Uf::Mg => "MG",
+            Uf::Pa => "PA",
+            Uf::Pb => "PB",
+            Uf::Pr => "PR",
+            Uf::Pe => "PE",
+            Uf::Pi => "PI",
+            Uf::Rj => "RJ",
+            Uf::Rn => "RN",
+            Uf::Rs => "RS",
+            Uf::Ro => "RO",
+            Uf::Rr => "RR",
+            Uf::Sc => "SC",
+            Uf::Sp => "SP",
+            Uf::Se => "SE",
+            Uf::To => "TO",
+        }
+    }
+
+    /// Retorna o nome da Unidade Federativa.
+    ///
+    /// ## Exemplo
+    ///
+    /// ```
+    /// use brado::common::Uf;
+    ///
+    /// let result = Uf::Sp.name(); // "São Paulo"
+    /// assert_eq!(result, "São Paulo");
+    /// ```
+    pub fn name(&self) -> &'static str {
+        match self {
+            Uf::Ac => "Acre",
+            Uf::Al => "Alagoas",
+            Uf::Ap => "Amapá",
+            Uf::Am => "Amazonas",
+            Uf::Ba => "Bahia",
+            Uf::Ce => "Ceará",
+            Uf::Df => "Distrito Federal",
+            Uf::Es => "Espírito Santo",
+            Uf::Go => "Goiás",
+            Uf::Ma => "Maranhão",
+            Uf::Mt => "Mato Grosso",
+            Uf::Ms => "Mato Grosso do Sul",
+            Uf::Mg => "Minas Gerais",
+            Uf::Pa => "Pará",
+            Uf::Pb => "Paraíba",
+            Uf::Pr => "Paraná",
+            Uf::Pe => "Pernambuco",
+            Uf::Pi => "Piauí",
+            Uf::Rj => "Rio de Janeiro",
+            Uf::Rn => "Rio Grande do Norte",
+            Uf::Rs => "Rio Grande do Sul",
+            Uf::Ro => "Rondônia",
+            Uf::Rr => "Roraima",
+            Uf::Sc => "Santa Catarina",
+            Uf::Sp => "São Paulo",
+            Uf::Se => "Sergipe",
+            Uf::To => "Tocantins",
+        }
+    }
+
+    /// Retorna a Unidade Federativa correspondente à sigla `sigla`,
+    /// independente de maiúsculas e minúsculas.
+    ///
+    /// ## Exemplo
+    ///
+    /// ```
+    /// use brado::common::Uf;
+    ///
+    /// let result = Uf::from_sigla("SP"); // Some(Uf::Sp)
+    /// assert_eq!(result, Some(Uf::Sp));
+    ///
+    /// let result = Uf::from_sigla("XX"); // None
+    /// assert_eq!(result, None);
+    /// ```
+    pub fn from_sigla(sigla: &str) -> Option<Uf> {
+        Uf::ALL
+            .iter()
+            .find(|uf| uf.sigla().eq_ignore_ascii_case(sigla))
+            .copied()
+    }
+}
+
+impl fmt::Display for Uf {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(f, "{}", self.sigla())
+    }
+}
+
+/// Interface comum a todos os tipos de documento.
+///
+/// Cada módulo de documento possui um tipo marcador (por exemplo,
+/// `cpf::CpfDocument`) que implementa este trait delegando às funções do
+/// próprio módulo. Os tipos marcadores não possuem campos e implementam
+/// `Default`, permitindo escrever código genérico sobre o tipo de documento.
+///
+/// ## Exemplo
+///
+/// ```
+/// use brado::common::Document;
+/// use brado::cnpj::CnpjDocument;
+/// use brado::cpf::CpfDocument;
+///
+/// fn field<D: Document + Default>(input: &str) -> Option<String> {
+///     let document = D::default();
+///     match document.validate(input) {
+///         true => Some(document.unmask(input)),
+///         false => None,
+///     }
+/// }
+///
+/// let result = field::<CpfDocument>("639.292.470-11");
+/// assert_eq!(result, Some(String::from("63929247011")));
+///
+/// let result = field::<CnpjDocument>("639.292.470-11");
+/// assert_eq!(result, None);
+/// ```
+pub trait Document {
+    /// Retorna o tipo do documento (`DocKind`).
+    fn kind(&self) -> DocKind;
+
+    /// Retorna o nome do documento.
+    fn name(&self) -> &'static str {
+        self.kind().name()
+    }
+
+    /// Retorna a quantidade de caracteres do documento sem máscara.
+    fn bare_size(&self) -> usize;
+
+    /// Realiza validação do documento, máscarado ou não.
+    fn validate(
+        &self,
+        doc: &str,
+    ) -> bool {
+        self.check(doc).is_ok()
+    }
+
+    /// Realiza validação do documento, máscarado ou não, informando o motivo
+    /// da falha.
+    fn check(
+        &self,
+        doc: &str,
+    ) -> Result<(), ValidationError>;
+
+    /// Verifica se o argumento `doc` pode ser o documento sem símbolos.
+    fn is_bare(
+        &self,
+        doc: &str,
+    ) -> bool;
+
+    /// Verifica se o argumento `doc` pode ser o documento com símbolos.
+    fn is_masked(
+        &self,
+        doc: &str,
+    ) -> bool;
+
+    /// Aplica a máscara do documento no argumento `doc`.
+    fn mask(
+        &self,
+        doc: &str,
+    ) -> Result<String, MaskError>;
+
+    /// Remove os símbolos do argumento `doc`.
+    fn unmask(
+        &self,
+        doc: &str,
+    ) -> String;
+
+    /// Gera um documento aleatório sem máscara.
+    fn generate(&self) -> String;
+
+    /// Gera um documento aleatório com máscara.
+    fn generate_masked(&self) -> String;
+
+    /// Gera um documento aleatório sem máscara a partir do gerador `rng`.
+    fn generate_with_rng(
+        &self,
+        rng: &mut dyn RngCore,
+    ) -> String;
+
+    /// Gera um documento aleatório com máscara a partir do gerador `rng`.
+    fn generate_masked_with_rng(
+        &self,
+        rng: &mut dyn RngCore,
+    ) -> String;
+}
+
+/// Verifica se um documento `doc` pode ser mascarado como o documento do tipo
+/// `kind`, ou seja, se possui `size` caracteres e todos eles são aceitos pela
+/// função de conversão passada.
+///
+/// ## Exemplo
+///
+/// ```
+/// use brado::common::{check_mask, to_decimal, MaskError};
+/// use brado::docs::DocKind;
+///
+/// let is_masked = |doc: &str| doc == "639.292.470-11";
+///
+/// let result = check_mask("63929247011", DocKind::Cpf, 11, is_masked, to_decimal);
+/// assert_eq!(result, Ok(()));
+///
+/// let result = check_mask("639.292.470-11", DocKind::Cpf, 11, is_masked, to_decimal);
+/// assert_eq!(result, Err(MaskError::AlreadyMasked { kind: DocKind::Cpf }));
+///
+/// let result = check_mask("6392924701", DocKind::Cpf, 11, is_masked, to_decimal);
+/// assert_eq!(
+///     result,
+///     Err(MaskError::InvalidLength {
+///         kind: DocKind::Cpf,
+///         expected: 11,
+///         found: 10,
+///     }),
+/// );
+/// ```
+pub fn check_mask<M, F>(
+    doc: &str,
+    kind: DocKind,
+    size: usize,
+    is_masked: M,
+    convert: F,
+) -> Result<(), MaskError>
+where
+    M: Fn(&str) -> bool,
+    F: Fn(char) -> Option<u16> + Copy,
+{
+    let found: usize = doc.chars().count();
+
+    if found == size {
+        return match get_symbols(doc, convert).first() {
+            Some(&(position, character)) => Err(MaskError::InvalidCharacter {
+                kind,
+                position,
+                character,
+            }),
+            None => Ok(()),
+        };
+    }
+
+    if get_digits(doc, convert).len() == size && is_masked(doc) {
+        return Err(MaskError::AlreadyMasked { kind });
+    }
+
+    Err(MaskError::InvalidLength {
+        kind,
+        expected: size,
+        found,
+    })
+}
+
+/// Verifica o formato de um documento `doc`, máscarado ou não, e retorna o
+/// vetor de dígitos extraído a partir da função de conversão passada.
+///
+/// O argumento `size` corresponde à quantidade de dígitos do documento sem
+/// máscara e o argumento `is_masked` verifica se `doc` está mascarado de
+/// acordo com o documento correspondente.
+///
+/// ## Exemplo
+///
+/// ```
+/// use brado::common::{check_format, to_decimal, ValidationError};
+///
+/// let is_masked = |doc: &str| doc == "1.1.1";
+///
+/// let result = check_format("111", 3, is_masked, to_decimal);
+/// assert_eq!(result, Ok(vec![1, 1, 1]));
+///
+/// let result = check_format("1.1.1", 3, is_masked, to_decimal);
+/// assert_eq!(result, Ok(vec![1, 1, 1]));
+///
+/// let result = check_format("1A1", 3, is_masked, to_decimal);
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::InvalidCharacter {
+///         position: 1,
+///         character: 'A',
+///     }),
+/// );
+///
+/// let result = check_format("1-1-1", 3, is_masked, to_decimal);
+/// assert_eq!(result, Err(ValidationError::InvalidMask));
+///
+/// let result = check_format("11", 3, is_masked, to_decimal);
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::InvalidLength {
+///         expected: 3,
+///         found: 2,
+///     }),
+/// );
+/// ```
+pub fn check_format<M, F>(
+    doc: &str,
+    size: usize,
+    is_masked: M,
+    convert: F,
+) -> Result<Vec<u16>, ValidationError>
+where
+    M: Fn(&str) -> bool,
+    F: Fn(char) -> Option<u16> + Copy,
+{
+    let digits: Vec<u16> = get_digits(doc, convert);
+
+    if doc.chars().count() == size {
+        return match get_symbols(doc, convert).first() {
+            Some(&(position, character)) => {
+                Err(ValidationError::InvalidCharacter {
+                    position,
+                    character,
+                })
+            }
+            None => Ok(digits),
+        };
+    }
+
+    if is_masked(doc) {
+        return Ok(digits);
+    }
+
+    match digits.len() == size {
+        true => Err(ValidationError::InvalidMask),
+        false => Err(ValidationError::InvalidLength {
+            expected: size,
+            found: digits.len(),
+        }),
+    }
+}
+
+/// Compara os dígitos verificadores calculados (`expected`) com os dígitos
+/// verificadores informados no documento (`found`).
+///
+/// ## Exemplo
+///
+/// ```
+/// use brado::common::{check_digits, ValidationError};
+///
+/// let result = check_digits(&[1, 1], &[1, 1]);
+/// assert_eq!(result, Ok(()));
+///
+/// let result = check_digits(&[1, 1], &[1, 0]);
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::CheckDigitMismatch {
+///         expected: String::from("11"),
+///         found: String::from("10"),
+///     }),
+/// );
+/// ```
+pub fn check_digits(
+    expected: &[u16],
+    found: &[u16],
+) -> Result<(), ValidationError> {
+    if expected == found {
+        return Ok(());
+    }
+
+    Err(ValidationError::CheckDigitMismatch {
+        expected: digits_to_string(expected),
+        found: digits_to_string(found),
+    })
+}
+
+fn digits_to_string(digits: &[u16]) -> String {
+    digits
+        .iter()
+        .map(|d| d.to_string())
+        .collect::<Vec<String>>()
+        .concat()
+}
+
+/// Retorna o ano corrente (UTC) de acordo com o relógio do sistema.
+///
+/// ## Exemplo
+///
+/// ```
+/// use brado::common::current_year;
+///
+/// let result = current_year();
+/// assert!(result >= 2024);
+/// ```
+pub fn current_year() -> u16 {
+    let secs: u64 = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    // Conversão de dias desde 1970-01-01 para o ano do calendário
+    // gregoriano, considerando eras de 400 anos (146097 dias) iniciadas em
+    // 1º de março.
+    let days: u64 = secs / 86_400 + 719_468;
+    let era: u64 = days / 146_097;
+    let doe: u64 = days % 146_097;
+    let yoe: u64 = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy: u64 = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp: u64 = (5 * doy + 2) / 153;
+    let year: u64 = yoe + era * 400 + u64::from(mp >= 10);
+
+    year as u16
+}
+
 /// Verifica se o vetor de dígitos possui um único numeral.
 /// Se possuir, retorna `true`, caso contrário, retorna `false`.
 ///
@@ -132,7 +871,34 @@ where
 /// assert_eq!(result.len(), 10);
 /// ```
 pub fn random_decimal_vector(size: usize) -> Vec<u16> {
-    let mut rng = rand::thread_rng();
+    random_decimal_vector_with_rng(size, &mut rand::thread_rng())
+}
+
+/// Gera e retorna um vetor de números decimais aleatórios
+/// com o tamanho `size`, a partir do gerador `rng`.
+///
+/// ## Exemplo
+///
+/// ```
+/// use brado::common::random_decimal_vector_with_rng;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let result = random_decimal_vector_with_rng(10, &mut rng);
+/// assert_eq!(result.len(), 10);
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let other = random_decimal_vector_with_rng(10, &mut rng);
+/// assert_eq!(result, other);
+/// ```
+pub fn random_decimal_vector_with_rng<R>(
+    size: usize,
+    rng: &mut R,
+) -> Vec<u16>
+where
+    R: Rng + ?Sized,
+{
     let mut digits: Vec<u16> = vec![];
     for _ in 0..size {
         digits.push(rng.gen_range(0..10));
@@ -155,7 +921,32 @@ pub fn random_element_from_vector<T>(options: &[T]) -> T
 where
     T: Clone,
 {
-    let mut rng = rand::thread_rng();
+    random_element_from_vector_with_rng(options, &mut rand::thread_rng())
+}
+
+/// Seleciona aleatoriamente um elemento de um vetor, a partir do gerador
+/// `rng`.
+///
+/// ## Exemplo
+///
+/// ```
+/// use brado::common::random_element_from_vector_with_rng;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let options = vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let result = random_element_from_vector_with_rng(&options, &mut rng);
+/// assert_eq!(options.contains(&result), true);
+/// ```
+pub fn random_element_from_vector_with_rng<T, R>(
+    options: &[T],
+    rng: &mut R,
+) -> T
+where
+    T: Clone,
+    R: Rng + ?Sized,
+{
     let idx = rng.gen_range(0..options.len());
     options[idx].clone()
 }
@@ -177,10 +968,40 @@ pub fn random_string_from_alphabet<T>(
 ) -> String
 where
     T: Clone + ToString,
+{
+    random_string_from_alphabet_with_rng(
+        size,
+        alphabet,
+        &mut rand::thread_rng(),
+    )
+}
+
+/// Gera e retorna uma string com o tamanho `size` composto por
+/// elementos aleatórios do alfabeto `alphabet`, a partir do gerador `rng`.
+///
+/// ## Exemplo
+///
+/// ```
+/// use brado::common::random_string_from_alphabet_with_rng;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let result = random_string_from_alphabet_with_rng(10, &[1,2,3], &mut rng);
+/// assert_eq!(result.len(), 10);
+/// ```
+pub fn random_string_from_alphabet_with_rng<T, R>(
+    size: usize,
+    alphabet: &[T],
+    rng: &mut R,
+) -> String
+where
+    T: Clone + ToString,
+    R: Rng + ?Sized,
 {
     let mut vector: Vec<T> = vec![];
     for _ in 0..size {
-        let element = random_element_from_vector(alphabet);
+        let element = random_element_from_vector_with_rng(alphabet, rng);
         vector.push(element);
     }
     vector
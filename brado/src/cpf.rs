@@ -1,8 +1,16 @@
 //! Utilitários para validação de Cadastro de Pessoa Física (CPF).
 
 use crate::common::{
-    get_digits, get_symbols, is_repeated, random_decimal_vector, to_decimal,
+    check_digits, check_format, check_mask, get_digits, get_symbols,
+    is_repeated, random_decimal_vector_with_rng, to_decimal, Document,
+    MaskError, Uf, ValidationError,
 };
+use crate::docs::DocKind;
+use rand::{Rng, RngCore};
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
 
 const CPF_SIZE: usize = 11;
 
@@ -34,21 +42,105 @@ const CPF_SIZE: usize = 11;
 /// assert!(!result);
 /// ```
 pub fn validate(doc: &str) -> bool {
-    let size: usize = doc.chars().count();
-
-    if size != CPF_SIZE && !is_masked(doc) {
-        return false;
-    }
+    check(doc).is_ok()
+}
 
-    let digits: Vec<u16> = get_digits(doc, to_decimal);
+/// Realiza validação de CPF, máscarado ou não, informando o motivo da falha.
+/// Retorna `Ok(())` se o argumento `doc` for um CPF válido, caso contrário,
+/// retorna o erro de validação (`ValidationError`) correspondente.
+///
+/// ## Exemplos
+///
+/// CPFs válidos:
+/// ```
+/// use brado::cpf;
+///
+/// let result = cpf::check("63929247011"); // Ok(())
+/// assert!(result.is_ok());
+///
+/// let result = cpf::check("639.292.470-11"); // Ok(())
+/// assert!(result.is_ok());
+/// ```
+///
+/// CPFs inválidos:
+/// ```
+/// use brado::common::ValidationError;
+/// use brado::cpf;
+///
+/// let result = cpf::check("63929247010");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::CheckDigitMismatch {
+///         expected: String::from("11"),
+///         found: String::from("10"),
+///     }),
+/// );
+///
+/// let result = cpf::check("111.111.111-11");
+/// assert_eq!(result, Err(ValidationError::RepeatedDigits));
+///
+/// let result = cpf::check("639.292.470:11");
+/// assert_eq!(result, Err(ValidationError::InvalidMask));
+/// ```
+pub fn check(doc: &str) -> Result<(), ValidationError> {
+    let digits: Vec<u16> = check_format(doc, CPF_SIZE, is_masked, to_decimal)?;
 
-    if digits.len() != CPF_SIZE || is_repeated(&digits) {
-        return false;
+    if is_repeated(&digits) {
+        return Err(ValidationError::RepeatedDigits);
     }
 
     let (d10, d11): (u16, u16) = generate_digits(&digits[..9]);
 
-    (d10, d11) == (digits[9], digits[10])
+    check_digits(&[d10, d11], &digits[9..])
+}
+
+/// Calcula os dígitos verificadores de um CPF a partir dos 9 primeiros
+/// dígitos (`base`), sem símbolos.
+/// Retorna os 2 dígitos verificadores, ou o erro correspondente
+/// (`ValidationError`) caso a base seja inválida.
+///
+/// ## Exemplos
+///
+/// Bases válidas:
+/// ```
+/// use brado::cpf;
+///
+/// let base = "639292470";
+/// let result = cpf::compute_check_digits(base); // Ok("11")
+/// assert_eq!(result, Ok(String::from("11")));
+///
+/// let doc = format!("{}{}", base, result.unwrap()); // "63929247011"
+/// assert!(cpf::validate(&doc));
+/// ```
+///
+/// Bases inválidas:
+/// ```
+/// use brado::common::ValidationError;
+/// use brado::cpf;
+///
+/// let result = cpf::compute_check_digits("63929247");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::InvalidLength {
+///         expected: 9,
+///         found: 8,
+///     }),
+/// );
+///
+/// let result = cpf::compute_check_digits("111111111");
+/// assert_eq!(result, Err(ValidationError::RepeatedDigits));
+/// ```
+pub fn compute_check_digits(base: &str) -> Result<String, ValidationError> {
+    let digits: Vec<u16> =
+        check_format(base, CPF_SIZE - 2, |_| false, to_decimal)?;
+
+    let (d10, d11): (u16, u16) = generate_digits(&digits);
+
+    if is_repeated(&[&digits[..], &[d10, d11]].concat()) {
+        return Err(ValidationError::RepeatedDigits);
+    }
+
+    Ok(format!("{}{}", d10, d11))
 }
 
 fn generate_digits(doc_slice: &[u16]) -> (u16, u16) {
@@ -163,10 +255,8 @@ pub fn is_masked(doc: &str) -> bool {
 ///     Err(e) => panic!("{}", e),
 /// };
 /// ```
-pub fn mask(doc: &str) -> Result<String, &'static str> {
-    if !is_bare(doc) {
-        return Err("The given string cannot be masked as CPF!");
-    }
+pub fn mask(doc: &str) -> Result<String, MaskError> {
+    check_mask(doc, DocKind::Cpf, CPF_SIZE, is_masked, to_decimal)?;
 
     let masked_doc: String = format!(
         "{}.{}.{}-{}",
@@ -179,6 +269,19 @@ pub fn mask(doc: &str) -> Result<String, &'static str> {
     Ok(masked_doc)
 }
 
+/// Remove os símbolos do argumento `doc` e retorna o CPF sem máscara.
+///
+/// ## Exemplo
+/// ```
+/// use brado::cpf;
+///
+/// let result = cpf::unmask("639.292.470-11"); // "63929247011"
+/// assert_eq!(result, "63929247011");
+/// ```
+pub fn unmask(doc: &str) -> String {
+    crate::common::unmask(doc, to_decimal)
+}
+
 /// Gera e retorna um CPF aleatório sem máscara.
 ///
 /// ## Exemplo
@@ -189,7 +292,30 @@ pub fn mask(doc: &str) -> Result<String, &'static str> {
 /// assert!(cpf::is_bare(&result)); // true
 /// ```
 pub fn generate() -> String {
-    let mut cpf: Vec<u16> = random_decimal_vector(9);
+    generate_with_rng(&mut rand::thread_rng())
+}
+
+/// Gera e retorna um CPF aleatório sem máscara a partir do gerador de
+/// números aleatórios `rng`. Geradores com a mesma semente produzem o mesmo
+/// CPF.
+///
+/// ## Exemplo
+/// ```
+/// use brado::cpf;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let result = cpf::generate_with_rng(&mut StdRng::seed_from_u64(42));
+/// assert!(cpf::is_bare(&result)); // true
+///
+/// let other = cpf::generate_with_rng(&mut StdRng::seed_from_u64(42));
+/// assert_eq!(result, other);
+/// ```
+pub fn generate_with_rng<R>(rng: &mut R) -> String
+where
+    R: Rng + ?Sized,
+{
+    let mut cpf: Vec<u16> = random_decimal_vector_with_rng(9, rng);
     cpf.push(generate_digit(&cpf));
     cpf.push(generate_digit(&cpf));
 
@@ -209,5 +335,385 @@ pub fn generate() -> String {
 /// assert!(cpf::is_masked(&result)); // true
 /// ```
 pub fn generate_masked() -> String {
-    mask(&generate()).expect("Invalid CPF!")
+    generate_masked_with_rng(&mut rand::thread_rng())
+}
+
+/// Gera e retorna um CPF aleatório com máscara a partir do gerador de
+/// números aleatórios `rng`. Geradores com a mesma semente produzem o mesmo
+/// CPF.
+///
+/// ## Exemplo
+/// ```
+/// use brado::cpf;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let result = cpf::generate_masked_with_rng(&mut rng);
+/// assert!(cpf::is_masked(&result)); // true
+/// ```
+pub fn generate_masked_with_rng<R>(rng: &mut R) -> String
+where
+    R: Rng + ?Sized,
+{
+    mask(&generate_with_rng(rng)).expect("Invalid CPF!")
+}
+
+/// Regiões fiscais da Receita Federal.
+///
+/// A região fiscal em que o CPF foi emitido é codificada no 9º dígito do
+/// documento, e cada região abrange um conjunto de Unidades Federativas.
+///
+/// ## Exemplo
+/// ```
+/// use brado::common::Uf;
+/// use brado::cpf::FiscalRegion;
+///
+/// let result = FiscalRegion::Eighth.digit(); // 8
+/// assert_eq!(result, 8);
+///
+/// let result = FiscalRegion::Seventh.states(); // [ES, RJ]
+/// assert_eq!(result, &[Uf::Es, Uf::Rj]);
+///
+/// let result = FiscalRegion::from_uf(Uf::Rs); // FiscalRegion::Tenth
+/// assert_eq!(result, FiscalRegion::Tenth);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FiscalRegion {
+    /// 1ª Região Fiscal (1): DF, GO, MS, MT, TO.
+    First,
+    /// 2ª Região Fiscal (2): AC, AM, AP, PA, RO, RR.
+    Second,
+    /// 3ª Região Fiscal (3): CE, MA, PI.
+    Third,
+    /// 4ª Região Fiscal (4): AL, PB, PE, RN.
+    Fourth,
+    /// 5ª Região Fiscal (5): BA, SE.
+    Fifth,
+    /// 6ª Região Fiscal (6): MG.
+    Sixth,
+    /// 7ª Região Fiscal (7): ES, RJ.
+    Seventh,
+    /// 8ª Região Fiscal (8): SP.
+    Eighth,
+    /// 9ª Região Fiscal (9): PR, SC.
+    Ninth,
+    /// 10ª Região Fiscal (0): RS.
+    Tenth,
+}
+
+impl FiscalRegion {
+    /// Todas as regiões fiscais.
+    pub const ALL: &'static [FiscalRegion] = &[
+        FiscalRegion::First,
+        FiscalRegion::Second,
+        FiscalRegion::Third,
+        FiscalRegion::Fourth,
+        FiscalRegion::Fifth,
+        FiscalRegion::Sixth,
+        FiscalRegion::Seventh,
+        FiscalRegion::Eighth,
+        FiscalRegion::Ninth,
+        FiscalRegion::Tenth,
+    ];
+
+    /// Retorna o dígito que identifica a região fiscal no CPF (9º dígito).
+    pub fn digit(&self) -> u16 {
+        match self {
+            FiscalRegion::First => 1,
+            FiscalRegion::Second => 2,
+            FiscalRegion::Third => 3,
+            FiscalRegion::Fourth => 4,
+            FiscalRegion::Fifth => 5,
+            FiscalRegion::Sixth => 6,
+            FiscalRegion::Seventh => 7,
+            FiscalRegion::Eighth => 8,
+            FiscalRegion::Ninth => 9,
+            FiscalRegion::Tenth => 0,
+        }
+    }
+
+    /// Retorna a região fiscal identificada pelo dígito `digit`, ou `None`
+    /// caso o argumento não seja um dígito decimal.
+    pub fn from_digit(digit: u16) -> Option<FiscalRegion> {
+        match digit {
+            1 => Some(FiscalRegion::First),
+            2 => Some(FiscalRegion::Second),
+            3 => Some(FiscalRegion::Third),
+            4 => Some(FiscalRegion::Fourth),
+            5 => Some(FiscalRegion::Fifth),
+            6 => Some(FiscalRegion::Sixth),
+            7 => Some(FiscalRegion::Seventh),
+            8 => Some(FiscalRegion::Eighth),
+            9 => Some(FiscalRegion::Ninth),
+            0 => Some(FiscalRegion::Tenth),
+            _ => None,
+        }
+    }
+
+    /// Retorna as Unidades Federativas abrangidas pela região fiscal.
+    pub fn states(&self) -> &'static [Uf] {
+        match self {
+            FiscalRegion::First => &[Uf::Df, Uf::Go, Uf::Ms, Uf::Mt, Uf::To],
+            FiscalRegion::Second => {
+                &[Uf::Ac, Uf::Am, Uf::Ap, Uf::Pa, Uf::Ro, Uf::Rr]
+            }
+            FiscalRegion::Third => &[Uf::Ce, Uf::Ma, Uf::Pi],
+            FiscalRegion::Fourth => &[Uf::Al, Uf::Pb, Uf::Pe, Uf::Rn],
+            FiscalRegion::Fifth => &[Uf::Ba, Uf::Se],
+            FiscalRegion::Sixth => &[Uf::Mg],
+            FiscalRegion::Seventh => &[Uf::Es, Uf::Rj],
+            FiscalRegion::Eighth => &[Uf::Sp],
+            FiscalRegion::Ninth => &[Uf::Pr, Uf::Sc],
+            FiscalRegion::Tenth => &[Uf::Rs],
+        }
+    }
+
+    /// Retorna a região fiscal que abrange a Unidade Federativa `uf`.
+    pub fn from_uf(uf: Uf) -> FiscalRegion {
+        *FiscalRegion::ALL
+            .iter()
+            .find(|region| region.states().contains(&uf))
+            .expect("Every UF belongs to a fiscal region!")
+    }
+}
+
+/// Retorna a região fiscal em que o CPF `doc`, máscarado ou não, foi
+/// emitido. Retorna `None` caso o CPF seja inválido.
+///
+/// ## Exemplos
+/// ```
+/// use brado::cpf::{self, FiscalRegion};
+///
+/// let result = cpf::fiscal_region("639.292.470-11"); // Some(Tenth)
+/// assert_eq!(result, Some(FiscalRegion::Tenth));
+///
+/// let result = cpf::fiscal_region("639.292.470-10"); // None
+/// assert_eq!(result, None);
+/// ```
+pub fn fiscal_region(doc: &str) -> Option<FiscalRegion> {
+    check(doc).ok()?;
+
+    let digits: Vec<u16> = get_digits(doc, to_decimal);
+
+    FiscalRegion::from_digit(digits[8])
+}
+
+/// Gera e retorna um CPF aleatório sem máscara emitido na região fiscal
+/// `region`.
+///
+/// ## Exemplo
+/// ```
+/// use brado::cpf::{self, FiscalRegion};
+///
+/// let result = cpf::generate_for_region(FiscalRegion::Eighth);
+/// assert!(cpf::validate(&result)); // true
+/// assert_eq!(cpf::fiscal_region(&result), Some(FiscalRegion::Eighth));
+/// ```
+pub fn generate_for_region(region: FiscalRegion) -> String {
+    generate_for_region_with_rng(region, &mut rand::thread_rng())
+}
+
+/// Gera e retorna um CPF aleatório sem máscara emitido na região fiscal
+/// `region`, a partir do gerador de números aleatórios `rng`.
+///
+/// ## Exemplo
+/// ```
+/// use brado::cpf::{self, FiscalRegion};
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let region = FiscalRegion::Sixth;
+/// let result = cpf::generate_for_region_with_rng(region, &mut rng);
+/// assert_eq!(cpf::fiscal_region(&result), Some(FiscalRegion::Sixth));
+/// ```
+pub fn generate_for_region_with_rng<R>(
+    region: FiscalRegion,
+    rng: &mut R,
+) -> String
+where
+    R: Rng + ?Sized,
+{
+    let mut cpf: Vec<u16> = random_decimal_vector_with_rng(8, rng);
+    cpf.push(region.digit());
+    cpf.push(generate_digit(&cpf));
+    cpf.push(generate_digit(&cpf));
+
+    cpf.iter()
+        .map(|d| d.to_string())
+        .collect::<Vec<String>>()
+        .concat()
+}
+
+/// CPF validado, armazenado sem máscara.
+///
+/// Só pode ser construído a partir de um CPF válido, máscarado ou não, por
+/// meio de `FromStr` ou `TryFrom<&str>`. É exibido (`Display`) com máscara.
+///
+/// ## Exemplo
+/// ```
+/// use brado::cpf::Cpf;
+///
+/// let doc: Cpf = "639.292.470-11".parse().unwrap();
+/// assert_eq!(doc.as_bare(), "63929247011");
+/// assert_eq!(doc.to_masked(), "639.292.470-11");
+/// assert_eq!(doc.to_string(), "639.292.470-11");
+///
+/// let result = "639.292.470-10".parse::<Cpf>();
+/// assert!(result.is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Cpf(String);
+
+impl Cpf {
+    /// Retorna o CPF sem máscara.
+    pub fn as_bare(&self) -> &str {
+        &self.0
+    }
+
+    /// Retorna o CPF com máscara.
+    pub fn to_masked(&self) -> String {
+        mask(&self.0).expect("Invalid CPF!")
+    }
+
+    /// Retorna a região fiscal em que o CPF foi emitido.
+    pub fn fiscal_region(&self) -> FiscalRegion {
+        fiscal_region(&self.0).expect("Invalid CPF!")
+    }
+}
+
+impl FromStr for Cpf {
+    type Err = ValidationError;
+
+    fn from_str(doc: &str) -> Result<Self, Self::Err> {
+        check(doc)?;
+        Ok(Cpf(unmask(doc)))
+    }
+}
+
+impl TryFrom<&str> for Cpf {
+    type Error = ValidationError;
+
+    fn try_from(doc: &str) -> Result<Self, Self::Error> {
+        doc.parse()
+    }
+}
+
+impl fmt::Display for Cpf {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(f, "{}", self.to_masked())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Cpf {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_bare())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Cpf {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let doc: String = String::deserialize(deserializer)?;
+        doc.parse().map_err(de::Error::custom)
+    }
+}
+
+/// Tipo marcador do CPF.
+/// Implementa o trait `Document` a partir das funções deste módulo.
+///
+/// ## Exemplo
+/// ```
+/// use brado::common::Document;
+/// use brado::cpf::CpfDocument;
+///
+/// let result = CpfDocument.validate("639.292.470-11"); // true
+/// assert!(result);
+///
+/// let result = CpfDocument.bare_size(); // 11
+/// assert_eq!(result, 11);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CpfDocument;
+
+impl Document for CpfDocument {
+    fn kind(&self) -> DocKind {
+        DocKind::Cpf
+    }
+
+    fn bare_size(&self) -> usize {
+        CPF_SIZE
+    }
+
+    fn check(
+        &self,
+        doc: &str,
+    ) -> Result<(), ValidationError> {
+        check(doc)
+    }
+
+    fn is_bare(
+        &self,
+        doc: &str,
+    ) -> bool {
+        is_bare(doc)
+    }
+
+    fn is_masked(
+        &self,
+        doc: &str,
+    ) -> bool {
+        is_masked(doc)
+    }
+
+    fn mask(
+        &self,
+        doc: &str,
+    ) -> Result<String, MaskError> {
+        mask(doc)
+    }
+
+    fn unmask(
+        &self,
+        doc: &str,
+    ) -> String {
+        unmask(doc)
+    }
+
+    fn generate(&self) -> String {
+        generate()
+    }
+
+    fn generate_masked(&self) -> String {
+        generate_masked()
+    }
+
+    fn generate_with_rng(
+        &self,
+        rng: &mut dyn RngCore,
+    ) -> String {
+        generate_with_rng(rng)
+    }
+
+    fn generate_masked_with_rng(
+        &self,
+        rng: &mut dyn RngCore,
+    ) -> String {
+        generate_masked_with_rng(rng)
+    }
 }
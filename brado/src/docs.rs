@@ -1,13 +1,408 @@
 //! Utilitários para identificação de documentos.
 
+use crate::cep;
 use crate::certidao;
+use crate::chassi;
 use crate::cnh;
 use crate::cnpj;
 use crate::cns;
+use crate::common::{Document, Uf};
 use crate::cpf;
 use crate::eleitoral;
+use crate::ie;
 use crate::nis;
+use crate::placa;
 use crate::renavam;
+use crate::telefone;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::fmt;
+
+/// Tipos de documentos suportados pela biblioteca.
+///
+/// ## Exemplo
+///
+/// ```
+/// use brado::docs::DocKind;
+///
+/// let result = DocKind::Cpf.name(); // "CPF"
+/// assert_eq!(result, "CPF");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DocKind {
+    /// Cadastro de Pessoa Física.
+    Cpf,
+    /// Cadastro Nacional de Pessoa Jurídica.
+    Cnpj,
+    /// Carteira Nacional de Habilitação.
+    Cnh,
+    /// Cartão Nacional de Saúde.
+    Cns,
+    /// NIS/NIT/PIS/PASEP.
+    Nis,
+    /// Título Eleitoral.
+    Eleitoral,
+    /// Registro Nacional de Veículos Automotores.
+    Renavam,
+    /// Certidões de Nascimento, Casamento e Óbito.
+    Certidao,
+    /// Inscrição Estadual da Unidade Federativa informada.
+    Ie(Uf),
+    /// Placa de Identificação Veicular.
+    Placa,
+    /// Número de Identificação do Veículo (chassi).
+    Chassi,
+    /// Código de Endereçamento Postal.
+    Cep,
+    /// Telefone celular, fixo, 0800 ou 0300.
+    Telefone,
+}
+
+impl DocKind {
+    /// Todos os tipos de documento que podem ser identificados sem
+    /// informações adicionais. Não inclui a Inscrição Estadual
+    /// (`DocKind::Ie`), cuja validação depende da UF, nem os documentos
+    /// numéricos sem dígito verificador, que seriam confundidos com outros
+    /// documentos: o CEP (`DocKind::Cep`) e o telefone (`DocKind::Telefone`).
+    ///
+    /// ## Exemplo
+    ///
+    /// ```
+    /// use brado::docs::DocKind;
+    ///
+    /// let result = DocKind::ALL.len(); // 10
+    /// assert_eq!(result, 10);
+    /// ```
+    pub const ALL: &'static [DocKind] = &[
+        DocKind::Cpf,
+        DocKind::Cnpj,
+        DocKind::Cnh,
+        DocKind::Cns,
+        DocKind::Nis,
+        DocKind::Eleitoral,
+        DocKind::Renavam,
+        DocKind::Certidao,
+        DocKind::Placa,
+        DocKind::Chassi,
+    ];
+
+    /// Retorna o nome do tipo de documento.
+    ///
+    /// ## Exemplo
+    ///
+    /// ```
+    /// use brado::docs::DocKind;
+    ///
+    /// let result = DocKind::Nis.name(); // "NIS/NIT/PIS/PASEP"
+    /// assert_eq!(result, "NIS/NIT/PIS/PASEP");
+    /// ```
+    pub fn name(&self) -> &'static str {
+        match self {
+            DocKind::Cpf => "CPF",
+            DocKind::Cnpj => "CNPJ",
+            DocKind::Cnh => "CNH",
+            DocKind::Cns => "CNS",
+            DocKind::Nis => "NIS/NIT/PIS/PASEP",
+            DocKind::Eleitoral => "Título Eleitoral",
+            DocKind::Renavam => "RENAVAM",
+            DocKind::Certidao => "Certidão",
+            DocKind::Ie(_) => "IE",
+            DocKind::Placa => "Placa",
+            DocKind::Chassi => "Chassi",
+            DocKind::Cep => "CEP",
+            DocKind::Telefone => "Telefone",
+        }
+    }
+
+    /// Retorna a descrição do tipo de documento.
+    ///
+    /// ## Exemplo
+    ///
+    /// ```
+    /// use brado::docs::DocKind;
+    ///
+    /// let result = DocKind::Cpf.description(); // "Cadastro de Pessoa Física"
+    /// assert_eq!(result, "Cadastro de Pessoa Física");
+    /// ```
+    pub fn description(&self) -> &'static str {
+        match self {
+            DocKind::Cpf => "Cadastro de Pessoa Física",
+            DocKind::Cnpj => "Cadastro Nacional de Pessoa Jurídica",
+            DocKind::Cnh => "Carteira Nacional de Habilitação",
+            DocKind::Cns => "Cartão Nacional de Saúde",
+            DocKind::Nis => "Número de Identificação Social",
+            DocKind::Eleitoral => "Título Eleitoral",
+            DocKind::Renavam => "Registro Nacional de Veículos Automotores",
+            DocKind::Certidao => "Certidão de Nascimento, Casamento ou Óbito",
+            DocKind::Ie(_) => "Inscrição Estadual",
+            DocKind::Placa => "Placa de Identificação Veicular",
+            DocKind::Chassi => "Número de Identificação do Veículo",
+            DocKind::Cep => "Código de Endereçamento Postal",
+            DocKind::Telefone => "Telefone Celular ou Fixo",
+        }
+    }
+
+    /// Retorna a quantidade de caracteres do documento sem máscara.
+    ///
+    /// ## Exemplo
+    ///
+    /// ```
+    /// use brado::docs::DocKind;
+    ///
+    /// let result = DocKind::Cnpj.bare_size(); // 14
+    /// assert_eq!(result, 14);
+    /// ```
+    pub fn bare_size(&self) -> usize {
+        self.document().bare_size()
+    }
+
+    /// Retorna o objeto do trait `Document` correspondente ao tipo de
+    /// documento.
+    ///
+    /// ## Exemplo
+    ///
+    /// ```
+    /// use brado::docs::DocKind;
+    ///
+    /// let result = DocKind::Cpf.document().validate("639.292.470-11"); // true
+    /// assert!(result);
+    /// ```
+    pub fn document(&self) -> &'static dyn Document {
+        match self {
+            DocKind::Cpf => &cpf::CpfDocument,
+            DocKind::Cnpj => &cnpj::CnpjDocument,
+            DocKind::Cnh => &cnh::CnhDocument,
+            DocKind::Cns => &cns::CnsDocument,
+            DocKind::Nis => &nis::NisDocument,
+            DocKind::Eleitoral => &eleitoral::EleitoralDocument,
+            DocKind::Renavam => &renavam::RenavamDocument,
+            DocKind::Certidao => &certidao::CertidaoDocument,
+            DocKind::Ie(uf) => ie::IeDocument::for_uf(*uf),
+            DocKind::Placa => &placa::PlacaDocument,
+            DocKind::Chassi => &chassi::ChassiDocument,
+            DocKind::Cep => &cep::CepDocument,
+            DocKind::Telefone => &telefone::TelefoneDocument,
+        }
+    }
+
+    /// Realiza validação do documento `doc`, máscarado ou não, como um
+    /// documento do tipo correspondente.
+    ///
+    /// ## Exemplo
+    ///
+    /// ```
+    /// use brado::docs::DocKind;
+    ///
+    /// let result = DocKind::Renavam.validate("7907233836-3"); // true
+    /// assert!(result);
+    ///
+    /// let result = DocKind::Cpf.validate("7907233836-3"); // false
+    /// assert!(!result);
+    /// ```
+    pub fn validate(
+        &self,
+        doc: &str,
+    ) -> bool {
+        self.document().validate(doc)
+    }
+}
+
+impl fmt::Display for DocKind {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Retorna todos os tipos de documento suportados, como objetos do trait
+/// `Document`.
+///
+/// ## Exemplo
+///
+/// ```
+/// use brado::docs;
+///
+/// let result: Vec<&str> = docs::documents()
+///     .iter()
+///     .filter(|document| document.validate("63929247011"))
+///     .map(|document| document.name())
+///     .collect();
+/// assert_eq!(result, vec!["CPF", "CNH"]);
+/// ```
+pub fn documents() -> Vec<&'static dyn Document> {
+    DocKind::ALL.iter().map(|kind| kind.document()).collect()
+}
+
+/// Identifica os tipos de documento que um documento `doc`, máscarado ou não,
+/// pode ser. Retorna todos os tipos (`DocKind`) cuja validação é bem-sucedida,
+/// pois um mesmo documento pode ser válido para mais de um tipo (por exemplo,
+/// um número de 11 dígitos pode ser, ao mesmo tempo, CPF, CNH, NIS e RENAVAM).
+///
+/// ## Exemplos
+///
+/// ```
+/// use brado::docs::{self, DocKind};
+///
+/// let result = docs::identify("63929247011"); // [Cpf, Cnh]
+/// assert_eq!(result, vec![DocKind::Cpf, DocKind::Cnh]);
+///
+/// let result = docs::identify("639.292.470-11"); // [Cpf]
+/// assert_eq!(result, vec![DocKind::Cpf]);
+///
+/// let result = docs::identify("40865658047"); // [Nis, Renavam]
+/// assert_eq!(result, vec![DocKind::Nis, DocKind::Renavam]);
+///
+/// let result = docs::identify("63929247010"); // []
+/// assert!(result.is_empty());
+/// ```
+pub fn identify(doc: &str) -> Vec<DocKind> {
+    DocKind::ALL
+        .iter()
+        .filter(|kind| kind.validate(doc))
+        .copied()
+        .collect()
+}
+
+/// Indício de contexto fornecido para a classificação de um documento.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Hint {
+    /// O documento provavelmente é do tipo informado.
+    Prefer(DocKind),
+    /// O documento provavelmente não é do tipo informado.
+    Avoid(DocKind),
+}
+
+/// Motivo que contribui para a pontuação de um candidato na classificação de
+/// um documento.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Reason {
+    /// O documento é válido para o tipo.
+    Valid,
+    /// O documento é válido apenas para este tipo.
+    Unambiguous,
+    /// O documento está mascarado de acordo com o tipo.
+    MaskMatches,
+    /// O chamador indicou que o documento provavelmente é do tipo.
+    Preferred,
+    /// O chamador indicou que o documento provavelmente não é do tipo.
+    Avoided,
+}
+
+impl Reason {
+    /// Retorna o peso do motivo na pontuação de um candidato.
+    ///
+    /// ## Exemplo
+    ///
+    /// ```
+    /// use brado::docs::Reason;
+    ///
+    /// let result = Reason::MaskMatches.weight(); // 2
+    /// assert_eq!(result, 2);
+    /// ```
+    pub fn weight(&self) -> i32 {
+        match self {
+            Reason::Valid => 1,
+            Reason::Unambiguous => 2,
+            Reason::MaskMatches => 2,
+            Reason::Preferred => 3,
+            Reason::Avoided => -3,
+        }
+    }
+}
+
+/// Candidato resultante da classificação de um documento.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Candidate {
+    /// Tipo do documento.
+    pub kind: DocKind,
+    /// Pontuação do candidato, isto é, a soma dos pesos dos motivos.
+    pub score: i32,
+    /// Motivos que contribuíram para a pontuação.
+    pub reasons: Vec<Reason>,
+}
+
+/// Classifica um documento `doc`, máscarado ou não, entre os tipos de
+/// documento para os quais ele é válido.
+///
+/// Além da validade, cada candidato é pontuado pelo formato da máscara (por
+/// exemplo, `639.292.470-11` só está mascarado como CPF) e pelos indícios de
+/// contexto (`hints`) informados. Retorna os candidatos ordenados da maior
+/// para a menor pontuação; em caso de empate, é mantida a ordem de
+/// `DocKind::ALL`.
+///
+/// ## Exemplos
+///
+/// ```
+/// use brado::docs::{self, DocKind, Hint, Reason};
+///
+/// let result = docs::classify("639.292.470-11", &[]);
+/// assert_eq!(result[0].kind, DocKind::Cpf);
+/// assert_eq!(
+///     result[0].reasons,
+///     vec![Reason::Valid, Reason::Unambiguous, Reason::MaskMatches],
+/// );
+///
+/// let result = docs::classify("40865658047", &[]);
+/// let kinds: Vec<DocKind> = result.iter().map(|c| c.kind).collect();
+/// assert_eq!(kinds, vec![DocKind::Nis, DocKind::Renavam]);
+///
+/// let result = docs::classify("40865658047", &[Hint::Prefer(DocKind::Renavam)]);
+/// let kinds: Vec<DocKind> = result.iter().map(|c| c.kind).collect();
+/// assert_eq!(kinds, vec![DocKind::Renavam, DocKind::Nis]);
+/// ```
+pub fn classify(
+    doc: &str,
+    hints: &[Hint],
+) -> Vec<Candidate> {
+    let kinds: Vec<DocKind> = identify(doc);
+
+    let mut candidates: Vec<Candidate> = kinds
+        .iter()
+        .map(|&kind| {
+            let document: &dyn Document = kind.document();
+            let mut reasons: Vec<Reason> = vec![Reason::Valid];
+
+            if kinds.len() == 1 {
+                reasons.push(Reason::Unambiguous);
+            }
+
+            if document.is_masked(doc) {
+                reasons.push(Reason::MaskMatches);
+            }
+
+            for hint in hints {
+                match hint {
+                    Hint::Prefer(k) if *k == kind => {
+                        reasons.push(Reason::Preferred)
+                    }
+                    Hint::Avoid(k) if *k == kind => {
+                        reasons.push(Reason::Avoided)
+                    }
+                    _ => (),
+                }
+            }
+
+            let score: i32 = reasons.iter().map(|r| r.weight()).sum();
+
+            Candidate {
+                kind,
+                score,
+                reasons,
+            }
+        })
+        .collect();
+
+    candidates.sort_by_key(|c| Reverse(c.score));
+
+    candidates
+}
 
 /// Verifica se um documento `doc` é um CPF, máscarado ou não.
 /// Retorna `true` se o argumento `doc` for um CPF válido, caso contrário,
@@ -256,3 +651,164 @@ pub fn is_renavam(doc: &str) -> bool {
 pub fn is_certidao(doc: &str) -> bool {
     certidao::validate(doc)
 }
+
+/// Verifica se um documento `doc` é uma Inscrição Estadual da UF `uf`,
+/// máscarada ou não.
+/// Retorna `true` se o argumento `doc` for uma Inscrição Estadual válida,
+/// caso contrário, retorna `false`.
+///
+/// ## Exemplos
+///
+/// Inscrições Estaduais válidas:
+/// ```
+/// use brado::common::Uf;
+/// use brado::docs;
+///
+/// let result = docs::is_ie(Uf::Sp, "110042490114"); // true
+/// assert!(result);
+///
+/// let result = docs::is_ie(Uf::Sp, "110.042.490.114"); // true
+/// assert!(result);
+/// ```
+///
+/// Inscrições Estaduais inválidas:
+/// ```
+/// use brado::common::Uf;
+/// use brado::docs;
+///
+/// let result = docs::is_ie(Uf::Sp, "110042490115"); // false
+/// assert!(!result);
+///
+/// let result = docs::is_ie(Uf::Mg, "110.042.490.114"); // false
+/// assert!(!result);
+/// ```
+pub fn is_ie(
+    uf: Uf,
+    doc: &str,
+) -> bool {
+    ie::validate(uf, doc)
+}
+
+/// Verifica se um documento `doc` é uma Placa, máscarada ou não.
+/// Retorna `true` se o argumento `doc` for uma Placa válida, caso contrário,
+/// retorna `false`.
+///
+/// ## Exemplos
+///
+/// Placas válidas:
+/// ```
+/// use brado::docs;
+///
+/// let result = docs::is_placa("ABC1234"); // true
+/// assert!(result);
+///
+/// let result = docs::is_placa("ABC1C34"); // true
+/// assert!(result);
+/// ```
+///
+/// Placas inválidas:
+/// ```
+/// use brado::docs;
+///
+/// let result = docs::is_placa("AB12345"); // false
+/// assert!(!result);
+///
+/// let result = docs::is_placa("ABC-12C4"); // false
+/// assert!(!result);
+/// ```
+pub fn is_placa(doc: &str) -> bool {
+    placa::validate(doc)
+}
+
+/// Verifica se um documento `doc` é um chassi, máscarado ou não.
+/// Retorna `true` se o argumento `doc` for um chassi válido, caso contrário,
+/// retorna `false`.
+///
+/// ## Exemplos
+///
+/// Chassis válidos:
+/// ```
+/// use brado::docs;
+///
+/// let result = docs::is_chassi("9BWZZZ372VT004251"); // true
+/// assert!(result);
+///
+/// let result = docs::is_chassi("9BW ZZZ372 VT004251"); // true
+/// assert!(result);
+/// ```
+///
+/// Chassis inválidos:
+/// ```
+/// use brado::docs;
+///
+/// let result = docs::is_chassi("9BWZZZ373VT004251"); // false
+/// assert!(!result);
+///
+/// let result = docs::is_chassi("9BW ZZZ373 VT004251"); // false
+/// assert!(!result);
+/// ```
+pub fn is_chassi(doc: &str) -> bool {
+    chassi::validate(doc)
+}
+
+/// Verifica se um documento `doc` é um CEP, máscarado ou não.
+/// Retorna `true` se o argumento `doc` for um CEP válido, caso contrário,
+/// retorna `false`.
+///
+/// ## Exemplos
+///
+/// CEPs válidos:
+/// ```
+/// use brado::docs;
+///
+/// let result = docs::is_cep("01310100"); // true
+/// assert!(result);
+///
+/// let result = docs::is_cep("01310-100"); // true
+/// assert!(result);
+/// ```
+///
+/// CEPs inválidos:
+/// ```
+/// use brado::docs;
+///
+/// let result = docs::is_cep("00310100"); // false
+/// assert!(!result);
+///
+/// let result = docs::is_cep("0131-0100"); // false
+/// assert!(!result);
+/// ```
+pub fn is_cep(doc: &str) -> bool {
+    cep::validate(doc)
+}
+
+/// Verifica se um documento `doc` é um telefone, máscarado ou não.
+/// Retorna `true` se o argumento `doc` for um telefone válido, caso
+/// contrário, retorna `false`.
+///
+/// ## Exemplos
+///
+/// Telefones válidos:
+/// ```
+/// use brado::docs;
+///
+/// let result = docs::is_telefone("11912345678"); // true
+/// assert!(result);
+///
+/// let result = docs::is_telefone("(11) 91234-5678"); // true
+/// assert!(result);
+/// ```
+///
+/// Telefones inválidos:
+/// ```
+/// use brado::docs;
+///
+/// let result = docs::is_telefone("10912345678"); // false
+/// assert!(!result);
+///
+/// let result = docs::is_telefone("(11) 81234-5678"); // false
+/// assert!(!result);
+/// ```
+pub fn is_telefone(doc: &str) -> bool {
+    telefone::validate(doc)
+}
@@ -1,12 +1,54 @@
 //! Utilitários para validação de Título Eleitoral.
 
 use crate::common::{
-    get_digits, get_symbols, random_decimal_vector, to_decimal,
+    check_digits, check_format, check_mask, get_digits, get_symbols,
+    random_decimal_vector_with_rng, to_decimal, Document, MaskError, Uf,
+    ValidationError,
 };
-use rand::Rng;
+use crate::docs::DocKind;
+use rand::{Rng, RngCore};
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
 
 const ELEITORAL_SIZE: usize = 12;
 
+/// Unidades Federativas na ordem dos códigos de estado (dígitos 9 e 10) do
+/// Título Eleitoral: 01 = SP, 02 = MG, ..., 27 = TO.
+const STATES: [Uf; 27] = [
+    Uf::Sp,
+    Uf::Mg,
+    Uf::Rj,
+    Uf::Rs,
+    Uf::Ba,
+    Uf::Pr,
+    Uf::Ce,
+    Uf::Pe,
+    Uf::Sc,
+    Uf::Go,
+    Uf::Ma,
+    Uf::Pb,
+    Uf::Pa,
+    Uf::Es,
+    Uf::Pi,
+    Uf::Rn,
+    Uf::Al,
+    Uf::Mt,
+    Uf::Ms,
+    Uf::Df,
+    Uf::Se,
+    Uf::Am,
+    Uf::Ro,
+    Uf::Ac,
+    Uf::Ap,
+    Uf::Rr,
+    Uf::To,
+];
+
+/// Código de estado dos Títulos Eleitorais emitidos no exterior (ZZ).
+const EXTERIOR_CODE: u16 = 28;
+
 /// Realiza validação de Título Eleitoral, máscarado ou não.
 /// Retorna `true` se o argumento `doc` for um Título Eleitoral válido,
 /// caso contrário, retorna `false`.
@@ -35,31 +77,149 @@ const ELEITORAL_SIZE: usize = 12;
 /// assert!(!result);
 /// ```
 pub fn validate(doc: &str) -> bool {
-    let size: usize = doc.chars().count();
+    check(doc).is_ok()
+}
 
-    if size != ELEITORAL_SIZE && !is_masked(doc) {
-        return false;
-    }
+/// Realiza validação de Título Eleitoral, máscarado ou não, informando o
+/// motivo da falha.
+/// Retorna `Ok(())` se o argumento `doc` for um Título Eleitoral válido,
+/// caso contrário, retorna o erro de validação (`ValidationError`)
+/// correspondente.
+///
+/// ## Exemplos
+///
+/// Títulos Eleitorais válidos:
+/// ```
+/// use brado::eleitoral;
+///
+/// let result = eleitoral::check("773537801651"); // Ok(())
+/// assert!(result.is_ok());
+///
+/// let result = eleitoral::check("7735 3780 1651"); // Ok(())
+/// assert!(result.is_ok());
+/// ```
+///
+/// Títulos Eleitorais inválidos:
+/// ```
+/// use brado::common::ValidationError;
+/// use brado::eleitoral;
+///
+/// let result = eleitoral::check("773537801650");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::CheckDigitMismatch {
+///         expected: String::from("51"),
+///         found: String::from("50"),
+///     }),
+/// );
+///
+/// let result = eleitoral::check("77353780165");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::InvalidLength {
+///         expected: 12,
+///         found: 11,
+///     }),
+/// );
+///
+/// let result = eleitoral::check("773537802951");
+/// assert_eq!(result, Err(ValidationError::InvalidStateCode { found: 29 }));
+/// ```
+pub fn check(doc: &str) -> Result<(), ValidationError> {
+    let digits: Vec<u16> =
+        check_format(doc, ELEITORAL_SIZE, is_masked, to_decimal)?;
 
-    let digits: Vec<u16> = get_digits(doc, to_decimal);
+    check_state_code(&digits)?;
 
-    if digits.len() != ELEITORAL_SIZE {
-        return false;
-    }
+    let (d11, d12): (u16, u16) = generate_digits(&digits);
+
+    check_digits(&[d11, d12], &digits[10..])
+}
+
+/// Calcula os dígitos verificadores de um Título Eleitoral a partir dos 10
+/// primeiros dígitos (`base`), sem símbolos.
+/// Retorna os 2 dígitos verificadores, ou o erro correspondente
+/// (`ValidationError`) caso a base seja inválida.
+///
+/// ## Exemplos
+///
+/// Bases válidas:
+/// ```
+/// use brado::eleitoral;
+///
+/// let base = "7735378016";
+/// let result = eleitoral::compute_check_digits(base); // Ok("51")
+/// assert_eq!(result, Ok(String::from("51")));
+///
+/// let doc = format!("{}{}", base, result.unwrap()); // "773537801651"
+/// assert!(eleitoral::validate(&doc));
+/// ```
+///
+/// Bases inválidas:
+/// ```
+/// use brado::common::ValidationError;
+/// use brado::eleitoral;
+///
+/// let result = eleitoral::compute_check_digits("77353780");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::InvalidLength {
+///         expected: 10,
+///         found: 8,
+///     }),
+/// );
+/// ```
+pub fn compute_check_digits(base: &str) -> Result<String, ValidationError> {
+    let digits: Vec<u16> =
+        check_format(base, ELEITORAL_SIZE - 2, |_| false, to_decimal)?;
+
+    check_state_code(&digits)?;
 
     let (d11, d12): (u16, u16) = generate_digits(&digits);
 
-    (d11, d12) == (digits[10], digits[11])
+    Ok(format!("{}{}", d11, d12))
+}
+
+fn state_code(doc_slice: &[u16]) -> u16 {
+    doc_slice[8] * 10 + doc_slice[9]
+}
+
+fn check_state_code(doc_slice: &[u16]) -> Result<(), ValidationError> {
+    let code: u16 = state_code(doc_slice);
+
+    match (1..=EXTERIOR_CODE).contains(&code) {
+        true => Ok(()),
+        false => Err(ValidationError::InvalidStateCode { found: code }),
+    }
+}
+
+fn uf_from_code(code: u16) -> Option<Uf> {
+    STATES.get(code.checked_sub(1)? as usize).copied()
+}
+
+fn code_from_uf(uf: Uf) -> u16 {
+    let idx: usize = STATES
+        .iter()
+        .position(|state| *state == uf)
+        .expect("Every UF has a state code!");
+
+    idx as u16 + 1
 }
 
 fn generate_digits(doc_slice: &[u16]) -> (u16, u16) {
-    let d11: u16 = generate_first_digit(&doc_slice[0..8]);
-    let d12: u16 = generate_second_digit(&doc_slice[8..10], d11);
+    // SP (01) e MG (02): resto 0 resulta em dígito verificador 1.
+    let zero_as_one: bool = [1, 2].contains(&state_code(doc_slice));
+
+    let d11: u16 = generate_first_digit(&doc_slice[0..8], zero_as_one);
+    let d12: u16 = generate_second_digit(&doc_slice[8..10], d11, zero_as_one);
 
     (d11, d12)
 }
 
-fn generate_first_digit(doc_slice: &[u16]) -> u16 {
+fn generate_first_digit(
+    doc_slice: &[u16],
+    zero_as_one: bool,
+) -> u16 {
     let multipliers: Vec<u16> = (2..10).collect();
 
     let sum: u16 = doc_slice
@@ -72,6 +232,7 @@ fn generate_first_digit(doc_slice: &[u16]) -> u16 {
 
     match rest {
         10 => 0,
+        0 if zero_as_one => 1,
         _ => rest,
     }
 }
@@ -79,6 +240,7 @@ fn generate_first_digit(doc_slice: &[u16]) -> u16 {
 fn generate_second_digit(
     doc_slice: &[u16],
     first_digit: u16,
+    zero_as_one: bool,
 ) -> u16 {
     let multipliers: Vec<u16> = (7..10).collect();
 
@@ -95,6 +257,7 @@ fn generate_second_digit(
 
     match rest {
         10 => 0,
+        0 if zero_as_one => 1,
         _ => rest,
     }
 }
@@ -187,10 +350,14 @@ pub fn is_masked(doc: &str) -> bool {
 ///     Err(e) => panic!("{}", e),
 /// };
 /// ```
-pub fn mask(doc: &str) -> Result<String, &'static str> {
-    if !is_bare(doc) {
-        return Err("The given string cannot be masked as Título Eleitoral!");
-    }
+pub fn mask(doc: &str) -> Result<String, MaskError> {
+    check_mask(
+        doc,
+        DocKind::Eleitoral,
+        ELEITORAL_SIZE,
+        is_masked,
+        to_decimal,
+    )?;
 
     let masked_doc: String =
         format!("{} {} {}", &doc[0..4], &doc[4..8], &doc[8..12]);
@@ -198,6 +365,20 @@ pub fn mask(doc: &str) -> Result<String, &'static str> {
     Ok(masked_doc)
 }
 
+/// Remove os símbolos do argumento `doc` e retorna o Título Eleitoral sem
+/// máscara.
+///
+/// ## Exemplo
+/// ```
+/// use brado::eleitoral;
+///
+/// let result = eleitoral::unmask("7735 3780 1651"); // "773537801651"
+/// assert_eq!(result, "773537801651");
+/// ```
+pub fn unmask(doc: &str) -> String {
+    crate::common::unmask(doc, to_decimal)
+}
+
 /// Gera e retorna um Título Eleitoral aleatório sem máscara.
 ///
 /// ## Exemplo
@@ -208,13 +389,46 @@ pub fn mask(doc: &str) -> Result<String, &'static str> {
 /// assert!(eleitoral::is_bare(&result)); // true
 /// ```
 pub fn generate() -> String {
-    let mut eleitoral: Vec<u16> = random_decimal_vector(8);
-    eleitoral.extend(generate_state_identifier());
+    generate_with_rng(&mut rand::thread_rng())
+}
+
+/// Gera e retorna um Título Eleitoral aleatório sem máscara a partir do
+/// gerador de números aleatórios `rng`. Geradores com a mesma semente
+/// produzem o mesmo Título Eleitoral.
+///
+/// ## Exemplo
+/// ```
+/// use brado::eleitoral;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let result = eleitoral::generate_with_rng(&mut StdRng::seed_from_u64(42));
+/// assert!(eleitoral::is_bare(&result)); // true
+///
+/// let other = eleitoral::generate_with_rng(&mut StdRng::seed_from_u64(42));
+/// assert_eq!(result, other);
+/// ```
+pub fn generate_with_rng<R>(rng: &mut R) -> String
+where
+    R: Rng + ?Sized,
+{
+    let code: u16 = rng.gen_range(1..=EXTERIOR_CODE);
+
+    generate_for_code(code, rng)
+}
+
+fn generate_for_code<R>(
+    code: u16,
+    rng: &mut R,
+) -> String
+where
+    R: Rng + ?Sized,
+{
+    let mut eleitoral: Vec<u16> = random_decimal_vector_with_rng(8, rng);
+    eleitoral.extend([code / 10, code % 10]);
 
-    let d11: u16 = generate_first_digit(&eleitoral[0..8]);
-    eleitoral.push(d11);
-    let d12: u16 = generate_second_digit(&eleitoral[8..10], d11);
-    eleitoral.push(d12);
+    let (d11, d12): (u16, u16) = generate_digits(&eleitoral);
+    eleitoral.extend([d11, d12]);
 
     eleitoral
         .iter()
@@ -223,12 +437,6 @@ pub fn generate() -> String {
         .concat()
 }
 
-fn generate_state_identifier() -> Vec<u16> {
-    let mut rng = rand::thread_rng();
-
-    vec![rng.gen_range(0..2), rng.gen_range(0..10)]
-}
-
 /// Gera e retorna um Título Eleitoral aleatório com máscara.
 ///
 /// ## Exemplo
@@ -239,5 +447,285 @@ fn generate_state_identifier() -> Vec<u16> {
 /// assert!(eleitoral::is_masked(&result)); // true
 /// ```
 pub fn generate_masked() -> String {
-    mask(&generate()).expect("Invalid Título Eleitoral!")
+    generate_masked_with_rng(&mut rand::thread_rng())
+}
+
+/// Gera e retorna um Título Eleitoral aleatório com máscara a partir do
+/// gerador de números aleatórios `rng`. Geradores com a mesma semente
+/// produzem o mesmo Título Eleitoral.
+///
+/// ## Exemplo
+/// ```
+/// use brado::eleitoral;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let result = eleitoral::generate_masked_with_rng(&mut rng);
+/// assert!(eleitoral::is_masked(&result)); // true
+/// ```
+pub fn generate_masked_with_rng<R>(rng: &mut R) -> String
+where
+    R: Rng + ?Sized,
+{
+    mask(&generate_with_rng(rng)).expect("Invalid Título Eleitoral!")
+}
+
+/// Retorna a Unidade Federativa em que o Título Eleitoral `doc`, máscarado
+/// ou não, foi emitido. Retorna `None` caso o Título Eleitoral seja inválido
+/// ou tenha sido emitido no exterior (código de estado 28, ZZ).
+///
+/// ## Exemplos
+/// ```
+/// use brado::common::Uf;
+/// use brado::eleitoral;
+///
+/// let result = eleitoral::uf("7735 3780 1651"); // Some(Uf::Rn)
+/// assert_eq!(result, Some(Uf::Rn));
+///
+/// let result = eleitoral::uf("7735 3780 1650"); // None
+/// assert_eq!(result, None);
+/// ```
+pub fn uf(doc: &str) -> Option<Uf> {
+    check(doc).ok()?;
+
+    uf_from_code(state_code(&get_digits(doc, to_decimal)))
+}
+
+/// Verifica se o Título Eleitoral `doc`, máscarado ou não, é válido e foi
+/// emitido no exterior (código de estado 28, ZZ).
+///
+/// ## Exemplos
+/// ```
+/// use brado::eleitoral;
+///
+/// let result = eleitoral::is_exterior("7735 3780 2852"); // true
+/// assert!(result);
+///
+/// let result = eleitoral::is_exterior("7735 3780 1651"); // false
+/// assert!(!result);
+/// ```
+pub fn is_exterior(doc: &str) -> bool {
+    match check(doc) {
+        Ok(()) => state_code(&get_digits(doc, to_decimal)) == EXTERIOR_CODE,
+        Err(_) => false,
+    }
+}
+
+/// Gera e retorna um Título Eleitoral aleatório sem máscara emitido na
+/// Unidade Federativa `uf`.
+///
+/// ## Exemplo
+/// ```
+/// use brado::common::Uf;
+/// use brado::eleitoral;
+///
+/// let result = eleitoral::generate_for_uf(Uf::Sp);
+/// assert!(eleitoral::validate(&result)); // true
+/// assert_eq!(eleitoral::uf(&result), Some(Uf::Sp));
+/// ```
+pub fn generate_for_uf(uf: Uf) -> String {
+    generate_for_uf_with_rng(uf, &mut rand::thread_rng())
+}
+
+/// Gera e retorna um Título Eleitoral aleatório sem máscara emitido na
+/// Unidade Federativa `uf`, a partir do gerador de números aleatórios `rng`.
+///
+/// ## Exemplo
+/// ```
+/// use brado::common::Uf;
+/// use brado::eleitoral;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let result = eleitoral::generate_for_uf_with_rng(Uf::Mg, &mut rng);
+/// assert_eq!(eleitoral::uf(&result), Some(Uf::Mg));
+/// ```
+pub fn generate_for_uf_with_rng<R>(
+    uf: Uf,
+    rng: &mut R,
+) -> String
+where
+    R: Rng + ?Sized,
+{
+    generate_for_code(code_from_uf(uf), rng)
+}
+
+/// Título Eleitoral validado, armazenado sem máscara.
+///
+/// Só pode ser construído a partir de um Título Eleitoral válido, máscarado
+/// ou não, por meio de `FromStr` ou `TryFrom<&str>`. É exibido (`Display`)
+/// com máscara.
+///
+/// ## Exemplo
+/// ```
+/// use brado::eleitoral::TituloEleitoral;
+///
+/// let doc: TituloEleitoral = "7735 3780 1651".parse().unwrap();
+/// assert_eq!(doc.as_bare(), "773537801651");
+/// assert_eq!(doc.to_masked(), "7735 3780 1651");
+/// assert_eq!(doc.to_string(), "7735 3780 1651");
+///
+/// let result = "7735 3780 1650".parse::<TituloEleitoral>();
+/// assert!(result.is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TituloEleitoral(String);
+
+impl TituloEleitoral {
+    /// Retorna o Título Eleitoral sem máscara.
+    pub fn as_bare(&self) -> &str {
+        &self.0
+    }
+
+    /// Retorna o Título Eleitoral com máscara.
+    pub fn to_masked(&self) -> String {
+        mask(&self.0).expect("Invalid Título Eleitoral!")
+    }
+
+    /// Retorna a Unidade Federativa em que o Título Eleitoral foi emitido,
+    /// ou `None` caso tenha sido emitido no exterior.
+    pub fn uf(&self) -> Option<Uf> {
+        uf(&self.0)
+    }
+
+    /// Verifica se o Título Eleitoral foi emitido no exterior.
+    pub fn is_exterior(&self) -> bool {
+        is_exterior(&self.0)
+    }
+}
+
+impl FromStr for TituloEleitoral {
+    type Err = ValidationError;
+
+    fn from_str(doc: &str) -> Result<Self, Self::Err> {
+        check(doc)?;
+        Ok(TituloEleitoral(unmask(doc)))
+    }
+}
+
+impl TryFrom<&str> for TituloEleitoral {
+    type Error = ValidationError;
+
+    fn try_from(doc: &str) -> Result<Self, Self::Error> {
+        doc.parse()
+    }
+}
+
+impl fmt::Display for TituloEleitoral {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(f, "{}", self.to_masked())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for TituloEleitoral {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_bare())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for TituloEleitoral {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let doc: String = String::deserialize(deserializer)?;
+        doc.parse().map_err(de::Error::custom)
+    }
+}
+
+/// Tipo marcador do Título Eleitoral.
+/// Implementa o trait `Document` a partir das funções deste módulo.
+///
+/// ## Exemplo
+/// ```
+/// use brado::common::Document;
+/// use brado::eleitoral::EleitoralDocument;
+///
+/// let result = EleitoralDocument.validate("7735 3780 1651"); // true
+/// assert!(result);
+///
+/// let result = EleitoralDocument.bare_size(); // 12
+/// assert_eq!(result, 12);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct EleitoralDocument;
+
+impl Document for EleitoralDocument {
+    fn kind(&self) -> DocKind {
+        DocKind::Eleitoral
+    }
+
+    fn bare_size(&self) -> usize {
+        ELEITORAL_SIZE
+    }
+
+    fn check(
+        &self,
+        doc: &str,
+    ) -> Result<(), ValidationError> {
+        check(doc)
+    }
+
+    fn is_bare(
+        &self,
+        doc: &str,
+    ) -> bool {
+        is_bare(doc)
+    }
+
+    fn is_masked(
+        &self,
+        doc: &str,
+    ) -> bool {
+        is_masked(doc)
+    }
+
+    fn mask(
+        &self,
+        doc: &str,
+    ) -> Result<String, MaskError> {
+        mask(doc)
+    }
+
+    fn unmask(
+        &self,
+        doc: &str,
+    ) -> String {
+        unmask(doc)
+    }
+
+    fn generate(&self) -> String {
+        generate()
+    }
+
+    fn generate_masked(&self) -> String {
+        generate_masked()
+    }
+
+    fn generate_with_rng(
+        &self,
+        rng: &mut dyn RngCore,
+    ) -> String {
+        generate_with_rng(rng)
+    }
+
+    fn generate_masked_with_rng(
+        &self,
+        rng: &mut dyn RngCore,
+    ) -> String {
+        generate_masked_with_rng(rng)
+    }
 }
@@ -0,0 +1,1148 @@
+//! Utilitários para validação de Inscrição Estadual (IE).
+//!
+//! Cada Unidade Federativa (`Uf`) define o tamanho, os prefixos permitidos, a
+//! máscara e o cálculo dos dígitos verificadores da sua Inscrição Estadual,
+//! por isso as funções deste módulo recebem a UF do documento.
+
+use crate::common::{
+    check_digits, get_digits, random_decimal_vector_with_rng,
+    random_element_from_vector_with_rng, to_decimal, Document, MaskError, Uf,
+    ValidationError,
+};
+use crate::docs::DocKind;
+use rand::{Rng, RngCore};
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+const DIGIT: char = '#';
+const RURAL_PREFIX: char = 'P';
+
+const WEIGHTS_9_TO_2: [u16; 8] = [9, 8, 7, 6, 5, 4, 3, 2];
+
+/// Formato de Inscrição Estadual de uma UF.
+///
+/// - `mask`: máscara do documento, em que `#` representa um dígito;
+/// - `prefixes`: prefixos permitidos, em que `#` representa qualquer dígito
+///   (vazio caso não haja restrição);
+/// - `check_positions`: posições dos dígitos verificadores;
+/// - `fill`: calcula os dígitos verificadores, preenchendo-os no vetor.
+struct Layout {
+    mask: &'static str,
+    prefixes: &'static [&'static str],
+    check_positions: &'static [usize],
+    fill: fn(&mut [u16]),
+}
+
+impl Layout {
+    fn bare(&self) -> String {
+        self.mask
+            .chars()
+            .filter(|&c| c == DIGIT || c == RURAL_PREFIX)
+            .collect()
+    }
+
+    fn size(&self) -> usize {
+        self.mask.chars().filter(|&c| c == DIGIT).count()
+    }
+
+    fn has_symbols(&self) -> bool {
+        self.bare() != self.mask
+    }
+
+    fn check_prefix(
+        &self,
+        digits: &[u16],
+    ) -> Result<(), ValidationError> {
+        if self.prefixes.is_empty()
+            || self.prefixes.iter().any(|p| matches_prefix(p, digits))
+        {
+            return Ok(());
+        }
+
+        Err(ValidationError::InvalidPrefix {
+            found: digits[..self.prefixes[0].len()]
+                .iter()
+                .map(|d| d.to_string())
+                .collect(),
+        })
+    }
+
+    fn check_digits(
+        &self,
+        digits: &[u16],
+    ) -> Result<(), ValidationError> {
+        let mut expected: Vec<u16> = digits.to_vec();
+        (self.fill)(&mut expected);
+
+        let pick = |doc: &[u16]| -> Vec<u16> {
+            self.check_positions.iter().map(|&i| doc[i]).collect()
+        };
+
+        check_digits(&pick(&expected), &pick(digits))
+    }
+}
+
+fn matches_prefix(
+    prefix: &str,
+    digits: &[u16],
+) -> bool {
+    prefix
+        .chars()
+        .zip(digits)
+        .all(|(p, &d)| p == DIGIT || to_decimal(p) == Some(d))
+}
+
+fn matches_char(
+    pattern: char,
+    c: char,
+) -> bool {
+    match pattern {
+        DIGIT => c.is_ascii_digit(),
+        _ => pattern == c,
+    }
+}
+
+fn matches_pattern(
+    pattern: &str,
+    doc: &str,
+) -> bool {
+    pattern.chars().count() == doc.chars().count()
+        && pattern
+            .chars()
+            .zip(doc.chars())
+            .all(|(p, c)| matches_char(p, c))
+}
+
+fn layouts(uf: Uf) -> &'static [Layout] {
+    match uf {
+        Uf::Ac => &[Layout {
+            mask: "##.###.###/###-##",
+            prefixes: &["01"],
+            check_positions: &[11, 12],
+            fill: fill_ac_df,
+        }],
+        Uf::Al => &[Layout {
+            mask: "#########",
+            prefixes: &["240", "243", "245", "247", "248"],
+            check_positions: &[8],
+            fill: fill_al_rn,
+        }],
+        Uf::Ap => &[Layout {
+            mask: "#########",
+            prefixes: &["03"],
+            check_positions: &[8],
+            fill: fill_ap,
+        }],
+        Uf::Am => &[Layout {
+            mask: "##.###.###-#",
+            prefixes: &[],
+            check_positions: &[8],
+            fill: fill_mod11,
+        }],
+        Uf::Ba => &[
+            Layout {
+                mask: "#######-##",
+                prefixes: &[],
+                check_positions: &[7, 8],
+                fill: fill_ba,
+            },
+            Layout {
+                mask: "######-##",
+                prefixes: &[],
+                check_positions: &[6, 7],
+                fill: fill_ba,
+            },
+        ],
+        Uf::Ce => &[Layout {
+            mask: "########-#",
+            prefixes: &[],
+            check_positions: &[8],
+            fill: fill_mod11,
+        }],
+        Uf::Df => &[Layout {
+            mask: "###########-##",
+            prefixes: &["07"],
+            check_positions: &[11, 12],
+            fill: fill_ac_df,
+        }],
+        Uf::Es => &[Layout {
+            mask: "###.###.##-#",
+            prefixes: &[],
+            check_positions: &[8],
+            fill: fill_mod11,
+        }],
+        Uf::Go => &[Layout {
+            mask: "##.###.###-#",
+            prefixes: &[
+                "10", "11", "15", "20", "21", "22", "23", "24", "25", "26",
+                "27", "28", "29",
+            ],
+            check_positions: &[8],
+            fill: fill_go,
+        }],
+        Uf::Ma => &[Layout {
+            mask: "#########",
+            prefixes: &["12"],
+            check_positions: &[8],
+            fill: fill_mod11,
+        }],
+        Uf::Mt => &[Layout {
+            mask: "##########-#",
+            prefixes: &[],
+            check_positions: &[10],
+            fill: fill_mt,
+        }],
+        Uf::Ms => &[Layout {
+            mask: "#########",
+            prefixes: &["28", "50"],
+            check_positions: &[8],
+            fill: fill_mod11,
+        }],
+        Uf::Mg => &[Layout {
+            mask: "###.###.###/####",
+            prefixes: &[],
+            check_positions: &[11, 12],
+            fill: fill_mg,
+        }],
+        Uf::Pa => &[Layout {
+            mask: "##-######-#",
+            prefixes: &["15"],
+            check_positions: &[8],
+            fill: fill_mod11,
+        }],
+        Uf::Pb => &[Layout {
+            mask: "########-#",
+            prefixes: &[],
+            check_positions: &[8],
+            fill: fill_mod11,
+        }],
+        Uf::Pr => &[Layout {
+            mask: "########-##",
+            prefixes: &[],
+            check_positions: &[8, 9],
+            fill: fill_pr,
+        }],
+        Uf::Pe => &[
+            Layout {
+                mask: "#######-##",
+                prefixes: &[],
+                check_positions: &[7, 8],
+                fill: fill_pe,
+            },
+            Layout {
+                mask: "##.#.###.#######-#",
+                prefixes: &[],
+                check_positions: &[13],
+                fill: fill_pe_legacy,
+            },
+        ],
+        Uf::Pi => &[Layout {
+            mask: "#########",
+            prefixes: &[],
+            check_positions: &[8],
+            fill: fill_mod11,
+        }],
+        Uf::Rj => &[Layout {
+            mask: "##.###.##-#",
+            prefixes: &[],
+            check_positions: &[7],
+            fill: fill_rj,
+        }],
+        Uf::Rn => &[
+            Layout {
+                mask: "##.###.###-#",
+                prefixes: &["20"],
+                check_positions: &[8],
+                fill: fill_al_rn,
+            },
+            Layout {
+                mask: "##.#.###.###-#",
+                prefixes: &["20"],
+                check_positions: &[9],
+                fill: fill_al_rn,
+            },
+        ],
+        Uf::Rs => &[Layout {
+            mask: "###/#######",
+            prefixes: &[],
+            check_positions: &[9],
+            fill: fill_rs,
+        }],
+        Uf::Ro => &[
+            Layout {
+                mask: "#############-#",
+                prefixes: &[],
+                check_positions: &[13],
+                fill: fill_ro,
+            },
+            Layout {
+                mask: "###.#####-#",
+                prefixes: &[],
+                check_positions: &[8],
+                fill: fill_ro_legacy,
+            },
+        ],
+        Uf::Rr => &[Layout {
+            mask: "########-#",
+            prefixes: &["24"],
+            check_positions: &[8],
+            fill: fill_rr,
+        }],
+        Uf::Sc => &[Layout {
+            mask: "###.###.###",
+            prefixes: &[],
+            check_positions: &[8],
+            fill: fill_mod11,
+        }],
+        Uf::Sp => &[
+            Layout {
+                mask: "###.###.###.###",
+                prefixes: &[],
+                check_positions: &[8, 11],
+                fill: fill_sp,
+            },
+            Layout {
+                mask: "P-########.#/###",
+                prefixes: &["0"],
+                check_positions: &[8],
+                fill: fill_sp_rural,
+            },
+        ],
+        Uf::Se => &[Layout {
+            mask: "########-#",
+            prefixes: &[],
+            check_positions: &[8],
+            fill: fill_mod11,
+        }],
+        Uf::To => &[
+            Layout {
+                mask: "########-#",
+                prefixes: &[],
+                check_positions: &[8],
+                fill: fill_mod11,
+            },
+            Layout {
+                mask: "##.##.######-#",
+                prefixes: &["##01", "##02", "##03", "##99"],
+                check_positions: &[10],
+                fill: fill_to_legacy,
+            },
+        ],
+    }
+}
+
+fn weighted_sum(
+    digits: &[u16],
+    weights: &[u16],
+) -> u16 {
+    digits.iter().zip(weights).map(|(d, w)| d * w).sum()
+}
+
+fn to_number(digits: &[u16]) -> u32 {
+    digits.iter().fold(0, |acc, &d| acc * 10 + d as u32)
+}
+
+fn mod11(sum: u16) -> u16 {
+    match sum % 11 {
+        0 | 1 => 0,
+        rest => 11 - rest,
+    }
+}
+
+fn fill_mod11(digits: &mut [u16]) {
+    digits[8] = mod11(weighted_sum(&digits[..8], &WEIGHTS_9_TO_2));
+}
+
+fn fill_ac_df(digits: &mut [u16]) {
+    let weights: [u16; 12] = [5, 4, 3, 2, 9, 8, 7, 6, 5, 4, 3, 2];
+
+    digits[11] = mod11(weighted_sum(&digits[..11], &weights[1..]));
+    digits[12] = mod11(weighted_sum(&digits[..12], &weights));
+}
+
+fn fill_al_rn(digits: &mut [u16]) {
+    let weights: [u16; 9] = [10, 9, 8, 7, 6, 5, 4, 3, 2];
+    let size: usize = digits.len() - 1;
+
+    let sum: u16 = weighted_sum(&digits[..size], &weights[9 - size..]);
+    digits[size] = sum * 10 % 11 % 10;
+}
+
+fn fill_ap(digits: &mut [u16]) {
+    let (p, d): (u16, u16) = match to_number(&digits[..8]) {
+        3_000_001..=3_017_000 => (5, 0),
+        3_017_001..=3_019_022 => (9, 1),
+        _ => (0, 0),
+    };
+
+    digits[8] =
+        match 11 - (p + weighted_sum(&digits[..8], &WEIGHTS_9_TO_2)) % 11 {
+            10 => 0,
+            11 => d,
+            dv => dv,
+        };
+}
+
+fn fill_ba(digits: &mut [u16]) {
+    let weights: [u16; 8] = WEIGHTS_9_TO_2;
+
+    let size: usize = digits.len() - 2;
+
+    // O módulo é definido pelo primeiro dígito (8 dígitos) ou pelo segundo
+    // dígito (9 dígitos) da inscrição.
+    let modulus: u16 = match digits[size - 6] {
+        6 | 7 | 9 => 11,
+        _ => 10,
+    };
+    let check_digit = |sum: u16| -> u16 {
+        match sum % modulus {
+            0 => 0,
+            1 if modulus == 11 => 0,
+            rest => modulus - rest,
+        }
+    };
+
+    // O segundo dígito verificador é calculado primeiro e compõe o cálculo
+    // do primeiro.
+    let base: Vec<u16> = digits[..size].to_vec();
+    digits[size + 1] = check_digit(weighted_sum(&base, &weights[8 - size..]));
+
+    let base: Vec<u16> = [&base[..], &[digits[size + 1]]].concat();
+    digits[size] = check_digit(weighted_sum(&base, &weights[7 - size..]));
+}
+
+fn fill_go(digits: &mut [u16]) {
+    let rest: u16 = weighted_sum(&digits[..8], &WEIGHTS_9_TO_2) % 11;
+
+    digits[8] = match rest {
+        0 => 0,
+        1 => match to_number(&digits[..8]) {
+            10_103_105..=10_119_997 => 1,
+            _ => 0,
+        },
+        _ => 11 - rest,
+    };
+}
+
+fn fill_mt(digits: &mut [u16]) {
+    let weights: [u16; 10] = [3, 2, 9, 8, 7, 6, 5, 4, 3, 2];
+
+    digits[10] = mod11(weighted_sum(&digits[..10], &weights));
+}
+
+fn fill_mg(digits: &mut [u16]) {
+    // Primeiro dígito: insere-se um 0 após o código do município e somam-se
+    // os algarismos dos produtos pelos pesos 1 e 2, alternadamente.
+    let base: Vec<u16> = [&digits[..3], &[0], &digits[3..11]].concat();
+    let sum: u16 = base
+        .iter()
+        .enumerate()
+        .map(|(i, d)| d * (1 + i as u16 % 2))
+        .map(|product| product / 10 + product % 10)
+        .sum();
+    digits[11] = (10 - sum % 10) % 10;
+
+    let weights: [u16; 12] = [3, 2, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2];
+    digits[12] = mod11(weighted_sum(&digits[..12], &weights));
+}
+
+fn fill_pr(digits: &mut [u16]) {
+    let weights: [u16; 9] = [4, 3, 2, 7, 6, 5, 4, 3, 2];
+
+    digits[8] = mod11(weighted_sum(&digits[..8], &weights[1..]));
+    digits[9] = mod11(weighted_sum(&digits[..9], &weights));
+}
+
+fn fill_pe(digits: &mut [u16]) {
+    digits[7] = mod11(weighted_sum(&digits[..7], &WEIGHTS_9_TO_2[1..]));
+    digits[8] = mod11(weighted_sum(&digits[..8], &WEIGHTS_9_TO_2));
+}
+
+fn fill_pe_legacy(digits: &mut [u16]) {
+    let weights: [u16; 13] = [5, 4, 3, 2, 1, 9, 8, 7, 6, 5, 4, 3, 2];
+
+    digits[13] = (11 - weighted_sum(&digits[..13], &weights) % 11) % 10;
+}
+
+fn fill_rj(digits: &mut [u16]) {
+    let weights: [u16; 7] = [2, 7, 6, 5, 4, 3, 2];
+
+    digits[7] = mod11(weighted_sum(&digits[..7], &weights));
+}
+
+fn fill_rs(digits: &mut [u16]) {
+    let weights: [u16; 9] = [2, 9, 8, 7, 6, 5, 4, 3, 2];
+
+    digits[9] = mod11(weighted_sum(&digits[..9], &weights));
+}
+
+fn fill_ro(digits: &mut [u16]) {
+    let weights: [u16; 13] = [6, 5, 4, 3, 2, 9, 8, 7, 6, 5, 4, 3, 2];
+
+    digits[13] = (11 - weighted_sum(&digits[..13], &weights) % 11) % 10;
+}
+
+fn fill_ro_legacy(digits: &mut [u16]) {
+    // Os 3 primeiros dígitos (código do município) não compõem o cálculo.
+    let weights: [u16; 5] = [6, 5, 4, 3, 2];
+
+    digits[8] = (11 - weighted_sum(&digits[3..8], &weights) % 11) % 10;
+}
+
+fn fill_rr(digits: &mut [u16]) {
+    let weights: [u16; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+
+    digits[8] = weighted_sum(&digits[..8], &weights) % 9;
+}
+
+fn fill_sp(digits: &mut [u16]) {
+    fill_sp_rural(digits);
+
+    let weights: [u16; 11] = [3, 2, 10, 9, 8, 7, 6, 5, 4, 3, 2];
+    digits[11] = weighted_sum(&digits[..11], &weights) % 11 % 10;
+}
+
+fn fill_sp_rural(digits: &mut [u16]) {
+    let weights: [u16; 8] = [1, 3, 4, 5, 6, 7, 8, 10];
+
+    digits[8] = weighted_sum(&digits[..8], &weights) % 11 % 10;
+}
+
+fn fill_to_legacy(digits: &mut [u16]) {
+    // Os dígitos 3 e 4 (tipo de empresa) não compõem o cálculo.
+    let base: Vec<u16> = [&digits[..2], &digits[4..10]].concat();
+
+    digits[10] = mod11(weighted_sum(&base, &WEIGHTS_9_TO_2));
+}
+
+fn find_layout(
+    uf: Uf,
+    doc: &str,
+) -> Result<(&'static Layout, Vec<u16>), ValidationError> {
+    let layouts: &'static [Layout] = layouts(uf);
+
+    if let Some(layout) = layouts.iter().find(|l| {
+        matches_pattern(&l.bare(), doc) || matches_pattern(l.mask, doc)
+    }) {
+        return Ok((layout, get_digits(doc, to_decimal)));
+    }
+
+    let found: usize = doc.chars().count();
+
+    if let Some(layout) = layouts.iter().find(|l| l.bare().len() == found) {
+        let (position, character): (usize, char) = layout
+            .bare()
+            .chars()
+            .zip(doc.chars())
+            .enumerate()
+            .find(|(_, (p, c))| !matches_char(*p, *c))
+            .map(|(i, (_, c))| (i, c))
+            .expect("A mismatched character must exist!");
+
+        return Err(ValidationError::InvalidCharacter {
+            position,
+            character,
+        });
+    }
+
+    let digits: Vec<u16> = get_digits(doc, to_decimal);
+
+    match layouts.iter().any(|l| l.size() == digits.len()) {
+        true => Err(ValidationError::InvalidMask),
+        false => Err(ValidationError::InvalidLength {
+            expected: layouts[0].size(),
+            found: digits.len(),
+        }),
+    }
+}
+
+/// Realiza validação de Inscrição Estadual da UF `uf`, máscarada ou não.
+/// Retorna `true` se o argumento `doc` for uma Inscrição Estadual válida,
+/// caso contrário, retorna `false`.
+///
+/// ## Exemplos
+///
+/// Inscrições Estaduais válidas:
+/// ```
+/// use brado::common::Uf;
+/// use brado::ie;
+///
+/// let result = ie::validate(Uf::Sp, "110042490114"); // true
+/// assert!(result);
+///
+/// let result = ie::validate(Uf::Sp, "110.042.490.114"); // true
+/// assert!(result);
+///
+/// let result = ie::validate(Uf::Sp, "P-01100424.3/002"); // true
+/// assert!(result);
+/// ```
+///
+/// Inscrições Estaduais inválidas:
+/// ```
+/// use brado::common::Uf;
+/// use brado::ie;
+///
+/// let result = ie::validate(Uf::Sp, "110042490115"); // false
+/// assert!(!result);
+///
+/// let result = ie::validate(Uf::Mg, "110.042.490.114"); // false
+/// assert!(!result);
+/// ```
+pub fn validate(
+    uf: Uf,
+    doc: &str,
+) -> bool {
+    check(uf, doc).is_ok()
+}
+
+/// Realiza validação de Inscrição Estadual da UF `uf`, máscarada ou não,
+/// informando o motivo da falha.
+/// Retorna `Ok(())` se o argumento `doc` for uma Inscrição Estadual válida,
+/// caso contrário, retorna o erro de validação (`ValidationError`)
+/// correspondente.
+///
+/// ## Exemplos
+///
+/// Inscrições Estaduais válidas:
+/// ```
+/// use brado::common::Uf;
+/// use brado::ie;
+///
+/// let result = ie::check(Uf::Sp, "110.042.490.114"); // Ok(())
+/// assert!(result.is_ok());
+///
+/// let result = ie::check(Uf::Ba, "123456-63"); // Ok(())
+/// assert!(result.is_ok());
+/// ```
+///
+/// Inscrições Estaduais inválidas:
+/// ```
+/// use brado::common::{Uf, ValidationError};
+/// use brado::ie;
+///
+/// let result = ie::check(Uf::Sp, "110042490115");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::CheckDigitMismatch {
+///         expected: String::from("04"),
+///         found: String::from("05"),
+///     }),
+/// );
+///
+/// let result = ie::check(Uf::Ac, "0200482300112");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::InvalidPrefix {
+///         found: String::from("02"),
+///     }),
+/// );
+///
+/// let result = ie::check(Uf::Sp, "11004249011");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::InvalidLength {
+///         expected: 12,
+///         found: 11,
+///     }),
+/// );
+/// ```
+pub fn check(
+    uf: Uf,
+    doc: &str,
+) -> Result<(), ValidationError> {
+    let (layout, digits): (&Layout, Vec<u16>) = find_layout(uf, doc)?;
+
+    layout.check_prefix(&digits)?;
+
+    layout.check_digits(&digits)
+}
+
+/// Verifica se o argumento `doc` pode ser uma Inscrição Estadual da UF `uf`
+/// sem símbolos.
+/// Se for, retorna `true`, caso contrário, retorna `false`.
+///
+/// ## Exemplos
+///
+/// Inscrições Estaduais válidas:
+/// ```
+/// use brado::common::Uf;
+/// use brado::ie;
+///
+/// let result = ie::is_bare(Uf::Sp, "110042490114"); // true
+/// assert!(result);
+///
+/// let result = ie::is_bare(Uf::Sp, "110.042.490.114"); // false
+/// assert!(!result);
+/// ```
+///
+/// Inscrições Estaduais inválidas:
+/// ```
+/// use brado::common::Uf;
+/// use brado::ie;
+///
+/// let result = ie::is_bare(Uf::Sp, "110042490115"); // true
+/// assert!(result);
+/// ```
+pub fn is_bare(
+    uf: Uf,
+    doc: &str,
+) -> bool {
+    layouts(uf)
+        .iter()
+        .any(|layout| matches_pattern(&layout.bare(), doc))
+}
+
+/// Verifica se o argumento `doc` pode ser uma Inscrição Estadual da UF `uf`
+/// com símbolos.
+/// Se for, retorna `true`, caso contrário, retorna `false`.
+///
+/// As UFs cuja Inscrição Estadual não possui máscara (AL, AP, MA, MS e PI)
+/// sempre retornam `false`.
+///
+/// ## Exemplos
+///
+/// Inscrições Estaduais válidas:
+/// ```
+/// use brado::common::Uf;
+/// use brado::ie;
+///
+/// let result = ie::is_masked(Uf::Sp, "110.042.490.114"); // true
+/// assert!(result);
+///
+/// let result = ie::is_masked(Uf::Sp, "110042490114"); // false
+/// assert!(!result);
+/// ```
+///
+/// Inscrições Estaduais inválidas:
+/// ```
+/// use brado::common::Uf;
+/// use brado::ie;
+///
+/// let result = ie::is_masked(Uf::Sp, "110.042.490.115"); // true
+/// assert!(result);
+/// ```
+pub fn is_masked(
+    uf: Uf,
+    doc: &str,
+) -> bool {
+    layouts(uf)
+        .iter()
+        .any(|layout| layout.has_symbols() && matches_pattern(layout.mask, doc))
+}
+
+/// Aplica máscara de Inscrição Estadual da UF `uf` no argumento `doc` e
+/// retorna resultado.
+/// O argumento deve ser uma string sem símbolos, caso contrário, deve lançar
+/// erro.
+///
+/// ## Exemplos
+///
+/// Documento de 12 dígitos sem máscara:
+/// ```
+/// use brado::common::Uf;
+/// use brado::ie;
+///
+/// let result = match ie::mask(Uf::Sp, "110042490114") { // Ok("110.042.490.114")
+///     Ok(doc) => doc,
+///     Err(e) => panic!("{}", e),
+/// };
+/// assert!(ie::is_masked(Uf::Sp, &result)); // true
+/// ```
+///
+/// Documento de 12 dígitos com máscara:
+/// ```should_panic
+/// use brado::common::Uf;
+/// use brado::ie;
+///
+/// let result = match ie::mask(Uf::Sp, "110.042.490.114") { // It panics!
+///     Ok(doc) => doc,
+///     Err(e) => panic!("{}", e),
+/// };
+/// ```
+pub fn mask(
+    uf: Uf,
+    doc: &str,
+) -> Result<String, MaskError> {
+    let kind: DocKind = DocKind::Ie(uf);
+    let layouts: &'static [Layout] = layouts(uf);
+
+    if let Some(layout) =
+        layouts.iter().find(|l| matches_pattern(&l.bare(), doc))
+    {
+        let mut chars = doc.chars();
+
+        return Ok(layout
+            .mask
+            .chars()
+            .map(|m| match m {
+                DIGIT | RURAL_PREFIX => chars.next().expect("Invalid IE!"),
+                _ => m,
+            })
+            .collect());
+    }
+
+    if is_masked(uf, doc) {
+        return Err(MaskError::AlreadyMasked { kind });
+    }
+
+    match find_layout(uf, doc) {
+        Err(ValidationError::InvalidCharacter {
+            position,
+            character,
+        }) => Err(MaskError::InvalidCharacter {
+            kind,
+            position,
+            character,
+        }),
+        _ => Err(MaskError::InvalidLength {
+            kind,
+            expected: layouts[0].bare().len(),
+            found: doc.chars().count(),
+        }),
+    }
+}
+
+/// Remove os símbolos do argumento `doc` e retorna a Inscrição Estadual sem
+/// máscara. O prefixo `P` da inscrição de produtor rural de SP é mantido.
+///
+/// ## Exemplos
+/// ```
+/// use brado::ie;
+///
+/// let result = ie::unmask("110.042.490.114"); // "110042490114"
+/// assert_eq!(result, "110042490114");
+///
+/// let result = ie::unmask("P-01100424.3/002"); // "P011004243002"
+/// assert_eq!(result, "P011004243002");
+/// ```
+pub fn unmask(doc: &str) -> String {
+    doc.chars()
+        .filter(|&c| c.is_ascii_digit() || c == RURAL_PREFIX)
+        .collect()
+}
+
+/// Gera e retorna uma Inscrição Estadual aleatória da UF `uf` sem máscara,
+/// no formato vigente da UF.
+///
+/// ## Exemplo
+/// ```
+/// use brado::common::Uf;
+/// use brado::ie;
+///
+/// let result = ie::generate(Uf::Mg); // "0623079040081"
+/// assert!(ie::is_bare(Uf::Mg, &result)); // true
+/// ```
+pub fn generate(uf: Uf) -> String {
+    generate_with_rng(uf, &mut rand::thread_rng())
+}
+
+/// Gera e retorna uma Inscrição Estadual aleatória da UF `uf` sem máscara,
+/// no formato vigente da UF, a partir do gerador de números aleatórios
+/// `rng`. Geradores com a mesma semente produzem a mesma Inscrição Estadual.
+///
+/// ## Exemplo
+/// ```
+/// use brado::common::Uf;
+/// use brado::ie;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let result = ie::generate_with_rng(Uf::Mg, &mut StdRng::seed_from_u64(42));
+/// assert!(ie::is_bare(Uf::Mg, &result)); // true
+///
+/// let other = ie::generate_with_rng(Uf::Mg, &mut StdRng::seed_from_u64(42));
+/// assert_eq!(result, other);
+/// ```
+pub fn generate_with_rng<R>(
+    uf: Uf,
+    rng: &mut R,
+) -> String
+where
+    R: Rng + ?Sized,
+{
+    let layout: &Layout = &layouts(uf)[0];
+
+    let mut digits: Vec<u16> =
+        random_decimal_vector_with_rng(layout.size(), rng);
+
+    if !layout.prefixes.is_empty() {
+        let prefix: &str =
+            random_element_from_vector_with_rng(layout.prefixes, rng);
+
+        for (i, p) in prefix.chars().enumerate() {
+            if let Some(d) = to_decimal(p) {
+                digits[i] = d;
+            }
+        }
+    }
+
+    (layout.fill)(&mut digits);
+
+    digits.iter().map(|d| d.to_string()).collect()
+}
+
+/// Gera e retorna uma Inscrição Estadual aleatória da UF `uf` com máscara.
+///
+/// ## Exemplo
+/// ```
+/// use brado::common::Uf;
+/// use brado::ie;
+///
+/// let result = ie::generate_masked(Uf::Mg); // "062.307.904/0081"
+/// assert!(ie::is_masked(Uf::Mg, &result)); // true
+/// ```
+pub fn generate_masked(uf: Uf) -> String {
+    generate_masked_with_rng(uf, &mut rand::thread_rng())
+}
+
+/// Gera e retorna uma Inscrição Estadual aleatória da UF `uf` com máscara a
+/// partir do gerador de números aleatórios `rng`. Geradores com a mesma
+/// semente produzem a mesma Inscrição Estadual.
+///
+/// ## Exemplo
+/// ```
+/// use brado::common::Uf;
+/// use brado::ie;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let result = ie::generate_masked_with_rng(Uf::Mg, &mut rng);
+/// assert!(ie::is_masked(Uf::Mg, &result)); // true
+/// ```
+pub fn generate_masked_with_rng<R>(
+    uf: Uf,
+    rng: &mut R,
+) -> String
+where
+    R: Rng + ?Sized,
+{
+    mask(uf, &generate_with_rng(uf, rng)).expect("Invalid IE!")
+}
+
+/// Inscrição Estadual validada, armazenada sem máscara junto da sua UF.
+///
+/// Só pode ser construída a partir de uma Inscrição Estadual válida,
+/// máscarada ou não, por meio de `Ie::new`. É exibida (`Display`) com
+/// máscara.
+///
+/// ## Exemplo
+/// ```
+/// use brado::common::Uf;
+/// use brado::ie::Ie;
+///
+/// let doc = Ie::new(Uf::Sp, "110.042.490.114").unwrap();
+/// assert_eq!(doc.uf(), Uf::Sp);
+/// assert_eq!(doc.as_bare(), "110042490114");
+/// assert_eq!(doc.to_masked(), "110.042.490.114");
+/// assert_eq!(doc.to_string(), "110.042.490.114");
+///
+/// let result = Ie::new(Uf::Mg, "110.042.490.114");
+/// assert!(result.is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ie {
+    uf: Uf,
+    doc: String,
+}
+
+impl Ie {
+    /// Valida a Inscrição Estadual `doc` da UF `uf`, máscarada ou não, e
+    /// retorna a Inscrição Estadual validada, ou o erro de validação
+    /// (`ValidationError`) correspondente.
+    pub fn new(
+        uf: Uf,
+        doc: &str,
+    ) -> Result<Ie, ValidationError> {
+        check(uf, doc)?;
+        Ok(Ie {
+            uf,
+            doc: unmask(doc),
+        })
+    }
+
+    /// Retorna a UF da Inscrição Estadual.
+    pub fn uf(&self) -> Uf {
+        self.uf
+    }
+
+    /// Retorna a Inscrição Estadual sem máscara.
+    pub fn as_bare(&self) -> &str {
+        &self.doc
+    }
+
+    /// Retorna a Inscrição Estadual com máscara.
+    pub fn to_masked(&self) -> String {
+        mask(self.uf, &self.doc).expect("Invalid IE!")
+    }
+}
+
+impl fmt::Display for Ie {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(f, "{}", self.to_masked())
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct IeFields {
+    uf: Uf,
+    ie: String,
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Ie {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        IeFields {
+            uf: self.uf,
+            ie: self.doc.clone(),
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Ie {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let fields: IeFields = IeFields::deserialize(deserializer)?;
+        Ie::new(fields.uf, &fields.ie).map_err(de::Error::custom)
+    }
+}
+
+const DOCUMENTS: [IeDocument; 27] = [
+    IeDocument(Uf::Ac),
+    IeDocument(Uf::Al),
+    IeDocument(Uf::Ap),
+    IeDocument(Uf::Am),
+    IeDocument(Uf::Ba),
+    IeDocument(Uf::Ce),
+    IeDocument(Uf::Df),
+    IeDocument(Uf::Es),
+    IeDocument(Uf::Go),
+    IeDocument(Uf::Ma),
+    IeDocument(Uf::Mt),
+    IeDocument(Uf::Ms),
+    IeDocument(Uf::Mg),
+    IeDocument(Uf::Pa),
+    IeDocument(Uf::Pb),
+    IeDocument(Uf::Pr),
+    IeDocument(Uf::Pe),
+    IeDocument(Uf::Pi),
+    IeDocument(Uf::Rj),
+    IeDocument(Uf::Rn),
+    IeDocument(Uf::Rs),
+    IeDocument(Uf::Ro),
+    IeDocument(Uf::Rr),
+    IeDocument(Uf::Sc),
+    IeDocument(Uf::Sp),
+    IeDocument(Uf::Se),
+    IeDocument(Uf::To),
+];
+
+/// Tipo marcador da Inscrição Estadual de uma UF.
+/// Implementa o trait `Document` a partir das funções deste módulo, para a
+/// UF informada. Diferente dos demais tipos marcadores, possui a UF como
+/// campo e não implementa `Default`.
+///
+/// ## Exemplo
+/// ```
+/// use brado::common::{Document, Uf};
+/// use brado::ie::IeDocument;
+///
+/// let result = IeDocument(Uf::Sp).validate("110.042.490.114"); // true
+/// assert!(result);
+///
+/// let result = IeDocument(Uf::Sp).bare_size(); // 12
+/// assert_eq!(result, 12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IeDocument(pub Uf);
+
+impl IeDocument {
+    pub(crate) fn for_uf(uf: Uf) -> &'static IeDocument {
+        DOCUMENTS
+            .iter()
+            .find(|document| document.0 == uf)
+            .expect("Every UF has an IE document!")
+    }
+}
+
+impl Document for IeDocument {
+    fn kind(&self) -> DocKind {
+        DocKind::Ie(self.0)
+    }
+
+    fn bare_size(&self) -> usize {
+        layouts(self.0)[0].bare().len()
+    }
+
+    fn check(
+        &self,
+        doc: &str,
+    ) -> Result<(), ValidationError> {
+        check(self.0, doc)
+    }
+
+    fn is_bare(
+        &self,
+        doc: &str,
+    ) -> bool {
+        is_bare(self.0, doc)
+    }
+
+    fn is_masked(
+        &self,
+        doc: &str,
+    ) -> bool {
+        is_masked(self.0, doc)
+    }
+
+    fn mask(
+        &self,
+        doc: &str,
+    ) -> Result<String, MaskError> {
+        mask(self.0, doc)
+    }
+
+    fn unmask(
+        &self,
+        doc: &str,
+    ) -> String {
+        unmask(doc)
+    }
+
+    fn generate(&self) -> String {
+        generate(self.0)
+    }
+
+    fn generate_masked(&self) -> String {
+        generate_masked(self.0)
+    }
+
+    fn generate_with_rng(
+        &self,
+        rng: &mut dyn RngCore,
+    ) -> String {
+        generate_with_rng(self.0, rng)
+    }
+
+    fn generate_masked_with_rng(
+        &self,
+        rng: &mut dyn RngCore,
+    ) -> String {
+        generate_masked_with_rng(self.0, rng)
+    }
+}
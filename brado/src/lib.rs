@@ -21,7 +21,9 @@
 //! let result = brado::cpf::validate("639.292.470-11");
 //! assert!(result);
 //! ```
+pub mod cep;
 pub mod certidao;
+pub mod chassi;
 pub mod cnh;
 pub mod cnpj;
 pub mod cns;
@@ -29,5 +31,10 @@ pub mod common;
 pub mod cpf;
 pub mod docs;
 pub mod eleitoral;
+pub mod ie;
+#[cfg(feature = "serde")]
+pub mod masked;
 pub mod nis;
+pub mod placa;
 pub mod renavam;
+pub mod telefone;
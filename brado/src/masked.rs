@@ -0,0 +1,51 @@
+//! Serialização de documentos com máscara.
+//!
+//! Por padrão, os tipos validados (`cpf::Cpf`, `cnpj::Cnpj`, ...) são
+//! serializados sem máscara. Este módulo permite serializá-los com máscara
+//! por meio do atributo `#[serde(with = "brado::masked")]`. A desserialização
+//! aceita o documento máscarado ou não e falha caso o documento seja inválido.
+//!
+//! ## Exemplo
+//!
+//! ```
+//! use brado::cpf::Cpf;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Pessoa {
+//!     #[serde(with = "brado::masked")]
+//!     cpf: Cpf,
+//! }
+//!
+//! let pessoa: Pessoa =
+//!     serde_json::from_str(r#"{"cpf":"63929247011"}"#).unwrap();
+//! let result = serde_json::to_string(&pessoa).unwrap();
+//! assert_eq!(result, r#"{"cpf":"639.292.470-11"}"#);
+//! ```
+
+use serde::{de, Deserialize, Deserializer, Serializer};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Serializa o documento `doc` com máscara.
+pub fn serialize<T, S>(
+    doc: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    T: Display,
+    S: Serializer,
+{
+    serializer.collect_str(doc)
+}
+
+/// Desserializa um documento, máscarado ou não, validando-o.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: FromStr,
+    T::Err: Display,
+    D: Deserializer<'de>,
+{
+    let doc: String = String::deserialize(deserializer)?;
+    doc.parse().map_err(de::Error::custom)
+}
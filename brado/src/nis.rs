@@ -6,8 +6,16 @@
 //! PASEP: Programa de Formação do Patrimônio do Servidor Público.
 
 use crate::common::{
-    get_digits, get_symbols, is_repeated, random_decimal_vector, to_decimal,
+    check_digits, check_format, check_mask, get_digits, get_symbols,
+    is_repeated, random_decimal_vector_with_rng, to_decimal, Document,
+    MaskError, ValidationError,
 };
+use crate::docs::DocKind;
+use rand::{Rng, RngCore};
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
 
 const NIS_SIZE: usize = 11;
 
@@ -39,21 +47,101 @@ const NIS_SIZE: usize = 11;
 /// assert!(!result);
 /// ```
 pub fn validate(doc: &str) -> bool {
-    let size: usize = doc.chars().count();
-
-    if size != NIS_SIZE && !is_masked(doc) {
-        return false;
-    }
+    check(doc).is_ok()
+}
 
-    let digits: Vec<u16> = get_digits(doc, to_decimal);
+/// Realiza validação de NIS/NIT/PIS/PASEP, máscarado ou não, informando o
+/// motivo da falha.
+/// Retorna `Ok(())` se o argumento `doc` for um NIS/NIT/PIS/PASEP válido,
+/// caso contrário, retorna o erro de validação (`ValidationError`)
+/// correspondente.
+///
+/// ## Exemplos
+///
+/// Números NIS/NIT/PIS/PASEP válidos:
+/// ```
+/// use brado::nis;
+///
+/// let result = nis::check("40865658047"); // Ok(())
+/// assert!(result.is_ok());
+///
+/// let result = nis::check("408.65658.04-7"); // Ok(())
+/// assert!(result.is_ok());
+/// ```
+///
+/// Números NIS/NIT/PIS/PASEP inválidos:
+/// ```
+/// use brado::common::ValidationError;
+/// use brado::nis;
+///
+/// let result = nis::check("40865658046");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::CheckDigitMismatch {
+///         expected: String::from("7"),
+///         found: String::from("6"),
+///     }),
+/// );
+///
+/// let result = nis::check("408.65658.04:7");
+/// assert_eq!(result, Err(ValidationError::InvalidMask));
+/// ```
+pub fn check(doc: &str) -> Result<(), ValidationError> {
+    let digits: Vec<u16> = check_format(doc, NIS_SIZE, is_masked, to_decimal)?;
 
-    if digits.len() != NIS_SIZE || is_repeated(&digits) {
-        return false;
+    if is_repeated(&digits) {
+        return Err(ValidationError::RepeatedDigits);
     }
 
     let d11: u16 = generate_digit(&digits[..10]);
 
-    d11 == digits[10]
+    check_digits(&[d11], &digits[10..])
+}
+
+/// Calcula o dígito verificador de um NIS/NIT/PIS/PASEP a partir dos 10
+/// primeiros dígitos (`base`), sem símbolos.
+/// Retorna o dígito verificador, ou o erro correspondente
+/// (`ValidationError`) caso a base seja inválida.
+///
+/// ## Exemplos
+///
+/// Bases válidas:
+/// ```
+/// use brado::nis;
+///
+/// let base = "4086565804";
+/// let result = nis::compute_check_digits(base); // Ok("7")
+/// assert_eq!(result, Ok(String::from("7")));
+///
+/// let doc = format!("{}{}", base, result.unwrap()); // "40865658047"
+/// assert!(nis::validate(&doc));
+/// ```
+///
+/// Bases inválidas:
+/// ```
+/// use brado::common::ValidationError;
+/// use brado::nis;
+///
+/// let result = nis::compute_check_digits("408656580A");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::InvalidCharacter {
+///         position: 9,
+///         character: 'A',
+///     }),
+/// );
+/// ```
+pub fn compute_check_digits(base: &str) -> Result<String, ValidationError> {
+    let digits: Vec<u16> =
+        check_format(base, NIS_SIZE - 1, |_| false, to_decimal)?;
+
+    let d11: u16 = generate_digit(&digits);
+
+    if is_repeated(&[&digits[..], &[d11]].concat()) {
+        return Err(ValidationError::RepeatedDigits);
+    }
+
+    Ok(d11.to_string())
 }
 
 fn generate_digit(doc_slice: &[u16]) -> u16 {
@@ -161,10 +249,8 @@ pub fn is_masked(doc: &str) -> bool {
 ///     Err(e) => panic!("{}", e),
 /// };
 /// ```
-pub fn mask(doc: &str) -> Result<String, &'static str> {
-    if !is_bare(doc) {
-        return Err("The given string cannot be masked as NIS/NIT/PIS/PASEP!");
-    }
+pub fn mask(doc: &str) -> Result<String, MaskError> {
+    check_mask(doc, DocKind::Nis, NIS_SIZE, is_masked, to_decimal)?;
 
     let masked_doc: String = format!(
         "{}.{}.{}-{}",
@@ -177,6 +263,20 @@ pub fn mask(doc: &str) -> Result<String, &'static str> {
     Ok(masked_doc)
 }
 
+/// Remove os símbolos do argumento `doc` e retorna o NIS/NIT/PIS/PASEP sem
+/// máscara.
+///
+/// ## Exemplo
+/// ```
+/// use brado::nis;
+///
+/// let result = nis::unmask("408.65658.04-7"); // "40865658047"
+/// assert_eq!(result, "40865658047");
+/// ```
+pub fn unmask(doc: &str) -> String {
+    crate::common::unmask(doc, to_decimal)
+}
+
 /// Gera e retorna um NIS/NIT/PIS/PASEP aleatório sem máscara.
 ///
 /// ## Exemplo
@@ -187,7 +287,30 @@ pub fn mask(doc: &str) -> Result<String, &'static str> {
 /// assert!(nis::is_bare(&result)); // true
 /// ```
 pub fn generate() -> String {
-    let mut nis: Vec<u16> = random_decimal_vector(10);
+    generate_with_rng(&mut rand::thread_rng())
+}
+
+/// Gera e retorna um NIS/NIT/PIS/PASEP aleatório sem máscara a partir do
+/// gerador de números aleatórios `rng`. Geradores com a mesma semente
+/// produzem o mesmo NIS/NIT/PIS/PASEP.
+///
+/// ## Exemplo
+/// ```
+/// use brado::nis;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let result = nis::generate_with_rng(&mut StdRng::seed_from_u64(42));
+/// assert!(nis::is_bare(&result)); // true
+///
+/// let other = nis::generate_with_rng(&mut StdRng::seed_from_u64(42));
+/// assert_eq!(result, other);
+/// ```
+pub fn generate_with_rng<R>(rng: &mut R) -> String
+where
+    R: Rng + ?Sized,
+{
+    let mut nis: Vec<u16> = random_decimal_vector_with_rng(10, rng);
     nis.push(generate_digit(&nis));
 
     nis.iter()
@@ -206,5 +329,193 @@ pub fn generate() -> String {
 /// assert!(nis::is_masked(&result)); // true
 /// ```
 pub fn generate_masked() -> String {
-    mask(&generate()).expect("Invalid NIS/NIT/PIS/PASEP!")
+    generate_masked_with_rng(&mut rand::thread_rng())
+}
+
+/// Gera e retorna um NIS/NIT/PIS/PASEP aleatório com máscara a partir do
+/// gerador de números aleatórios `rng`. Geradores com a mesma semente
+/// produzem o mesmo NIS/NIT/PIS/PASEP.
+///
+/// ## Exemplo
+/// ```
+/// use brado::nis;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let result = nis::generate_masked_with_rng(&mut rng);
+/// assert!(nis::is_masked(&result)); // true
+/// ```
+pub fn generate_masked_with_rng<R>(rng: &mut R) -> String
+where
+    R: Rng + ?Sized,
+{
+    mask(&generate_with_rng(rng)).expect("Invalid NIS/NIT/PIS/PASEP!")
+}
+
+/// NIS/NIT/PIS/PASEP validado, armazenado sem máscara.
+///
+/// Só pode ser construído a partir de um NIS/NIT/PIS/PASEP válido, máscarado
+/// ou não, por meio de `FromStr` ou `TryFrom<&str>`. É exibido (`Display`)
+/// com máscara.
+///
+/// ## Exemplo
+/// ```
+/// use brado::nis::Nis;
+///
+/// let doc: Nis = "408.65658.04-7".parse().unwrap();
+/// assert_eq!(doc.as_bare(), "40865658047");
+/// assert_eq!(doc.to_masked(), "408.65658.04-7");
+/// assert_eq!(doc.to_string(), "408.65658.04-7");
+///
+/// let result = "408.65658.04-6".parse::<Nis>();
+/// assert!(result.is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Nis(String);
+
+impl Nis {
+    /// Retorna o NIS/NIT/PIS/PASEP sem máscara.
+    pub fn as_bare(&self) -> &str {
+        &self.0
+    }
+
+    /// Retorna o NIS/NIT/PIS/PASEP com máscara.
+    pub fn to_masked(&self) -> String {
+        mask(&self.0).expect("Invalid NIS/NIT/PIS/PASEP!")
+    }
+}
+
+impl FromStr for Nis {
+    type Err = ValidationError;
+
+    fn from_str(doc: &str) -> Result<Self, Self::Err> {
+        check(doc)?;
+        Ok(Nis(unmask(doc)))
+    }
+}
+
+impl TryFrom<&str> for Nis {
+    type Error = ValidationError;
+
+    fn try_from(doc: &str) -> Result<Self, Self::Error> {
+        doc.parse()
+    }
+}
+
+impl fmt::Display for Nis {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(f, "{}", self.to_masked())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Nis {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_bare())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Nis {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let doc: String = String::deserialize(deserializer)?;
+        doc.parse().map_err(de::Error::custom)
+    }
+}
+
+/// Tipo marcador do NIS/NIT/PIS/PASEP.
+/// Implementa o trait `Document` a partir das funções deste módulo.
+///
+/// ## Exemplo
+/// ```
+/// use brado::common::Document;
+/// use brado::nis::NisDocument;
+///
+/// let result = NisDocument.validate("408.65658.04-7"); // true
+/// assert!(result);
+///
+/// let result = NisDocument.bare_size(); // 11
+/// assert_eq!(result, 11);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct NisDocument;
+
+impl Document for NisDocument {
+    fn kind(&self) -> DocKind {
+        DocKind::Nis
+    }
+
+    fn bare_size(&self) -> usize {
+        NIS_SIZE
+    }
+
+    fn check(
+        &self,
+        doc: &str,
+    ) -> Result<(), ValidationError> {
+        check(doc)
+    }
+
+    fn is_bare(
+        &self,
+        doc: &str,
+    ) -> bool {
+        is_bare(doc)
+    }
+
+    fn is_masked(
+        &self,
+        doc: &str,
+    ) -> bool {
+        is_masked(doc)
+    }
+
+    fn mask(
+        &self,
+        doc: &str,
+    ) -> Result<String, MaskError> {
+        mask(doc)
+    }
+
+    fn unmask(
+        &self,
+        doc: &str,
+    ) -> String {
+        unmask(doc)
+    }
+
+    fn generate(&self) -> String {
+        generate()
+    }
+
+    fn generate_masked(&self) -> String {
+        generate_masked()
+    }
+
+    fn generate_with_rng(
+        &self,
+        rng: &mut dyn RngCore,
+    ) -> String {
+        generate_with_rng(rng)
+    }
+
+    fn generate_masked_with_rng(
+        &self,
+        rng: &mut dyn RngCore,
+    ) -> String {
+        generate_masked_with_rng(rng)
+    }
 }
@@ -0,0 +1,737 @@
+//! Utilitários para validação de placas de veículos, nos formatos antigo
+//! (`AAA-9999`) e Mercosul (`AAA9A99`). Somente as placas no formato antigo
+//! possuem máscara; as placas Mercosul são escritas sem hífen.
+
+use crate::common::{
+    check_format, check_mask, get_digits, get_symbols,
+    random_string_from_alphabet_with_rng, Document, MaskError, ValidationError,
+};
+use crate::docs::DocKind;
+use rand::{Rng, RngCore};
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+const PLACA_SIZE: usize = 7;
+const MERCOSUL_POSITION: usize = 4;
+
+fn to_placa_char(c: char) -> Option<u16> {
+    match c {
+        '0'..='9' => Some(c as u16 - '0' as u16),
+        'A'..='Z' => Some(c as u16 - 'A' as u16 + 10),
+        _ => None,
+    }
+}
+
+/// Realiza validação de placa nos formatos antigo, máscarada ou não, e
+/// Mercosul, sem máscara.
+/// Retorna `true` se o argumento `doc` for uma placa válida, caso contrário,
+/// retorna `false`.
+///
+/// ## Exemplos
+///
+/// Placas válidas:
+/// ```
+/// use brado::placa;
+///
+/// let result = placa::validate("ABC1234"); // true
+/// assert!(result);
+///
+/// let result = placa::validate("ABC-1234"); // true
+/// assert!(result);
+///
+/// let result = placa::validate("ABC1C34"); // true
+/// assert!(result);
+/// ```
+///
+/// Placas inválidas:
+/// ```
+/// use brado::placa;
+///
+/// let result = placa::validate("AB12345"); // false
+/// assert!(!result);
+///
+/// let result = placa::validate("abc1234"); // false
+/// assert!(!result);
+///
+/// let result = placa::validate("ABC-1C34"); // false
+/// assert!(!result);
+/// ```
+pub fn validate(doc: &str) -> bool {
+    check(doc).is_ok()
+}
+
+/// Realiza validação de placa nos formatos antigo, máscarada ou não, e
+/// Mercosul, sem máscara, informando o motivo da falha.
+/// Retorna `Ok(())` se o argumento `doc` for uma placa válida, caso
+/// contrário, retorna o erro de validação (`ValidationError`) correspondente.
+///
+/// As três primeiras posições devem ser letras, a quarta, a sexta e a sétima
+/// devem ser dígitos e a quinta pode ser um dígito (formato antigo) ou uma
+/// letra (formato Mercosul).
+///
+/// ## Exemplos
+///
+/// Placas válidas:
+/// ```
+/// use brado::placa;
+///
+/// let result = placa::check("ABC-1234"); // Ok(())
+/// assert!(result.is_ok());
+///
+/// let result = placa::check("ABC1C34"); // Ok(())
+/// assert!(result.is_ok());
+/// ```
+///
+/// Placas inválidas:
+/// ```
+/// use brado::common::ValidationError;
+/// use brado::placa;
+///
+/// let result = placa::check("AB12345");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::InvalidCharacter {
+///         position: 2,
+///         character: '1',
+///     }),
+/// );
+///
+/// let result = placa::check("ABC12345");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::InvalidLength {
+///         expected: 7,
+///         found: 8,
+///     }),
+/// );
+///
+/// let result = placa::check("ABC-1C34");
+/// assert_eq!(result, Err(ValidationError::InvalidMask));
+/// ```
+pub fn check(doc: &str) -> Result<(), ValidationError> {
+    check_format(doc, PLACA_SIZE, is_masked, to_placa_char)?;
+
+    match unmask(doc).chars().enumerate().find(|&(i, c)| match i {
+        0..=2 => !c.is_ascii_uppercase(),
+        MERCOSUL_POSITION => false,
+        _ => !c.is_ascii_digit(),
+    }) {
+        Some((position, character)) => Err(ValidationError::InvalidCharacter {
+            position,
+            character,
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Verifica se o argumento `doc` pode ser uma placa sem símbolos.
+/// Se for, retorna `true`, caso contrário, retorna `false`.
+///
+/// ## Exemplos
+///
+/// Placas válidas:
+/// ```
+/// use brado::placa;
+///
+/// let result = placa::is_bare("ABC1234"); // true
+/// assert!(result);
+///
+/// let result = placa::is_bare("ABC-1234"); // false
+/// assert!(!result);
+/// ```
+///
+/// Placas inválidas:
+/// ```
+/// use brado::placa;
+///
+/// let result = placa::is_bare("1234ABC"); // true
+/// assert!(result);
+/// ```
+pub fn is_bare(doc: &str) -> bool {
+    doc.chars().count() == PLACA_SIZE
+        && get_digits(doc, to_placa_char).len() == PLACA_SIZE
+}
+
+/// Verifica se o argumento `doc` pode ser uma placa com símbolos.
+/// Se for, retorna `true`, caso contrário, retorna `false`. Somente placas no
+/// formato antigo (com um dígito na quinta posição) possuem máscara.
+///
+/// ## Exemplos
+///
+/// Placas válidas:
+/// ```
+/// use brado::placa;
+///
+/// let result = placa::is_masked("ABC-1234"); // true
+/// assert!(result);
+///
+/// let result = placa::is_masked("ABC-1C34"); // false
+/// assert!(!result);
+///
+/// let result = placa::is_masked("ABC1234"); // false
+/// assert!(!result);
+/// ```
+///
+/// Placas inválidas:
+/// ```
+/// use brado::placa;
+///
+/// let result = placa::is_masked("123-45BC"); // true
+/// assert!(result);
+/// ```
+pub fn is_masked(doc: &str) -> bool {
+    let symbols: Vec<(usize, char)> = get_symbols(doc, to_placa_char);
+    let digits: Vec<u16> = get_digits(doc, to_placa_char);
+
+    if symbols.len() != 1 || digits.len() != PLACA_SIZE {
+        return false;
+    }
+
+    symbols[0] == (3, '-')
+        && matches!(
+            unmask(doc).chars().nth(MERCOSUL_POSITION),
+            Some(c) if c.is_ascii_digit()
+        )
+}
+
+/// Aplica máscara de placa no argumento `doc` e retorna resultado.
+/// O argumento deve ser uma string sem símbolos, caso contrário, deve lançar
+/// erro. Placas no formato Mercosul não possuem máscara e são retornadas sem
+/// alteração.
+///
+/// ## Exemplos
+///
+/// Documento de 7 caracteres sem máscara:
+/// ```
+/// use brado::placa;
+///
+/// let result = match placa::mask("ABC1234") { // Ok("ABC-1234")
+///     Ok(doc) => doc,
+///     Err(e) => panic!("{}", e),
+/// };
+/// assert!(placa::is_masked(&result)); // true
+///
+/// let result = placa::mask("ABC1C34"); // Ok("ABC1C34")
+/// assert_eq!(result, Ok(String::from("ABC1C34")));
+/// ```
+///
+/// Documento de 7 caracteres com máscara:
+/// ```should_panic
+/// use brado::placa;
+///
+/// let result = match placa::mask("ABC-1234") { // It panics!
+///     Ok(doc) => doc,
+///     Err(e) => panic!("{}", e),
+/// };
+/// ```
+pub fn mask(doc: &str) -> Result<String, MaskError> {
+    check_mask(doc, DocKind::Placa, PLACA_SIZE, is_masked, to_placa_char)?;
+
+    let masked_doc: String = match doc.chars().nth(MERCOSUL_POSITION) {
+        Some(c) if c.is_ascii_digit() => {
+            format!("{}-{}", &doc[0..3], &doc[3..7])
+        }
+        _ => doc.to_string(),
+    };
+
+    Ok(masked_doc)
+}
+
+/// Remove os símbolos do argumento `doc` e retorna a placa sem máscara.
+///
+/// ## Exemplo
+/// ```
+/// use brado::placa;
+///
+/// let result = placa::unmask("ABC-1234"); // "ABC1234"
+/// assert_eq!(result, "ABC1234");
+/// ```
+pub fn unmask(doc: &str) -> String {
+    doc.chars()
+        .filter(|c| to_placa_char(*c).is_some())
+        .collect()
+}
+
+fn letters() -> Vec<char> {
+    ('A'..='Z').collect()
+}
+
+fn numbers() -> Vec<char> {
+    ('0'..='9').collect()
+}
+
+/// Gera e retorna uma placa aleatória sem máscara, no formato antigo ou
+/// Mercosul.
+///
+/// ## Exemplo
+/// ```
+/// use brado::placa;
+///
+/// let result = placa::generate(); // "ABC1C34"
+/// assert!(placa::is_bare(&result)); // true
+/// ```
+pub fn generate() -> String {
+    generate_with_rng(&mut rand::thread_rng())
+}
+
+/// Gera e retorna uma placa aleatória sem máscara, no formato antigo ou
+/// Mercosul, a partir do gerador de números aleatórios `rng`. Geradores com
+/// a mesma semente produzem a mesma placa.
+///
+/// ## Exemplo
+/// ```
+/// use brado::placa;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let result = placa::generate_with_rng(&mut StdRng::seed_from_u64(42));
+/// assert!(placa::is_bare(&result)); // true
+///
+/// let other = placa::generate_with_rng(&mut StdRng::seed_from_u64(42));
+/// assert_eq!(result, other);
+/// ```
+pub fn generate_with_rng<R>(rng: &mut R) -> String
+where
+    R: Rng + ?Sized,
+{
+    match rng.gen_bool(0.5) {
+        true => generate_legacy_with_rng(rng),
+        false => generate_mercosul_with_rng(rng),
+    }
+}
+
+/// Gera e retorna uma placa aleatória com máscara. Placas geradas no formato
+/// Mercosul não possuem máscara (ver `mask`).
+///
+/// ## Exemplo
+/// ```
+/// use brado::placa;
+///
+/// let result = placa::generate_masked(); // "ABC-1234"
+/// assert!(placa::validate(&result)); // true
+/// ```
+pub fn generate_masked() -> String {
+    generate_masked_with_rng(&mut rand::thread_rng())
+}
+
+/// Gera e retorna uma placa aleatória com máscara a partir do gerador de
+/// números aleatórios `rng`. Geradores com a mesma semente produzem a mesma
+/// placa. Placas geradas no formato Mercosul não possuem máscara (ver
+/// `mask`).
+///
+/// ## Exemplo
+/// ```
+/// use brado::placa;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let result = placa::generate_masked_with_rng(&mut rng);
+/// assert!(placa::validate(&result)); // true
+/// ```
+pub fn generate_masked_with_rng<R>(rng: &mut R) -> String
+where
+    R: Rng + ?Sized,
+{
+    mask(&generate_with_rng(rng)).expect("Invalid placa!")
+}
+
+/// Formatos de placa: antigo (`AAA9999`) ou Mercosul (`AAA9A99`).
+///
+/// ## Exemplo
+/// ```
+/// use brado::placa::{self, PlacaFormat};
+///
+/// let result = placa::detect_format("ABC-1234");
+/// assert_eq!(result, Some(PlacaFormat::Legacy));
+///
+/// let result = placa::detect_format("ABC1C34");
+/// assert_eq!(result, Some(PlacaFormat::Mercosul));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PlacaFormat {
+    /// Placa no formato antigo, com três letras e quatro dígitos.
+    Legacy,
+    /// Placa no formato Mercosul, com uma letra na quinta posição.
+    Mercosul,
+}
+
+impl PlacaFormat {
+    /// Todos os formatos de placa.
+    ///
+    /// ## Exemplo
+    /// ```
+    /// use brado::placa::PlacaFormat;
+    ///
+    /// let result = PlacaFormat::ALL.len(); // 2
+    /// assert_eq!(result, 2);
+    /// ```
+    pub const ALL: &'static [PlacaFormat] =
+        &[PlacaFormat::Legacy, PlacaFormat::Mercosul];
+}
+
+/// Identifica o formato (`PlacaFormat`) de uma placa válida, máscarada ou
+/// não. Retorna `None` caso o argumento `doc` não seja uma placa válida.
+///
+/// ## Exemplos
+/// ```
+/// use brado::placa::{self, PlacaFormat};
+///
+/// let result = placa::detect_format("ABC1234");
+/// assert_eq!(result, Some(PlacaFormat::Legacy));
+///
+/// let result = placa::detect_format("ABC1C34");
+/// assert_eq!(result, Some(PlacaFormat::Mercosul));
+///
+/// let result = placa::detect_format("AB12345");
+/// assert_eq!(result, None);
+/// ```
+pub fn detect_format(doc: &str) -> Option<PlacaFormat> {
+    check(doc).ok()?;
+
+    match unmask(doc).chars().nth(MERCOSUL_POSITION)?.is_ascii_digit() {
+        true => Some(PlacaFormat::Legacy),
+        false => Some(PlacaFormat::Mercosul),
+    }
+}
+
+/// Converte uma placa válida, máscarada ou não, para o formato Mercosul e
+/// retorna a placa sem máscara. O dígito da quinta posição é substituído
+/// pela letra correspondente (0 = A, 1 = B, ..., 9 = J). Placas que já estão
+/// no formato Mercosul são retornadas sem máscara.
+///
+/// Retorna o erro de validação (`ValidationError`) correspondente caso a
+/// placa seja inválida.
+///
+/// ## Exemplos
+/// ```
+/// use brado::placa;
+///
+/// let result = placa::to_mercosul("ABC-1234"); // Ok("ABC1C34")
+/// assert_eq!(result, Ok(String::from("ABC1C34")));
+///
+/// let result = placa::to_mercosul("ABC1C34"); // Ok("ABC1C34")
+/// assert_eq!(result, Ok(String::from("ABC1C34")));
+///
+/// let result = placa::to_mercosul("AB12345");
+/// assert!(result.is_err());
+/// ```
+pub fn to_mercosul(doc: &str) -> Result<String, ValidationError> {
+    check(doc)?;
+
+    Ok(unmask(doc)
+        .chars()
+        .enumerate()
+        .map(|(i, c)| match (i, c.to_digit(10)) {
+            (MERCOSUL_POSITION, Some(d)) => letters()[d as usize],
+            _ => c,
+        })
+        .collect())
+}
+
+/// Converte uma placa válida, máscarada ou não, para o formato antigo e
+/// retorna a placa sem máscara. A letra da quinta posição é substituída pelo
+/// dígito correspondente (A = 0, B = 1, ..., J = 9). Placas que já estão no
+/// formato antigo são retornadas sem máscara.
+///
+/// Retorna o erro de validação (`ValidationError`) correspondente caso a
+/// placa seja inválida ou a letra da quinta posição não possua dígito
+/// correspondente (K a Z).
+///
+/// ## Exemplos
+/// ```
+/// use brado::common::ValidationError;
+/// use brado::placa;
+///
+/// let result = placa::to_legacy("ABC1C34"); // Ok("ABC1234")
+/// assert_eq!(result, Ok(String::from("ABC1234")));
+///
+/// let result = placa::to_legacy("ABC1K34");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::InvalidCharacter {
+///         position: 4,
+///         character: 'K',
+///     }),
+/// );
+/// ```
+pub fn to_legacy(doc: &str) -> Result<String, ValidationError> {
+    check(doc)?;
+
+    unmask(doc)
+        .chars()
+        .enumerate()
+        .map(|(i, c)| match i {
+            MERCOSUL_POSITION if c.is_ascii_uppercase() => letters()
+                .iter()
+                .position(|&l| l == c)
+                .and_then(|d| numbers().get(d).copied())
+                .ok_or(ValidationError::InvalidCharacter {
+                    position: i,
+                    character: c,
+                }),
+            _ => Ok(c),
+        })
+        .collect()
+}
+
+/// Gera e retorna uma placa aleatória sem máscara no formato antigo
+/// (`AAA9999`).
+///
+/// ## Exemplo
+/// ```
+/// use brado::placa::{self, PlacaFormat};
+///
+/// let result = placa::generate_legacy(); // "ABC1234"
+/// assert_eq!(placa::detect_format(&result), Some(PlacaFormat::Legacy));
+/// ```
+pub fn generate_legacy() -> String {
+    generate_legacy_with_rng(&mut rand::thread_rng())
+}
+
+/// Gera e retorna uma placa aleatória sem máscara no formato antigo
+/// (`AAA9999`), a partir do gerador de números aleatórios `rng`.
+///
+/// ## Exemplo
+/// ```
+/// use brado::placa::{self, PlacaFormat};
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let result = placa::generate_legacy_with_rng(&mut rng);
+/// assert_eq!(placa::detect_format(&result), Some(PlacaFormat::Legacy));
+/// ```
+pub fn generate_legacy_with_rng<R>(rng: &mut R) -> String
+where
+    R: Rng + ?Sized,
+{
+    [
+        random_string_from_alphabet_with_rng(3, &letters(), rng),
+        random_string_from_alphabet_with_rng(4, &numbers(), rng),
+    ]
+    .concat()
+}
+
+/// Gera e retorna uma placa aleatória sem máscara no formato Mercosul
+/// (`AAA9A99`).
+///
+/// ## Exemplo
+/// ```
+/// use brado::placa::{self, PlacaFormat};
+///
+/// let result = placa::generate_mercosul(); // "ABC1C34"
+/// assert_eq!(placa::detect_format(&result), Some(PlacaFormat::Mercosul));
+/// ```
+pub fn generate_mercosul() -> String {
+    generate_mercosul_with_rng(&mut rand::thread_rng())
+}
+
+/// Gera e retorna uma placa aleatória sem máscara no formato Mercosul
+/// (`AAA9A99`), a partir do gerador de números aleatórios `rng`.
+///
+/// ## Exemplo
+/// ```
+/// use brado::placa::{self, PlacaFormat};
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let result = placa::generate_mercosul_with_rng(&mut rng);
+/// assert_eq!(placa::detect_format(&result), Some(PlacaFormat::Mercosul));
+/// ```
+pub fn generate_mercosul_with_rng<R>(rng: &mut R) -> String
+where
+    R: Rng + ?Sized,
+{
+    [
+        random_string_from_alphabet_with_rng(3, &letters(), rng),
+        random_string_from_alphabet_with_rng(1, &numbers(), rng),
+        random_string_from_alphabet_with_rng(1, &letters(), rng),
+        random_string_from_alphabet_with_rng(2, &numbers(), rng),
+    ]
+    .concat()
+}
+
+/// Placa validada, armazenada sem máscara.
+///
+/// Só pode ser construída a partir de uma placa válida por meio de `FromStr`
+/// ou `TryFrom<&str>`. É exibida (`Display`) com máscara no formato antigo e
+/// sem máscara no formato Mercosul.
+///
+/// ## Exemplo
+/// ```
+/// use brado::placa::{Placa, PlacaFormat};
+///
+/// let doc: Placa = "ABC-1234".parse().unwrap();
+/// assert_eq!(doc.as_bare(), "ABC1234");
+/// assert_eq!(doc.to_masked(), "ABC-1234");
+/// assert_eq!(doc.to_string(), "ABC-1234");
+/// assert_eq!(doc.format(), PlacaFormat::Legacy);
+/// assert_eq!(doc.to_mercosul().as_bare(), "ABC1C34");
+/// assert_eq!(doc.to_mercosul().to_string(), "ABC1C34");
+///
+/// let result = "AB12345".parse::<Placa>();
+/// assert!(result.is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Placa(String);
+
+impl Placa {
+    /// Retorna a placa sem máscara.
+    pub fn as_bare(&self) -> &str {
+        &self.0
+    }
+
+    /// Retorna a placa com máscara (sem máscara no formato Mercosul).
+    pub fn to_masked(&self) -> String {
+        mask(&self.0).expect("Invalid placa!")
+    }
+
+    /// Retorna o formato da placa (antigo ou Mercosul).
+    pub fn format(&self) -> PlacaFormat {
+        detect_format(&self.0).expect("Invalid placa!")
+    }
+
+    /// Retorna a placa convertida para o formato Mercosul.
+    pub fn to_mercosul(&self) -> Placa {
+        Placa(to_mercosul(&self.0).expect("Invalid placa!"))
+    }
+}
+
+impl FromStr for Placa {
+    type Err = ValidationError;
+
+    fn from_str(doc: &str) -> Result<Self, Self::Err> {
+        check(doc)?;
+        Ok(Placa(unmask(doc)))
+    }
+}
+
+impl TryFrom<&str> for Placa {
+    type Error = ValidationError;
+
+    fn try_from(doc: &str) -> Result<Self, Self::Error> {
+        doc.parse()
+    }
+}
+
+impl fmt::Display for Placa {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(f, "{}", self.to_masked())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Placa {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_bare())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Placa {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let doc: String = String::deserialize(deserializer)?;
+        doc.parse().map_err(de::Error::custom)
+    }
+}
+
+/// Tipo marcador da placa.
+/// Implementa o trait `Document` a partir das funções deste módulo.
+///
+/// ## Exemplo
+/// ```
+/// use brado::common::Document;
+/// use brado::placa::PlacaDocument;
+///
+/// let result = PlacaDocument.validate("ABC-1234"); // true
+/// assert!(result);
+///
+/// let result = PlacaDocument.bare_size(); // 7
+/// assert_eq!(result, 7);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct PlacaDocument;
+
+impl Document for PlacaDocument {
+    fn kind(&self) -> DocKind {
+        DocKind::Placa
+    }
+
+    fn bare_size(&self) -> usize {
+        PLACA_SIZE
+    }
+
+    fn check(
+        &self,
+        doc: &str,
+    ) -> Result<(), ValidationError> {
+        check(doc)
+    }
+
+    fn is_bare(
+        &self,
+        doc: &str,
+    ) -> bool {
+        is_bare(doc)
+    }
+
+    fn is_masked(
+        &self,
+        doc: &str,
+    ) -> bool {
+        is_masked(doc)
+    }
+
+    fn mask(
+        &self,
+        doc: &str,
+    ) -> Result<String, MaskError> {
+        mask(doc)
+    }
+
+    fn unmask(
+        &self,
+        doc: &str,
+    ) -> String {
+        unmask(doc)
+    }
+
+    fn generate(&self) -> String {
+        generate()
+    }
+
+    fn generate_masked(&self) -> String {
+        generate_masked()
+    }
+
+    fn generate_with_rng(
+        &self,
+        rng: &mut dyn RngCore,
+    ) -> String {
+        generate_with_rng(rng)
+    }
+
+    fn generate_masked_with_rng(
+        &self,
+        rng: &mut dyn RngCore,
+    ) -> String {
+        generate_masked_with_rng(rng)
+    }
+}
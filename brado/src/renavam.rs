@@ -2,10 +2,19 @@
 //! (RENAVAM).
 
 use crate::common::{
-    get_digits, get_symbols, random_decimal_vector, to_decimal,
+    check_digits, check_format, check_mask, get_digits, get_symbols,
+    random_decimal_vector_with_rng, to_decimal, Document, MaskError,
+    ValidationError,
 };
+use crate::docs::DocKind;
+use rand::{Rng, RngCore};
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
 
 const RENAVAM_SIZE: usize = 11;
+const LEGACY_SIZE: usize = 9;
 
 /// Realiza validação de RENAVAM, máscarado ou não.
 /// Retorna `true` se o argumento `doc` for um RENAVAM válido, caso contrário,
@@ -35,21 +44,97 @@ const RENAVAM_SIZE: usize = 11;
 /// assert!(!result);
 /// ```
 pub fn validate(doc: &str) -> bool {
-    let size: usize = doc.chars().count();
+    check(doc).is_ok()
+}
 
-    if size != RENAVAM_SIZE && !is_masked(doc) {
-        return false;
-    }
+/// Realiza validação de RENAVAM, máscarado ou não, informando o motivo da
+/// falha.
+/// Retorna `Ok(())` se o argumento `doc` for um RENAVAM válido, caso
+/// contrário, retorna o erro de validação (`ValidationError`) correspondente.
+///
+/// ## Exemplos
+///
+/// RENAVAMs válidos:
+/// ```
+/// use brado::renavam;
+///
+/// let result = renavam::check("79072338363"); // Ok(())
+/// assert!(result.is_ok());
+///
+/// let result = renavam::check("7907233836-3"); // Ok(())
+/// assert!(result.is_ok());
+/// ```
+///
+/// RENAVAMs inválidos:
+/// ```
+/// use brado::common::ValidationError;
+/// use brado::renavam;
+///
+/// let result = renavam::check("79072338362");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::CheckDigitMismatch {
+///         expected: String::from("3"),
+///         found: String::from("2"),
+///     }),
+/// );
+///
+/// let result = renavam::check("7907233836A");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::InvalidCharacter {
+///         position: 10,
+///         character: 'A',
+///     }),
+/// );
+/// ```
+pub fn check(doc: &str) -> Result<(), ValidationError> {
+    let digits: Vec<u16> =
+        check_format(doc, RENAVAM_SIZE, is_masked, to_decimal)?;
 
-    let digits: Vec<u16> = get_digits(doc, to_decimal);
+    let d11: u16 = generate_digit(&digits[..10]);
 
-    if digits.len() != RENAVAM_SIZE {
-        return false;
-    }
+    check_digits(&[d11], &digits[10..])
+}
 
-    let d11: u16 = generate_digit(&digits[..10]);
+/// Calcula o dígito verificador de um RENAVAM a partir dos 10 primeiros
+/// dígitos (`base`), sem símbolos.
+/// Retorna o dígito verificador, ou o erro correspondente
+/// (`ValidationError`) caso a base seja inválida.
+///
+/// ## Exemplos
+///
+/// Bases válidas:
+/// ```
+/// use brado::renavam;
+///
+/// let base = "7907233836";
+/// let result = renavam::compute_check_digits(base); // Ok("3")
+/// assert_eq!(result, Ok(String::from("3")));
+///
+/// let doc = format!("{}{}", base, result.unwrap()); // "79072338363"
+/// assert!(renavam::validate(&doc));
+/// ```
+///
+/// Bases inválidas:
+/// ```
+/// use brado::common::ValidationError;
+/// use brado::renavam;
+///
+/// let result = renavam::compute_check_digits("790723383");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::InvalidLength {
+///         expected: 10,
+///         found: 9,
+///     }),
+/// );
+/// ```
+pub fn compute_check_digits(base: &str) -> Result<String, ValidationError> {
+    let digits: Vec<u16> =
+        check_format(base, RENAVAM_SIZE - 1, |_| false, to_decimal)?;
 
-    d11 == digits[10]
+    Ok(generate_digit(&digits).to_string())
 }
 
 fn generate_digit(doc_slice: &[u16]) -> u16 {
@@ -157,16 +242,27 @@ pub fn is_masked(doc: &str) -> bool {
 ///     Err(e) => panic!("{}", e),
 /// };
 /// ```
-pub fn mask(doc: &str) -> Result<String, &'static str> {
-    if !is_bare(doc) {
-        return Err("The given string cannot be masked as RENAVAM!");
-    }
+pub fn mask(doc: &str) -> Result<String, MaskError> {
+    check_mask(doc, DocKind::Renavam, RENAVAM_SIZE, is_masked, to_decimal)?;
 
     let masked_doc: String = format!("{}-{}", &doc[0..10], &doc[10..11]);
 
     Ok(masked_doc)
 }
 
+/// Remove os símbolos do argumento `doc` e retorna o RENAVAM sem máscara.
+///
+/// ## Exemplo
+/// ```
+/// use brado::renavam;
+///
+/// let result = renavam::unmask("7907233836-3"); // "79072338363"
+/// assert_eq!(result, "79072338363");
+/// ```
+pub fn unmask(doc: &str) -> String {
+    crate::common::unmask(doc, to_decimal)
+}
+
 /// Gera e retorna um RENAVAM aleatório sem máscara.
 ///
 /// ## Exemplo
@@ -177,7 +273,30 @@ pub fn mask(doc: &str) -> Result<String, &'static str> {
 /// assert!(renavam::is_bare(&result)); // true
 /// ```
 pub fn generate() -> String {
-    let mut renavam: Vec<u16> = random_decimal_vector(10);
+    generate_with_rng(&mut rand::thread_rng())
+}
+
+/// Gera e retorna um RENAVAM aleatório sem máscara a partir do gerador de
+/// números aleatórios `rng`. Geradores com a mesma semente produzem o mesmo
+/// RENAVAM.
+///
+/// ## Exemplo
+/// ```
+/// use brado::renavam;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let result = renavam::generate_with_rng(&mut StdRng::seed_from_u64(42));
+/// assert!(renavam::is_bare(&result)); // true
+///
+/// let other = renavam::generate_with_rng(&mut StdRng::seed_from_u64(42));
+/// assert_eq!(result, other);
+/// ```
+pub fn generate_with_rng<R>(rng: &mut R) -> String
+where
+    R: Rng + ?Sized,
+{
+    let mut renavam: Vec<u16> = random_decimal_vector_with_rng(10, rng);
     renavam.push(generate_digit(&renavam));
 
     renavam
@@ -197,5 +316,349 @@ pub fn generate() -> String {
 /// assert!(renavam::is_masked(&result)); // true
 /// ```
 pub fn generate_masked() -> String {
-    mask(&generate()).expect("Invalid RENAVAM!")
+    generate_masked_with_rng(&mut rand::thread_rng())
+}
+
+/// Gera e retorna um RENAVAM aleatório com máscara a partir do gerador de
+/// números aleatórios `rng`. Geradores com a mesma semente produzem o mesmo
+/// RENAVAM.
+///
+/// ## Exemplo
+/// ```
+/// use brado::renavam;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let result = renavam::generate_masked_with_rng(&mut rng);
+/// assert!(renavam::is_masked(&result)); // true
+/// ```
+pub fn generate_masked_with_rng<R>(rng: &mut R) -> String
+where
+    R: Rng + ?Sized,
+{
+    mask(&generate_with_rng(rng)).expect("Invalid RENAVAM!")
+}
+
+/// Normaliza o RENAVAM `doc` e retorna o RENAVAM sem máscara, com 11
+/// dígitos. Aceita RENAVAMs de 11 dígitos, máscarados ou não, e RENAVAMs
+/// legados de 9 dígitos (emitidos antes de 2013), que são completados com
+/// zeros à esquerda.
+///
+/// Retorna o erro de validação (`ValidationError`) correspondente caso o
+/// RENAVAM seja inválido. Em caso de tamanho inválido
+/// (`ValidationError::InvalidLength`), `expected` é o tamanho aceito mais
+/// próximo: 9 para documentos com menos de 9 caracteres e 11 nos demais casos.
+///
+/// ## Exemplos
+///
+/// RENAVAMs válidos:
+/// ```
+/// use brado::renavam;
+///
+/// let result = renavam::normalize("639920578"); // Ok("00639920578")
+/// assert_eq!(result, Ok(String::from("00639920578")));
+///
+/// let result = renavam::normalize("7907233836-3"); // Ok("79072338363")
+/// assert_eq!(result, Ok(String::from("79072338363")));
+/// ```
+///
+/// RENAVAMs inválidos:
+/// ```
+/// use brado::common::ValidationError;
+/// use brado::renavam;
+///
+/// let result = renavam::normalize("639920579");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::CheckDigitMismatch {
+///         expected: String::from("8"),
+///         found: String::from("9"),
+///     }),
+/// );
+///
+/// let result = renavam::normalize("6399205");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::InvalidLength {
+///         expected: 9,
+///         found: 7,
+///     }),
+/// );
+/// ```
+pub fn normalize(doc: &str) -> Result<String, ValidationError> {
+    let size: usize = doc.chars().count();
+    if size < LEGACY_SIZE {
+        check_format(doc, LEGACY_SIZE, |_| false, to_decimal)?;
+    }
+    if size != LEGACY_SIZE {
+        check(doc)?;
+        return Ok(unmask(doc));
+    }
+
+    check_format(doc, LEGACY_SIZE, |_| false, to_decimal)?;
+
+    let bare: String = format!("{:0>width$}", doc, width = RENAVAM_SIZE);
+    check(&bare)?;
+
+    Ok(bare)
+}
+
+/// Realiza validação de RENAVAM, aceitando também RENAVAMs legados de 9
+/// dígitos (ver `normalize`).
+/// Retorna `true` se o argumento `doc` for um RENAVAM válido, caso contrário,
+/// retorna `false`.
+///
+/// ## Exemplos
+/// ```
+/// use brado::renavam;
+///
+/// let result = renavam::validate_lenient("639920578"); // true
+/// assert!(result);
+///
+/// let result = renavam::validate_lenient("79072338363"); // true
+/// assert!(result);
+///
+/// let result = renavam::validate_lenient("639920579"); // false
+/// assert!(!result);
+/// ```
+pub fn validate_lenient(doc: &str) -> bool {
+    check_lenient(doc).is_ok()
+}
+
+/// Realiza validação de RENAVAM, aceitando também RENAVAMs legados de 9
+/// dígitos (ver `normalize`), informando o motivo da falha.
+/// Retorna `Ok(())` se o argumento `doc` for um RENAVAM válido, caso
+/// contrário, retorna o erro de validação (`ValidationError`) correspondente.
+///
+/// ## Exemplos
+/// ```
+/// use brado::common::ValidationError;
+/// use brado::renavam;
+///
+/// let result = renavam::check_lenient("639920578"); // Ok(())
+/// assert!(result.is_ok());
+///
+/// let result = renavam::check_lenient("63992057A");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::InvalidCharacter {
+///         position: 8,
+///         character: 'A',
+///     }),
+/// );
+/// ```
+pub fn check_lenient(doc: &str) -> Result<(), ValidationError> {
+    normalize(doc).map(|_| ())
+}
+
+/// Gera e retorna um RENAVAM legado (9 dígitos) aleatório.
+///
+/// ## Exemplo
+/// ```
+/// use brado::renavam;
+///
+/// let result = renavam::generate_legacy(); // "639920578"
+/// assert_eq!(result.len(), 9);
+/// assert!(renavam::validate_lenient(&result)); // true
+/// ```
+pub fn generate_legacy() -> String {
+    generate_legacy_with_rng(&mut rand::thread_rng())
+}
+
+/// Gera e retorna um RENAVAM legado (9 dígitos) aleatório a partir do
+/// gerador de números aleatórios `rng`.
+///
+/// ## Exemplo
+/// ```
+/// use brado::renavam;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let result = renavam::generate_legacy_with_rng(&mut rng);
+/// assert_eq!(result.len(), 9);
+/// assert!(renavam::validate_lenient(&result)); // true
+/// ```
+pub fn generate_legacy_with_rng<R>(rng: &mut R) -> String
+where
+    R: Rng + ?Sized,
+{
+    let padding: usize = RENAVAM_SIZE - LEGACY_SIZE;
+
+    let mut renavam: Vec<u16> = vec![0; padding];
+    renavam.extend_from_slice(&random_decimal_vector_with_rng(8, rng));
+    renavam.push(generate_digit(&renavam));
+
+    renavam[padding..]
+        .iter()
+        .map(|d| d.to_string())
+        .collect::<Vec<String>>()
+        .concat()
+}
+
+/// RENAVAM validado, armazenado sem máscara.
+///
+/// Só pode ser construído a partir de um RENAVAM válido, máscarado ou não, por
+/// meio de `FromStr` ou `TryFrom<&str>`. É exibido (`Display`) com máscara.
+///
+/// ## Exemplo
+/// ```
+/// use brado::renavam::Renavam;
+///
+/// let doc: Renavam = "7907233836-3".parse().unwrap();
+/// assert_eq!(doc.as_bare(), "79072338363");
+/// assert_eq!(doc.to_masked(), "7907233836-3");
+/// assert_eq!(doc.to_string(), "7907233836-3");
+///
+/// let result = "7907233836-2".parse::<Renavam>();
+/// assert!(result.is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Renavam(String);
+
+impl Renavam {
+    /// Retorna o RENAVAM sem máscara.
+    pub fn as_bare(&self) -> &str {
+        &self.0
+    }
+
+    /// Retorna o RENAVAM com máscara.
+    pub fn to_masked(&self) -> String {
+        mask(&self.0).expect("Invalid RENAVAM!")
+    }
+}
+
+impl FromStr for Renavam {
+    type Err = ValidationError;
+
+    fn from_str(doc: &str) -> Result<Self, Self::Err> {
+        check(doc)?;
+        Ok(Renavam(unmask(doc)))
+    }
+}
+
+impl TryFrom<&str> for Renavam {
+    type Error = ValidationError;
+
+    fn try_from(doc: &str) -> Result<Self, Self::Error> {
+        doc.parse()
+    }
+}
+
+impl fmt::Display for Renavam {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(f, "{}", self.to_masked())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Renavam {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_bare())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Renavam {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let doc: String = String::deserialize(deserializer)?;
+        doc.parse().map_err(de::Error::custom)
+    }
+}
+
+/// Tipo marcador do RENAVAM.
+/// Implementa o trait `Document` a partir das funções deste módulo.
+///
+/// ## Exemplo
+/// ```
+/// use brado::common::Document;
+/// use brado::renavam::RenavamDocument;
+///
+/// let result = RenavamDocument.validate("7907233836-3"); // true
+/// assert!(result);
+///
+/// let result = RenavamDocument.bare_size(); // 11
+/// assert_eq!(result, 11);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct RenavamDocument;
+
+impl Document for RenavamDocument {
+    fn kind(&self) -> DocKind {
+        DocKind::Renavam
+    }
+
+    fn bare_size(&self) -> usize {
+        RENAVAM_SIZE
+    }
+
+    fn check(
+        &self,
+        doc: &str,
+    ) -> Result<(), ValidationError> {
+        check(doc)
+    }
+
+    fn is_bare(
+        &self,
+        doc: &str,
+    ) -> bool {
+        is_bare(doc)
+    }
+
+    fn is_masked(
+        &self,
+        doc: &str,
+    ) -> bool {
+        is_masked(doc)
+    }
+
+    fn mask(
+        &self,
+        doc: &str,
+    ) -> Result<String, MaskError> {
+        mask(doc)
+    }
+
+    fn unmask(
+        &self,
+        doc: &str,
+    ) -> String {
+        unmask(doc)
+    }
+
+    fn generate(&self) -> String {
+        generate()
+    }
+
+    fn generate_masked(&self) -> String {
+        generate_masked()
+    }
+
+    fn generate_with_rng(
+        &self,
+        rng: &mut dyn RngCore,
+    ) -> String {
+        generate_with_rng(rng)
+    }
+
+    fn generate_masked_with_rng(
+        &self,
+        rng: &mut dyn RngCore,
+    ) -> String {
+        generate_masked_with_rng(rng)
+    }
 }
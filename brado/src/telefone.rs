@@ -0,0 +1,1037 @@
+//! Utilitários para validação de números de telefone brasileiros (celular,
+//! fixo, 0800 e 0300).
+
+use crate::common::{
+    random_decimal_vector_with_rng, random_element_from_vector_with_rng,
+    Document, MaskError, Uf, ValidationError,
+};
+use crate::docs::DocKind;
+use rand::{Rng, RngCore};
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+const TELEFONE_SIZE: usize = 11;
+const LANDLINE_SIZE: usize = 10;
+const COUNTRY_CODE: &str = "+55";
+
+/// Códigos de área (DDD) definidos pela ANATEL e suas Unidades Federativas.
+const DDDS: [(u16, Uf); 67] = [
+    (11, Uf::Sp),
+    (12, Uf::Sp),
+    (13, Uf::Sp),
+    (14, Uf::Sp),
+    (15, Uf::Sp),
+    (16, Uf::Sp),
+    (17, Uf::Sp),
+    (18, Uf::Sp),
+    (19, Uf::Sp),
+    (21, Uf::Rj),
+    (22, Uf::Rj),
+    (24, Uf::Rj),
+    (27, Uf::Es),
+    (28, Uf::Es),
+    (31, Uf::Mg),
+    (32, Uf::Mg),
+    (33, Uf::Mg),
+    (34, Uf::Mg),
+    (35, Uf::Mg),
+    (37, Uf::Mg),
+    (38, Uf::Mg),
+    (41, Uf::Pr),
+    (42, Uf::Pr),
+    (43, Uf::Pr),
+    (44, Uf::Pr),
+    (45, Uf::Pr),
+    (46, Uf::Pr),
+    (47, Uf::Sc),
+    (48, Uf::Sc),
+    (49, Uf::Sc),
+    (51, Uf::Rs),
+    (53, Uf::Rs),
+    (54, Uf::Rs),
+    (55, Uf::Rs),
+    (61, Uf::Df),
+    (62, Uf::Go),
+    (63, Uf::To),
+    (64, Uf::Go),
+    (65, Uf::Mt),
+    (66, Uf::Mt),
+    (67, Uf::Ms),
+    (68, Uf::Ac),
+    (69, Uf::Ro),
+    (71, Uf::Ba),
+    (73, Uf::Ba),
+    (74, Uf::Ba),
+    (75, Uf::Ba),
+    (77, Uf::Ba),
+    (79, Uf::Se),
+    (81, Uf::Pe),
+    (82, Uf::Al),
+    (83, Uf::Pb),
+    (84, Uf::Rn),
+    (85, Uf::Ce),
+    (86, Uf::Pi),
+    (87, Uf::Pe),
+    (88, Uf::Ce),
+    (89, Uf::Pi),
+    (91, Uf::Pa),
+    (92, Uf::Am),
+    (93, Uf::Pa),
+    (94, Uf::Pa),
+    (95, Uf::Rr),
+    (96, Uf::Ap),
+    (97, Uf::Am),
+    (98, Uf::Ma),
+    (99, Uf::Ma),
+];
+
+/// Realiza validação de telefone, máscarado ou não.
+/// Retorna `true` se o argumento `doc` for um telefone válido, caso
+/// contrário, retorna `false`.
+///
+/// ## Exemplos
+///
+/// Telefones válidos:
+/// ```
+/// use brado::telefone;
+///
+/// let result = telefone::validate("11912345678"); // true
+/// assert!(result);
+///
+/// let result = telefone::validate("(11) 91234-5678"); // true
+/// assert!(result);
+///
+/// let result = telefone::validate("+55 (11) 3123-4567"); // true
+/// assert!(result);
+///
+/// let result = telefone::validate("0800 123 4567"); // true
+/// assert!(result);
+/// ```
+///
+/// Telefones inválidos:
+/// ```
+/// use brado::telefone;
+///
+/// let result = telefone::validate("(10) 91234-5678"); // false
+/// assert!(!result);
+///
+/// let result = telefone::validate("(11) 81234-5678"); // false
+/// assert!(!result);
+/// ```
+pub fn validate(doc: &str) -> bool {
+    check(doc).is_ok()
+}
+
+/// Realiza validação de telefone, máscarado ou não, informando o motivo da
+/// falha.
+/// Retorna `Ok(())` se o argumento `doc` for um telefone válido, caso
+/// contrário, retorna o erro de validação (`ValidationError`) correspondente.
+///
+/// São aceitos os formatos sem máscara (`11912345678`), com máscara
+/// (`(11) 91234-5678`, `(11)91234-5678`, `11 91234-5678`, `(11) 3123-4567` e
+/// `0800 123 4567`) e com o código do país (`+5511912345678`,
+/// `+55 11912345678` e `+55 (11) 91234-5678`). O DDD deve constar na
+/// lista da ANATEL, celulares possuem 9 dígitos iniciados por 9 e telefones
+/// fixos possuem 8 dígitos iniciados por 2 a 5. Números 0800 e 0300 não
+/// possuem DDD nem formato internacional.
+///
+/// ## Exemplos
+///
+/// Telefones válidos:
+/// ```
+/// use brado::telefone;
+///
+/// let result = telefone::check("(11) 91234-5678"); // Ok(())
+/// assert!(result.is_ok());
+///
+/// let result = telefone::check("+5511912345678"); // Ok(())
+/// assert!(result.is_ok());
+///
+/// let result = telefone::check("11 91234-5678"); // Ok(())
+/// assert!(result.is_ok());
+/// ```
+///
+/// Telefones inválidos:
+/// ```
+/// use brado::common::ValidationError;
+/// use brado::telefone;
+///
+/// let result = telefone::check("(10) 91234-5678");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::InvalidPrefix {
+///         found: String::from("10"),
+///     }),
+/// );
+///
+/// let result = telefone::check("(11) 81234-5678");
+/// assert_eq!(result, Err(ValidationError::InvalidFirstDigit { found: 8 }));
+///
+/// let result = telefone::check("(11) 9123-45678");
+/// assert_eq!(result, Err(ValidationError::InvalidMask));
+/// ```
+pub fn check(doc: &str) -> Result<(), ValidationError> {
+    let (international, digits): (bool, String) = parse(doc)?;
+
+    check_number(international, &digits)
+}
+
+/// Separa o código do país do restante do argumento `doc`.
+fn split_country_code(doc: &str) -> (bool, &str) {
+    match doc.strip_prefix(COUNTRY_CODE) {
+        Some(national) => (true, national),
+        None => (false, doc),
+    }
+}
+
+fn is_numeric(doc: &str) -> bool {
+    doc.chars().all(|c| c.is_ascii_digit())
+}
+
+fn is_national_bare(doc: &str) -> bool {
+    is_numeric(doc) && [LANDLINE_SIZE, TELEFONE_SIZE].contains(&doc.len())
+}
+
+/// Retorna o número nacional do argumento `doc` caso esteja no formato E.164
+/// (`+5511912345678`). Números 0800 e 0300 não possuem formato internacional.
+fn split_e164(doc: &str) -> Option<&str> {
+    doc.strip_prefix(COUNTRY_CODE)
+        .filter(|national| is_national_bare(national))
+        .filter(|national| !national.starts_with('0'))
+}
+
+/// Verifica o formato do argumento `doc` e retorna se possui o código do
+/// país e os dígitos do número nacional (com DDD).
+fn parse(doc: &str) -> Result<(bool, String), ValidationError> {
+    let (international, rest): (bool, &str) = split_country_code(doc);
+    let national: &str = match (international, rest.strip_prefix(' ')) {
+        (false, _) => doc,
+        (true, Some(national)) => national,
+        (true, None) if is_numeric(rest) => rest,
+        _ => return Err(ValidationError::InvalidMask),
+    };
+    let offset: usize = doc.chars().count() - national.chars().count();
+    let digits: String =
+        national.chars().filter(|c| c.is_ascii_digit()).collect();
+
+    if is_numeric(national) {
+        return match digits.len() {
+            LANDLINE_SIZE | TELEFONE_SIZE => Ok((international, digits)),
+            found => Err(ValidationError::InvalidLength {
+                expected: TELEFONE_SIZE,
+                found,
+            }),
+        };
+    }
+
+    if let Some((position, character)) = national
+        .chars()
+        .enumerate()
+        .find(|(_, c)| !c.is_ascii_digit() && !"() -".contains(*c))
+    {
+        return Err(ValidationError::InvalidCharacter {
+            position: position + offset,
+            character,
+        });
+    }
+
+    match national_formats(&digits)
+        .iter()
+        .any(|masked| masked == national)
+    {
+        true => Ok((international, digits)),
+        false => Err(ValidationError::InvalidMask),
+    }
+}
+
+/// Retorna as máscaras nacionais aceitas para os dígitos `digits`, sendo a
+/// primeira a máscara padrão (ver `format_national`). Para números com DDD,
+/// são aceitos também o DDD sem espaço (`(11)91234-5678`) e sem parênteses
+/// (`11 91234-5678`).
+fn national_formats(digits: &str) -> Vec<String> {
+    let masked: String = match format_national(digits) {
+        Some(masked) => masked,
+        None => return vec![],
+    };
+
+    if digits.starts_with('0') {
+        return vec![masked];
+    }
+
+    let number: &str = &masked[5..];
+
+    vec![
+        format!("({}) {}", &digits[0..2], number),
+        format!("({}){}", &digits[0..2], number),
+        format!("{} {}", &digits[0..2], number),
+    ]
+}
+
+/// Aplica a máscara nacional aos dígitos `digits`, de acordo com a
+/// quantidade de dígitos e o prefixo (0800 e 0300).
+fn format_national(digits: &str) -> Option<String> {
+    match digits.len() {
+        TELEFONE_SIZE if digits.starts_with('0') => Some(format!(
+            "{} {} {}",
+            &digits[0..4],
+            &digits[4..7],
+            &digits[7..11]
+        )),
+        TELEFONE_SIZE => Some(format!(
+            "({}) {}-{}",
+            &digits[0..2],
+            &digits[2..7],
+            &digits[7..11]
+        )),
+        LANDLINE_SIZE => Some(format!(
+            "({}) {}-{}",
+            &digits[0..2],
+            &digits[2..6],
+            &digits[6..10]
+        )),
+        _ => None,
+    }
+}
+
+fn check_number(
+    international: bool,
+    digits: &str,
+) -> Result<(), ValidationError> {
+    if digits.starts_with('0') {
+        let prefix: &str = &digits[..digits.len().min(4)];
+
+        if international || !["0800", "0300"].contains(&prefix) {
+            return Err(ValidationError::InvalidPrefix {
+                found: String::from(prefix),
+            });
+        }
+
+        return match digits.len() {
+            TELEFONE_SIZE => Ok(()),
+            found => Err(ValidationError::InvalidLength {
+                expected: TELEFONE_SIZE,
+                found,
+            }),
+        };
+    }
+
+    if uf_from_ddd(ddd_from_digits(digits)).is_none() {
+        return Err(ValidationError::InvalidPrefix {
+            found: String::from(&digits[..2]),
+        });
+    }
+
+    let first: u16 = digits[2..3].parse().expect("Invalid telefone!");
+
+    match (digits.len(), first) {
+        (TELEFONE_SIZE, 9) | (LANDLINE_SIZE, 2..=5) => Ok(()),
+        _ => Err(ValidationError::InvalidFirstDigit { found: first }),
+    }
+}
+
+fn ddd_from_digits(digits: &str) -> u16 {
+    digits[..2].parse().expect("Invalid telefone!")
+}
+
+fn uf_from_ddd(ddd: u16) -> Option<Uf> {
+    DDDS.iter()
+        .find(|(code, _)| *code == ddd)
+        .map(|(_, uf)| *uf)
+}
+
+/// Verifica se o argumento `doc` pode ser um telefone sem símbolos, com 10
+/// (fixo) ou 11 dígitos (celular, 0800 e 0300), incluindo o formato E.164
+/// (`+5511912345678`).
+/// Se for, retorna `true`, caso contrário, retorna `false`.
+///
+/// ## Exemplos
+///
+/// Telefones válidos:
+/// ```
+/// use brado::telefone;
+///
+/// let result = telefone::is_bare("11912345678"); // true
+/// assert!(result);
+///
+/// let result = telefone::is_bare("1131234567"); // true
+/// assert!(result);
+///
+/// let result = telefone::is_bare("+5511912345678"); // true
+/// assert!(result);
+///
+/// let result = telefone::is_bare("(11) 91234-5678"); // false
+/// assert!(!result);
+/// ```
+///
+/// Telefones inválidos:
+/// ```
+/// use brado::telefone;
+///
+/// let result = telefone::is_bare("10912345678"); // true
+/// assert!(result);
+/// ```
+pub fn is_bare(doc: &str) -> bool {
+    is_national_bare(doc) || split_e164(doc).is_some()
+}
+
+/// Verifica se o argumento `doc` pode ser um telefone com símbolos,
+/// incluindo os formatos com o código do país separado por espaço
+/// (`+55 11912345678` e `+55 (11) 91234-5678`). O formato E.164
+/// (`+5511912345678`) é considerado sem máscara (ver `is_bare`).
+/// Se for, retorna `true`, caso contrário, retorna `false`.
+///
+/// ## Exemplos
+///
+/// Telefones válidos:
+/// ```
+/// use brado::telefone;
+///
+/// let result = telefone::is_masked("(11) 91234-5678"); // true
+/// assert!(result);
+///
+/// let result = telefone::is_masked("11 91234-5678"); // true
+/// assert!(result);
+///
+/// let result = telefone::is_masked("11912345678"); // false
+/// assert!(!result);
+///
+/// let result = telefone::is_masked("+5511912345678"); // false
+/// assert!(!result);
+/// ```
+///
+/// Telefones inválidos:
+/// ```
+/// use brado::telefone;
+///
+/// let result = telefone::is_masked("(10) 91234-5678"); // true
+/// assert!(result);
+/// ```
+pub fn is_masked(doc: &str) -> bool {
+    !is_bare(doc) && parse(doc).is_ok()
+}
+
+/// Aplica máscara de telefone no argumento `doc` e retorna resultado.
+/// O argumento deve ser uma string sem símbolos, caso contrário, deve lançar
+/// erro. Telefones no formato E.164 mantêm o código do país.
+///
+/// ## Exemplos
+///
+/// Documentos de 10 e 11 dígitos sem máscara:
+/// ```
+/// use brado::telefone;
+///
+/// let result = telefone::mask("11912345678"); // Ok("(11) 91234-5678")
+/// assert_eq!(result, Ok(String::from("(11) 91234-5678")));
+///
+/// let result = telefone::mask("1131234567"); // Ok("(11) 3123-4567")
+/// assert_eq!(result, Ok(String::from("(11) 3123-4567")));
+///
+/// let result = telefone::mask("08001234567"); // Ok("0800 123 4567")
+/// assert_eq!(result, Ok(String::from("0800 123 4567")));
+///
+/// let result = telefone::mask("+5511912345678"); // Ok("+55 (11) 91234-5678")
+/// assert_eq!(result, Ok(String::from("+55 (11) 91234-5678")));
+/// ```
+///
+/// Documento com máscara:
+/// ```should_panic
+/// use brado::telefone;
+///
+/// let result = match telefone::mask("(11) 91234-5678") { // It panics!
+///     Ok(doc) => doc,
+///     Err(e) => panic!("{}", e),
+/// };
+/// ```
+pub fn mask(doc: &str) -> Result<String, MaskError> {
+    if let Some(national) = split_e164(doc) {
+        let masked: String =
+            format_national(national).expect("Invalid telefone!");
+        return Ok(format!("{} {}", COUNTRY_CODE, masked));
+    }
+
+    if is_bare(doc) {
+        return Ok(format_national(doc).expect("Invalid telefone!"));
+    }
+
+    if is_masked(doc) {
+        return Err(MaskError::AlreadyMasked {
+            kind: DocKind::Telefone,
+        });
+    }
+
+    match doc.chars().enumerate().find(|(_, c)| !c.is_ascii_digit()) {
+        Some((position, character)) => Err(MaskError::InvalidCharacter {
+            kind: DocKind::Telefone,
+            position,
+            character,
+        }),
+        None => Err(MaskError::InvalidLength {
+            kind: DocKind::Telefone,
+            expected: TELEFONE_SIZE,
+            found: doc.chars().count(),
+        }),
+    }
+}
+
+/// Remove os símbolos e o código do país do argumento `doc` e retorna o
+/// telefone sem máscara (com DDD).
+///
+/// ## Exemplo
+/// ```
+/// use brado::telefone;
+///
+/// let result = telefone::unmask("(11) 91234-5678"); // "11912345678"
+/// assert_eq!(result, "11912345678");
+///
+/// let result = telefone::unmask("+55 (11) 91234-5678"); // "11912345678"
+/// assert_eq!(result, "11912345678");
+/// ```
+pub fn unmask(doc: &str) -> String {
+    split_country_code(doc)
+        .1
+        .chars()
+        .filter(|c| c.is_ascii_digit())
+        .collect()
+}
+
+/// Gera e retorna um telefone celular ou fixo aleatório sem máscara.
+///
+/// ## Exemplo
+/// ```
+/// use brado::telefone;
+///
+/// let result = telefone::generate(); // "11912345678"
+/// assert!(telefone::is_bare(&result)); // true
+/// ```
+pub fn generate() -> String {
+    generate_with_rng(&mut rand::thread_rng())
+}
+
+/// Gera e retorna um telefone celular ou fixo aleatório sem máscara a partir
+/// do gerador de números aleatórios `rng`. Geradores com a mesma semente
+/// produzem o mesmo telefone.
+///
+/// ## Exemplo
+/// ```
+/// use brado::telefone;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let result = telefone::generate_with_rng(&mut StdRng::seed_from_u64(42));
+/// assert!(telefone::is_bare(&result)); // true
+///
+/// let other = telefone::generate_with_rng(&mut StdRng::seed_from_u64(42));
+/// assert_eq!(result, other);
+/// ```
+pub fn generate_with_rng<R>(rng: &mut R) -> String
+where
+    R: Rng + ?Sized,
+{
+    let (ddd, _): (u16, Uf) = random_element_from_vector_with_rng(&DDDS, rng);
+
+    generate_geographic(ddd, rng)
+}
+
+/// Gera e retorna um telefone celular ou fixo aleatório com máscara.
+///
+/// ## Exemplo
+/// ```
+/// use brado::telefone;
+///
+/// let result = telefone::generate_masked(); // "(11) 91234-5678"
+/// assert!(telefone::is_masked(&result)); // true
+/// ```
+pub fn generate_masked() -> String {
+    generate_masked_with_rng(&mut rand::thread_rng())
+}
+
+/// Gera e retorna um telefone celular ou fixo aleatório com máscara a partir
+/// do gerador de números aleatórios `rng`. Geradores com a mesma semente
+/// produzem o mesmo telefone.
+///
+/// ## Exemplo
+/// ```
+/// use brado::telefone;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let result = telefone::generate_masked_with_rng(&mut rng);
+/// assert!(telefone::is_masked(&result)); // true
+/// ```
+pub fn generate_masked_with_rng<R>(rng: &mut R) -> String
+where
+    R: Rng + ?Sized,
+{
+    mask(&generate_with_rng(rng)).expect("Invalid telefone!")
+}
+
+fn generate_geographic<R>(
+    ddd: u16,
+    rng: &mut R,
+) -> String
+where
+    R: Rng + ?Sized,
+{
+    let kind: TelefoneKind = match rng.gen_bool(0.5) {
+        true => TelefoneKind::Mobile,
+        false => TelefoneKind::Landline,
+    };
+
+    generate_number(kind, ddd, rng)
+}
+
+fn generate_number<R>(
+    kind: TelefoneKind,
+    ddd: u16,
+    rng: &mut R,
+) -> String
+where
+    R: Rng + ?Sized,
+{
+    let (prefix, size): (String, usize) = match kind {
+        TelefoneKind::Mobile => (format!("{:02}9", ddd), 8),
+        TelefoneKind::Landline => {
+            (format!("{:02}{}", ddd, rng.gen_range(2..=5)), 7)
+        }
+        TelefoneKind::TollFree => (String::from("0800"), 7),
+        TelefoneKind::SharedCost => (String::from("0300"), 7),
+    };
+    let number: String = random_decimal_vector_with_rng(size, rng)
+        .iter()
+        .map(|d| d.to_string())
+        .collect();
+
+    format!("{}{}", prefix, number)
+}
+
+/// Tipos de telefone.
+///
+/// ## Exemplo
+/// ```
+/// use brado::telefone::{self, TelefoneKind};
+///
+/// let result = telefone::kind("(11) 91234-5678");
+/// assert_eq!(result, Some(TelefoneKind::Mobile));
+///
+/// let result = TelefoneKind::TollFree.description(); // "0800 (gratuito)"
+/// assert_eq!(result, "0800 (gratuito)");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TelefoneKind {
+    /// Celular: DDD e 9 dígitos iniciados por 9.
+    Mobile,
+    /// Fixo: DDD e 8 dígitos iniciados por 2 a 5.
+    Landline,
+    /// Número 0800, gratuito para quem liga.
+    TollFree,
+    /// Número 0300, de custo compartilhado.
+    SharedCost,
+}
+
+impl TelefoneKind {
+    /// Todos os tipos de telefone.
+    pub const ALL: &'static [TelefoneKind] = &[
+        TelefoneKind::Mobile,
+        TelefoneKind::Landline,
+        TelefoneKind::TollFree,
+        TelefoneKind::SharedCost,
+    ];
+
+    /// Retorna a descrição do tipo de telefone.
+    pub fn description(&self) -> &'static str {
+        match self {
+            TelefoneKind::Mobile => "Celular",
+            TelefoneKind::Landline => "Fixo",
+            TelefoneKind::TollFree => "0800 (gratuito)",
+            TelefoneKind::SharedCost => "0300 (custo compartilhado)",
+        }
+    }
+
+    /// Retorna `true` se o tipo de telefone possui DDD (celular e fixo).
+    pub fn is_geographic(&self) -> bool {
+        matches!(self, TelefoneKind::Mobile | TelefoneKind::Landline)
+    }
+}
+
+/// Identifica o tipo (`TelefoneKind`) de um telefone válido, máscarado ou
+/// não. Retorna `None` caso o argumento `doc` não seja um telefone válido.
+///
+/// ## Exemplos
+/// ```
+/// use brado::telefone::{self, TelefoneKind};
+///
+/// let result = telefone::kind("+55 (11) 3123-4567");
+/// assert_eq!(result, Some(TelefoneKind::Landline));
+///
+/// let result = telefone::kind("0300 123 4567");
+/// assert_eq!(result, Some(TelefoneKind::SharedCost));
+///
+/// let result = telefone::kind("(11) 81234-5678");
+/// assert_eq!(result, None);
+/// ```
+pub fn kind(doc: &str) -> Option<TelefoneKind> {
+    check(doc).ok()?;
+
+    let digits: String = unmask(doc);
+
+    match (&digits[..2], digits.len()) {
+        ("08", _) => Some(TelefoneKind::TollFree),
+        ("03", _) => Some(TelefoneKind::SharedCost),
+        (_, TELEFONE_SIZE) => Some(TelefoneKind::Mobile),
+        _ => Some(TelefoneKind::Landline),
+    }
+}
+
+/// Extrai o DDD de um telefone celular ou fixo válido, máscarado ou não.
+/// Retorna `None` caso o argumento `doc` não seja um telefone válido ou seja
+/// um número 0800 ou 0300.
+///
+/// ## Exemplos
+/// ```
+/// use brado::telefone;
+///
+/// let result = telefone::ddd("(21) 91234-5678"); // Some(21)
+/// assert_eq!(result, Some(21));
+///
+/// let result = telefone::ddd("0800 123 4567"); // None
+/// assert_eq!(result, None);
+/// ```
+pub fn ddd(doc: &str) -> Option<u16> {
+    kind(doc)?
+        .is_geographic()
+        .then(|| ddd_from_digits(&unmask(doc)))
+}
+
+/// Retorna a Unidade Federativa do DDD de um telefone celular ou fixo
+/// válido, máscarado ou não.
+/// Retorna `None` caso o argumento `doc` não seja um telefone válido ou seja
+/// um número 0800 ou 0300.
+///
+/// ## Exemplos
+/// ```
+/// use brado::common::Uf;
+/// use brado::telefone;
+///
+/// let result = telefone::uf("(61) 3123-4567"); // Some(Uf::Df)
+/// assert_eq!(result, Some(Uf::Df));
+///
+/// let result = telefone::uf("0800 123 4567"); // None
+/// assert_eq!(result, None);
+/// ```
+pub fn uf(doc: &str) -> Option<Uf> {
+    uf_from_ddd(ddd(doc)?)
+}
+
+/// Converte um telefone celular ou fixo válido, máscarado ou não, para o
+/// formato internacional E.164 (`+55` seguido do DDD e do número).
+/// Retorna o erro de validação (`ValidationError`) correspondente caso o
+/// telefone seja inválido ou seja um número 0800 ou 0300, que não possuem
+/// formato internacional.
+///
+/// ## Exemplos
+/// ```
+/// use brado::common::ValidationError;
+/// use brado::telefone;
+///
+/// let result = telefone::to_e164("(11) 91234-5678"); // Ok("+5511912345678")
+/// assert_eq!(result, Ok(String::from("+5511912345678")));
+///
+/// let result = telefone::to_e164("0800 123 4567");
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::InvalidPrefix {
+///         found: String::from("0800"),
+///     }),
+/// );
+/// ```
+pub fn to_e164(doc: &str) -> Result<String, ValidationError> {
+    let (_, digits): (bool, String) = parse(doc)?;
+
+    check_number(true, &digits)?;
+
+    Ok(format!("{}{}", COUNTRY_CODE, digits))
+}
+
+/// Gera e retorna um telefone celular ou fixo aleatório sem máscara com o
+/// DDD `ddd`.
+/// Retorna o erro de validação (`ValidationError`) correspondente caso o DDD
+/// não conste na lista da ANATEL.
+///
+/// ## Exemplos
+/// ```
+/// use brado::common::ValidationError;
+/// use brado::telefone;
+///
+/// let result = telefone::generate_for_ddd(11).unwrap();
+/// assert_eq!(telefone::ddd(&result), Some(11));
+///
+/// let result = telefone::generate_for_ddd(10);
+/// assert_eq!(
+///     result,
+///     Err(ValidationError::InvalidPrefix {
+///         found: String::from("10"),
+///     }),
+/// );
+/// ```
+pub fn generate_for_ddd(ddd: u16) -> Result<String, ValidationError> {
+    generate_for_ddd_with_rng(ddd, &mut rand::thread_rng())
+}
+
+/// Gera e retorna um telefone celular ou fixo aleatório sem máscara com o
+/// DDD `ddd`, a partir do gerador de números aleatórios `rng`.
+///
+/// ## Exemplo
+/// ```
+/// use brado::telefone;
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let result = telefone::generate_for_ddd_with_rng(61, &mut rng).unwrap();
+/// assert_eq!(telefone::ddd(&result), Some(61));
+/// ```
+pub fn generate_for_ddd_with_rng<R>(
+    ddd: u16,
+    rng: &mut R,
+) -> Result<String, ValidationError>
+where
+    R: Rng + ?Sized,
+{
+    if uf_from_ddd(ddd).is_none() {
+        return Err(ValidationError::InvalidPrefix {
+            found: format!("{:02}", ddd),
+        });
+    }
+
+    Ok(generate_geographic(ddd, rng))
+}
+
+/// Gera e retorna um telefone aleatório sem máscara do tipo `kind`. Para
+/// celulares e fixos, o DDD é escolhido aleatoriamente.
+///
+/// ## Exemplo
+/// ```
+/// use brado::telefone::{self, TelefoneKind};
+///
+/// let result = telefone::generate_for_kind(TelefoneKind::TollFree);
+/// assert_eq!(telefone::kind(&result), Some(TelefoneKind::TollFree));
+/// ```
+pub fn generate_for_kind(kind: TelefoneKind) -> String {
+    generate_for_kind_with_rng(kind, &mut rand::thread_rng())
+}
+
+/// Gera e retorna um telefone aleatório sem máscara do tipo `kind`, a partir
+/// do gerador de números aleatórios `rng`.
+///
+/// ## Exemplo
+/// ```
+/// use brado::telefone::{self, TelefoneKind};
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let kind = TelefoneKind::Mobile;
+/// let result = telefone::generate_for_kind_with_rng(kind, &mut rng);
+/// assert_eq!(telefone::kind(&result), Some(TelefoneKind::Mobile));
+/// ```
+pub fn generate_for_kind_with_rng<R>(
+    kind: TelefoneKind,
+    rng: &mut R,
+) -> String
+where
+    R: Rng + ?Sized,
+{
+    let (ddd, _): (u16, Uf) = random_element_from_vector_with_rng(&DDDS, rng);
+
+    generate_number(kind, ddd, rng)
+}
+
+/// Telefone validado, armazenado sem máscara e sem o código do país.
+///
+/// Só pode ser construído a partir de um telefone válido, máscarado ou não,
+/// por meio de `FromStr` ou `TryFrom<&str>`. É exibido (`Display`) com
+/// máscara.
+///
+/// ## Exemplo
+/// ```
+/// use brado::telefone::{Telefone, TelefoneKind};
+///
+/// let doc: Telefone = "+55 (11) 91234-5678".parse().unwrap();
+/// assert_eq!(doc.as_bare(), "11912345678");
+/// assert_eq!(doc.to_masked(), "(11) 91234-5678");
+/// assert_eq!(doc.to_string(), "(11) 91234-5678");
+/// assert_eq!(doc.kind(), TelefoneKind::Mobile);
+/// assert_eq!(doc.to_e164(), Some(String::from("+5511912345678")));
+///
+/// let result = "(11) 81234-5678".parse::<Telefone>();
+/// assert!(result.is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Telefone(String);
+
+impl Telefone {
+    /// Retorna o telefone sem máscara.
+    pub fn as_bare(&self) -> &str {
+        &self.0
+    }
+
+    /// Retorna o telefone com máscara.
+    pub fn to_masked(&self) -> String {
+        mask(&self.0).expect("Invalid telefone!")
+    }
+
+    /// Retorna o tipo do telefone.
+    pub fn kind(&self) -> TelefoneKind {
+        kind(&self.0).expect("Invalid telefone!")
+    }
+
+    /// Retorna o DDD do telefone, ou `None` para números 0800 e 0300.
+    pub fn ddd(&self) -> Option<u16> {
+        ddd(&self.0)
+    }
+
+    /// Retorna o telefone no formato E.164, ou `None` para números 0800 e
+    /// 0300.
+    pub fn to_e164(&self) -> Option<String> {
+        to_e164(&self.0).ok()
+    }
+}
+
+impl FromStr for Telefone {
+    type Err = ValidationError;
+
+    fn from_str(doc: &str) -> Result<Self, Self::Err> {
+        check(doc)?;
+        Ok(Telefone(unmask(doc)))
+    }
+}
+
+impl TryFrom<&str> for Telefone {
+    type Error = ValidationError;
+
+    fn try_from(doc: &str) -> Result<Self, Self::Error> {
+        doc.parse()
+    }
+}
+
+impl fmt::Display for Telefone {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(f, "{}", self.to_masked())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Telefone {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_bare())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Telefone {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let doc: String = String::deserialize(deserializer)?;
+        doc.parse().map_err(de::Error::custom)
+    }
+}
+
+/// Tipo marcador do telefone.
+/// Implementa o trait `Document` a partir das funções deste módulo. O
+/// tamanho sem máscara (`bare_size`) corresponde ao do celular (11 dígitos).
+///
+/// ## Exemplo
+/// ```
+/// use brado::common::Document;
+/// use brado::telefone::TelefoneDocument;
+///
+/// let result = TelefoneDocument.validate("(11) 91234-5678"); // true
+/// assert!(result);
+///
+/// let result = TelefoneDocument.bare_size(); // 11
+/// assert_eq!(result, 11);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct TelefoneDocument;
+
+impl Document for TelefoneDocument {
+    fn kind(&self) -> DocKind {
+        DocKind::Telefone
+    }
+
+    fn bare_size(&self) -> usize {
+        TELEFONE_SIZE
+    }
+
+    fn check(
+        &self,
+        doc: &str,
+    ) -> Result<(), ValidationError> {
+        check(doc)
+    }
+
+    fn is_bare(
+        &self,
+        doc: &str,
+    ) -> bool {
+        is_bare(doc)
+    }
+
+    fn is_masked(
+        &self,
+        doc: &str,
+    ) -> bool {
+        is_masked(doc)
+    }
+
+    fn mask(
+        &self,
+        doc: &str,
+    ) -> Result<String, MaskError> {
+        mask(doc)
+    }
+
+    fn unmask(
+        &self,
+        doc: &str,
+    ) -> String {
+        unmask(doc)
+    }
+
+    fn generate(&self) -> String {
+        generate()
+    }
+
+    fn generate_masked(&self) -> String {
+        generate_masked()
+    }
+
+    fn generate_with_rng(
+        &self,
+        rng: &mut dyn RngCore,
+    ) -> String {
+        generate_with_rng(rng)
+    }
+
+    fn generate_masked_with_rng(
+        &self,
+        rng: &mut dyn RngCore,
+    ) -> String {
+        generate_masked_with_rng(rng)
+    }
+}
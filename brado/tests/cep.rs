@@ -0,0 +1,291 @@
+#[cfg(test)]
+mod cep_tests {
+    use brado;
+    use brado::cep::{Cep, CepDocument};
+    use brado::common::{Document, MaskError, Uf, ValidationError};
+    use brado::docs::DocKind;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    const VALID_CEPS: [(&str, &str, Uf); 10] = [
+        ("01310100", "01310-100", Uf::Sp),
+        ("20040020", "20040-020", Uf::Rj),
+        ("30130010", "30130-010", Uf::Mg),
+        ("40020000", "40020-000", Uf::Ba),
+        ("68900073", "68900-073", Uf::Ap),
+        ("69301000", "69301-000", Uf::Rr),
+        ("69400000", "69400-000", Uf::Am),
+        ("70040010", "70040-010", Uf::Df),
+        ("73700000", "73700-000", Uf::Go),
+        ("99999999", "99999-999", Uf::Rs),
+    ];
+
+    #[test]
+    fn cep_validate_1_valid_ceps() {
+        for (bare, masked, _) in VALID_CEPS {
+            assert_eq!(brado::cep::validate(bare), true);
+            assert_eq!(brado::cep::validate(masked), true);
+        }
+    }
+
+    #[test]
+    fn cep_validate_2_invalid_ceps() {
+        let invalid_ceps = [
+            "00000000",
+            "00999-999",
+            "0131010",
+            "013101000",
+            "01310-10",
+            "0131-0100",
+            "013101-00",
+            "01310.100",
+            "01310-100-",
+            "AAAAAAAA",
+            "AAAAA-AAA",
+        ];
+        for invalid_cep in invalid_ceps {
+            assert_eq!(brado::cep::validate(invalid_cep), false);
+        }
+    }
+
+    #[test]
+    fn cep_check_1_valid_ceps() {
+        for (bare, masked, _) in VALID_CEPS {
+            assert_eq!(brado::cep::check(bare), Ok(()));
+            assert_eq!(brado::cep::check(masked), Ok(()));
+        }
+    }
+
+    #[test]
+    fn cep_check_2_invalid_ceps() {
+        let invalid_ceps = [
+            (
+                "00999-999",
+                ValidationError::InvalidPrefix {
+                    found: String::from("00999"),
+                },
+            ),
+            (
+                "0131010",
+                ValidationError::InvalidLength {
+                    expected: 8,
+                    found: 7,
+                },
+            ),
+            ("0131-0100", ValidationError::InvalidMask),
+            (
+                "0131010A",
+                ValidationError::InvalidCharacter {
+                    position: 7,
+                    character: 'A',
+                },
+            ),
+        ];
+        for (invalid_cep, error) in invalid_ceps {
+            assert_eq!(brado::cep::check(invalid_cep), Err(error));
+        }
+    }
+
+    #[test]
+    fn cep_is_bare_1_valid_bare() {
+        for (bare, _, _) in VALID_CEPS {
+            assert_eq!(brado::cep::is_bare(bare), true);
+        }
+        assert_eq!(brado::cep::is_bare("00000000"), true);
+    }
+
+    #[test]
+    fn cep_is_bare_2_invalid_bare() {
+        let invalid_bares = ["01310-100", "0131010", "013101000", "AAAAAAAA"];
+        for invalid_bare in invalid_bares {
+            assert_eq!(brado::cep::is_bare(invalid_bare), false);
+        }
+    }
+
+    #[test]
+    fn cep_is_masked_1_valid_masked() {
+        for (_, masked, _) in VALID_CEPS {
+            assert_eq!(brado::cep::is_masked(masked), true);
+        }
+        assert_eq!(brado::cep::is_masked("00000-000"), true);
+    }
+
+    #[test]
+    fn cep_is_masked_2_invalid_masked() {
+        let invalid_masked = [
+            "01310100",
+            "0131-0100",
+            "01310.100",
+            "01310-10",
+            "01310--100",
+        ];
+        for invalid_masked in invalid_masked {
+            assert_eq!(brado::cep::is_masked(invalid_masked), false);
+        }
+    }
+
+    #[test]
+    fn cep_mask_1_valid_mask() {
+        for (bare, masked, _) in VALID_CEPS {
+            assert_eq!(brado::cep::mask(bare), Ok(String::from(masked)));
+            assert_eq!(brado::cep::unmask(masked), bare);
+        }
+    }
+
+    #[test]
+    fn cep_mask_2_invalid_mask() {
+        assert_eq!(
+            brado::cep::mask("01310-100"),
+            Err(MaskError::AlreadyMasked { kind: DocKind::Cep }),
+        );
+        assert_eq!(
+            brado::cep::mask("0131010"),
+            Err(MaskError::InvalidLength {
+                kind: DocKind::Cep,
+                expected: 8,
+                found: 7,
+            }),
+        );
+    }
+
+    #[test]
+    fn cep_uf_1() {
+        for (bare, masked, uf) in VALID_CEPS {
+            assert_eq!(brado::cep::uf(bare), Some(uf));
+            assert_eq!(brado::cep::uf(masked), Some(uf));
+            assert_eq!(brado::cep::validate_for_uf(uf, masked), true);
+            assert_eq!(brado::cep::check_for_uf(uf, bare), Ok(()));
+        }
+        assert_eq!(brado::cep::uf("00999-999"), None);
+    }
+
+    #[test]
+    fn cep_uf_2_mismatch() {
+        assert_eq!(brado::cep::validate_for_uf(Uf::Rj, "01310-100"), false);
+        assert_eq!(
+            brado::cep::check_for_uf(Uf::Am, "69301-000"),
+            Err(ValidationError::InvalidPrefix {
+                found: String::from("69301"),
+            }),
+        );
+        assert_eq!(
+            brado::cep::check_for_uf(Uf::Sp, "0131010"),
+            Err(ValidationError::InvalidLength {
+                expected: 8,
+                found: 7,
+            }),
+        );
+    }
+
+    #[test]
+    fn cep_ranges_1() {
+        for &uf in Uf::ALL {
+            let ranges = brado::cep::ranges(uf);
+            assert!(!ranges.is_empty());
+            for range in ranges {
+                for cep in [*range.start(), *range.end()] {
+                    let cep = format!("{:08}", cep);
+                    assert_eq!(brado::cep::uf(&cep), Some(uf));
+                }
+            }
+        }
+        assert_eq!(
+            brado::cep::ranges(Uf::Am),
+            vec![69000000..=69299999, 69400000..=69899999],
+        );
+        assert_eq!(
+            brado::cep::ranges(Uf::Go),
+            vec![72800000..=72999999, 73700000..=76799999],
+        );
+    }
+
+    #[test]
+    fn cep_newtype_1_valid() {
+        for (bare, masked, uf) in VALID_CEPS {
+            let cep: Cep = masked.parse().unwrap();
+            assert_eq!(cep.as_bare(), bare);
+            assert_eq!(cep.to_masked(), masked);
+            assert_eq!(cep.to_string(), masked);
+            assert_eq!(cep.uf(), uf);
+            assert_eq!(Cep::try_from(bare), Ok(cep));
+        }
+    }
+
+    #[test]
+    fn cep_newtype_2_invalid() {
+        assert_eq!(
+            "00999-999".parse::<Cep>(),
+            Err(ValidationError::InvalidPrefix {
+                found: String::from("00999"),
+            }),
+        );
+        assert!(Cep::try_from("0131010").is_err());
+    }
+
+    #[test]
+    fn cep_document_1() {
+        assert_eq!(CepDocument.kind(), DocKind::Cep);
+        assert_eq!(CepDocument.name(), "CEP");
+        assert_eq!(CepDocument.bare_size(), 8);
+        assert_eq!(CepDocument.validate("01310-100"), true);
+        assert_eq!(brado::docs::is_cep("01310-100"), true);
+        assert_eq!(DocKind::ALL.contains(&DocKind::Cep), false);
+        assert_eq!(brado::docs::identify("01310-100"), vec![]);
+    }
+
+    #[test]
+    fn cep_generate_1() {
+        for _ in 0..1000 {
+            let cep = brado::cep::generate();
+            assert_eq!(brado::cep::validate(&cep), true);
+            assert_eq!(brado::cep::is_bare(&cep), true);
+        }
+    }
+
+    #[test]
+    fn cep_generate_masked_1() {
+        for _ in 0..1000 {
+            let cep = brado::cep::generate_masked();
+            assert_eq!(brado::cep::validate(&cep), true);
+            assert_eq!(brado::cep::is_masked(&cep), true);
+        }
+    }
+
+    #[test]
+    fn cep_generate_with_rng_1() {
+        for seed in 0..100 {
+            let cep =
+                brado::cep::generate_with_rng(&mut StdRng::seed_from_u64(seed));
+            assert_eq!(brado::cep::validate(&cep), true);
+            assert_eq!(
+                brado::cep::generate_with_rng(&mut StdRng::seed_from_u64(seed)),
+                cep,
+            );
+        }
+    }
+
+    #[test]
+    fn cep_generate_masked_with_rng_1() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut other = StdRng::seed_from_u64(42);
+        for _ in 0..100 {
+            let cep = brado::cep::generate_masked_with_rng(&mut rng);
+            assert_eq!(brado::cep::is_masked(&cep), true);
+            assert_eq!(brado::cep::generate_masked_with_rng(&mut other), cep);
+        }
+    }
+
+    #[test]
+    fn cep_generate_for_uf_1() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for &uf in Uf::ALL {
+            for _ in 0..100 {
+                let cep = brado::cep::generate_for_uf_with_rng(uf, &mut rng);
+                assert_eq!(brado::cep::uf(&cep), Some(uf));
+
+                let cep = brado::cep::generate_for_uf(uf);
+                assert_eq!(brado::cep::validate_for_uf(uf, &cep), true);
+            }
+        }
+    }
+}
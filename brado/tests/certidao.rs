@@ -1,6 +1,11 @@
 #[cfg(test)]
 mod certidao_tests {
     use brado;
+    use brado::certidao::CertidaoKind;
+    use brado::common::{MaskError, ValidationError};
+    use brado::docs::DocKind;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
 
     #[test]
     fn certidao_validate_1_valid_certidaos() {
@@ -48,6 +53,91 @@ mod certidao_tests {
         }
     }
 
+    #[test]
+    fn certidao_check_1_valid_certidaos() {
+        let valid_certidaos = [
+            "21924201552023106304243115818536",
+            "219242 01 55 2023 1 06304 243 1158185-36",
+        ];
+        for valid_certidao in valid_certidaos {
+            assert_eq!(brado::certidao::check(valid_certidao), Ok(()));
+        }
+    }
+
+    #[test]
+    fn certidao_check_2_invalid_certidaos() {
+        let invalid_certidaos = [
+            (
+                "2192420155202310630424311581853",
+                ValidationError::InvalidLength {
+                    expected: 32,
+                    found: 31,
+                },
+            ),
+            (
+                "21924201552023106304243115818535",
+                ValidationError::CheckDigitMismatch {
+                    expected: String::from("36"),
+                    found: String::from("35"),
+                },
+            ),
+            (
+                "219242 01 55 2023 1 06304 243 1158185:36",
+                ValidationError::InvalidMask,
+            ),
+            (
+                "2192420155202310630424311581853A",
+                ValidationError::InvalidCharacter {
+                    position: 31,
+                    character: 'A',
+                },
+            ),
+        ];
+        for (invalid_certidao, error) in invalid_certidaos {
+            assert_eq!(brado::certidao::check(invalid_certidao), Err(error));
+        }
+    }
+
+    #[test]
+    fn certidao_compute_check_digits_1_valid_bases() {
+        let bases = [("219242015520231063042431158185", "36")];
+        for (base, check_digits) in bases {
+            assert_eq!(
+                brado::certidao::compute_check_digits(base),
+                Ok(String::from(check_digits)),
+            );
+        }
+
+        for _ in 0..1000 {
+            let certidao = brado::certidao::generate();
+            let (base, check_digits) = certidao.split_at(certidao.len() - 2);
+            assert_eq!(
+                brado::certidao::compute_check_digits(base),
+                Ok(String::from(check_digits)),
+            );
+        }
+    }
+
+    #[test]
+    fn certidao_compute_check_digits_2_invalid_bases() {
+        let invalid_bases = [
+            (
+                "2192420155202310630424311581",
+                ValidationError::InvalidLength {
+                    expected: 30,
+                    found: 28,
+                },
+            ),
+            (
+                "219242 01 55 2023 1 06304 243 1158185",
+                ValidationError::InvalidMask,
+            ),
+        ];
+        for (base, error) in invalid_bases {
+            assert_eq!(brado::certidao::compute_check_digits(base), Err(error));
+        }
+    }
+
     #[test]
     fn certidao_is_bare_1_valid_bare() {
         let documents = [
@@ -139,16 +229,80 @@ mod certidao_tests {
     #[test]
     fn certidao_mask_2_invalid_mask() {
         let documents = [
-            "219242 01 55 2023 1 06304 243 1158185-36", // Valid Certidão
-            "219242 01 55 2023 1 06304 243 1158185-35", // Invalid Certidão
-            "2192420155202310630424311581853",          // Invalid Certidão
-            "219242015520231063042431158185365",        // Invalid Certidão
+            (
+                "219242 01 55 2023 1 06304 243 1158185-36", // Valid Certidão
+                MaskError::AlreadyMasked {
+                    kind: DocKind::Certidao,
+                },
+            ),
+            (
+                "219242 01 55 2023 1 06304 243 1158185-35", // Invalid Certidão
+                MaskError::AlreadyMasked {
+                    kind: DocKind::Certidao,
+                },
+            ),
+            (
+                "2192420155202310630424311581853", // Invalid Certidão
+                MaskError::InvalidLength {
+                    kind: DocKind::Certidao,
+                    expected: 32,
+                    found: 31,
+                },
+            ),
+            (
+                "219242015520231063042431158185365", // Invalid Certidão
+                MaskError::InvalidLength {
+                    kind: DocKind::Certidao,
+                    expected: 32,
+                    found: 33,
+                },
+            ),
+            (
+                "2192420155202310630424311581853?", // Invalid Certidão
+                MaskError::InvalidCharacter {
+                    kind: DocKind::Certidao,
+                    position: 31,
+                    character: '?',
+                },
+            ),
+        ];
+        for (document, error) in documents {
+            assert_eq!(brado::certidao::mask(document), Err(error));
+        }
+    }
+
+    #[test]
+    fn certidao_newtype_1_valid() {
+        let documents = [
+            "21924201552023106304243115818536",
+            "219242 01 55 2023 1 06304 243 1158185-36",
         ];
         for document in documents {
-            let result = brado::certidao::mask(document);
+            let doc: brado::certidao::Certidao = document.parse().unwrap();
+            assert_eq!(doc.as_bare(), "21924201552023106304243115818536");
+            assert_eq!(
+                doc.to_masked(),
+                "219242 01 55 2023 1 06304 243 1158185-36"
+            );
             assert_eq!(
-                result,
-                Err("The given string cannot be masked as Certidão!"),
+                doc.to_string(),
+                "219242 01 55 2023 1 06304 243 1158185-36"
+            );
+            assert_eq!(brado::certidao::Certidao::try_from(document), Ok(doc));
+        }
+    }
+
+    #[test]
+    fn certidao_newtype_2_invalid() {
+        let documents = [
+            "21924201552023106304243115818535",
+            "219242 01 55 2023 1 06304 243 1158185-35",
+            "2192420155202310630424311581853",
+        ];
+        for document in documents {
+            assert_eq!(
+                document.parse::<brado::certidao::Certidao>(),
+                Err(brado::certidao::check(document).unwrap_err()),
             );
         }
     }
@@ -170,4 +324,203 @@ mod certidao_tests {
             assert_eq!(brado::certidao::is_masked(&certidao), true);
         }
     }
+
+    #[test]
+    fn certidao_generate_with_rng_1() {
+        for seed in 0..1000 {
+            let certidao = brado::certidao::generate_with_rng(
+                &mut StdRng::seed_from_u64(seed),
+            );
+            assert_eq!(brado::certidao::validate(&certidao), true);
+            assert_eq!(brado::certidao::is_bare(&certidao), true);
+            assert_eq!(
+                brado::certidao::generate_with_rng(&mut StdRng::seed_from_u64(
+                    seed
+                )),
+                certidao,
+            );
+        }
+    }
+
+    #[test]
+    fn certidao_generate_masked_with_rng_1() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut other = StdRng::seed_from_u64(42);
+        for _ in 0..1000 {
+            let certidao = brado::certidao::generate_masked_with_rng(&mut rng);
+            assert_eq!(brado::certidao::validate(&certidao), true);
+            assert_eq!(brado::certidao::is_masked(&certidao), true);
+            assert_eq!(
+                brado::certidao::generate_masked_with_rng(&mut other),
+                certidao
+            );
+        }
+    }
+
+    #[test]
+    fn certidao_check_3_invalid_structure() {
+        let invalid_certidoes = [
+            (
+                "21924201552023006304243115818536",
+                ValidationError::InvalidBookType { found: 0 },
+            ),
+            (
+                "219242 01 55 9023 1 06304 243 1158185-36",
+                ValidationError::InvalidYear { found: 9023 },
+            ),
+        ];
+        for (invalid_certidao, error) in invalid_certidoes {
+            assert_eq!(brado::certidao::check(invalid_certidao), Err(error));
+        }
+
+        let next_year = brado::common::current_year() + 1;
+        let base = format!("2192420155{}1063042431158185", next_year);
+        assert_eq!(
+            brado::certidao::compute_check_digits(&base),
+            Err(ValidationError::InvalidYear { found: next_year }),
+        );
+    }
+
+    #[test]
+    fn certidao_parse_1_valid_certidoes() {
+        let documents = [
+            ("21924201552023106304243115818536", CertidaoKind::Nascimento),
+            ("21924201552023206304243115818580", CertidaoKind::Casamento),
+            ("21924201552023406304243115818571", CertidaoKind::Obito),
+            ("21924201552023906304243115818519", CertidaoKind::Interdicao),
+        ];
+        for (document, kind) in documents {
+            let parts = brado::certidao::parse(document).unwrap();
+            assert_eq!(parts.cartorio, "219242");
+            assert_eq!(parts.acervo, "01");
+            assert_eq!(parts.servico, "55");
+            assert_eq!(parts.ano, 2023);
+            assert_eq!(parts.kind, kind);
+            assert_eq!(parts.livro, "06304");
+            assert_eq!(parts.folha, "243");
+            assert_eq!(parts.termo, "1158185");
+            assert_eq!(parts.is_rcpn(), true);
+        }
+
+        let parts =
+            brado::certidao::parse("219242 01 55 1999 4 00001 001 0000001-92")
+                .unwrap();
+        assert_eq!(parts.ano, 1999);
+        assert_eq!(parts.kind, CertidaoKind::Obito);
+        assert_eq!(parts.livro, "00001");
+        assert_eq!(parts.folha, "001");
+        assert_eq!(parts.termo, "0000001");
+        assert_eq!(parts.dv, "92");
+    }
+
+    #[test]
+    fn certidao_parse_2_invalid_certidoes() {
+        let invalid_certidoes = [
+            (
+                "21924201552023106304243115818535",
+                ValidationError::CheckDigitMismatch {
+                    expected: String::from("36"),
+                    found: String::from("35"),
+                },
+            ),
+            (
+                "21924201552023006304243115818536",
+                ValidationError::InvalidBookType { found: 0 },
+            ),
+        ];
+        for (invalid_certidao, error) in invalid_certidoes {
+            assert_eq!(brado::certidao::parse(invalid_certidao), Err(error));
+        }
+    }
+
+    #[test]
+    fn certidao_kind_1() {
+        for (i, kind) in CertidaoKind::ALL.iter().enumerate() {
+            assert_eq!(kind.digit(), i as u16 + 1);
+            assert_eq!(CertidaoKind::from_digit(kind.digit()), Some(*kind));
+        }
+        assert_eq!(CertidaoKind::from_digit(0), None);
+        assert_eq!(CertidaoKind::Nascimento.livro(), "A");
+        assert_eq!(CertidaoKind::Natimorto.livro(), "C Auxiliar");
+    }
+
+    #[test]
+    fn certidao_generate_2_structure() {
+        for _ in 0..1000 {
+            let certidao: brado::certidao::Certidao =
+                brado::certidao::generate().parse().unwrap();
+            let parts = certidao.parts();
+            assert_eq!(parts.ano <= brado::common::current_year(), true);
+            assert_eq!(certidao.kind(), parts.kind);
+        }
+    }
+
+    #[test]
+    fn certidao_generate_for_1() {
+        for kind in CertidaoKind::ALL {
+            for year in [1950, 2010, brado::common::current_year()] {
+                let certidao =
+                    brado::certidao::generate_for(*kind, year, "219242")
+                        .unwrap();
+                assert_eq!(brado::certidao::validate(&certidao), true);
+                assert_eq!(brado::certidao::is_bare(&certidao), true);
+
+                let parts = brado::certidao::parse(&certidao).unwrap();
+                assert_eq!(parts.cartorio, "219242");
+                assert_eq!(parts.acervo, "01");
+                assert_eq!(parts.servico, "55");
+                assert_eq!(parts.ano, year);
+                assert_eq!(parts.kind, *kind);
+            }
+        }
+    }
+
+    #[test]
+    fn certidao_generate_for_2_invalid() {
+        let next_year = brado::common::current_year() + 1;
+        let invalid = [
+            (
+                2020,
+                "21924",
+                ValidationError::InvalidLength {
+                    expected: 6,
+                    found: 5,
+                },
+            ),
+            (
+                2020,
+                "21924A",
+                ValidationError::InvalidCharacter {
+                    position: 5,
+                    character: 'A',
+                },
+            ),
+            (
+                next_year,
+                "219242",
+                ValidationError::InvalidYear { found: next_year },
+            ),
+        ];
+        for (year, cartorio, error) in invalid {
+            assert_eq!(
+                brado::certidao::generate_for(
+                    CertidaoKind::Nascimento,
+                    year,
+                    cartorio
+                ),
+                Err(error),
+            );
+        }
+    }
+
+    #[test]
+    fn certidao_generate_3_coherent_fields() {
+        for _ in 0..1000 {
+            let certidao = brado::certidao::generate();
+            let parts = brado::certidao::parse(&certidao).unwrap();
+            assert_eq!(parts.acervo, "01");
+            assert_eq!(parts.is_rcpn(), true);
+            assert_eq!(parts.ano >= 1900, true);
+        }
+    }
 }
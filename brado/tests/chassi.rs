@@ -0,0 +1,420 @@
+#[cfg(test)]
+mod chassi_tests {
+    use brado;
+    use brado::chassi::{Chassi, ChassiDocument, ManufacturerRegion};
+    use brado::common::{Document, MaskError, ValidationError};
+    use brado::docs::DocKind;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    const VALID_CHASSIS: [(&str, &str); 7] = [
+        ("9BWZZZ372VT004251", "9BW ZZZ372 VT004251"),
+        ("9BWSU19F98B302158", "9BW SU19F9 8B302158"),
+        ("9BGKS48U9DG123456", "9BG KS48U9 DG123456"),
+        ("93HGE6887EZ100001", "93H GE6887 EZ100001"),
+        ("94DBCAN1XEB123456", "94D BCAN1X EB123456"),
+        ("1M8GDM9AXKP042788", "1M8 GDM9AX KP042788"),
+        ("11111111111111111", "111 111111 11111111"),
+    ];
+
+    #[test]
+    fn chassi_validate_1_valid_chassis() {
+        for (bare, masked) in VALID_CHASSIS {
+            assert_eq!(brado::chassi::validate(bare), true);
+            assert_eq!(brado::chassi::validate(masked), true);
+        }
+    }
+
+    #[test]
+    fn chassi_validate_2_invalid_chassis() {
+        let invalid_chassis = [
+            "9BWZZZ373VT004251",
+            "9BWZZZ372VT00425",
+            "9BWZZZ372VT0042511",
+            "9BWZZZ372VT0O4251",
+            "9BWZZZ372VT0I4251",
+            "9BWZZZ372VT0Q4251",
+            "9bwzzz372vt004251",
+            "9BW ZZZ373 VT004251",
+            "9BW-ZZZ372-VT004251",
+            "9BWZ ZZ372 VT004251",
+            "9BW ZZZ372 VT004251 ",
+            "94DBCAN10EB123456",
+        ];
+        for invalid_chassi in invalid_chassis {
+            assert_eq!(brado::chassi::validate(invalid_chassi), false);
+        }
+    }
+
+    #[test]
+    fn chassi_check_1_valid_chassis() {
+        for (bare, masked) in VALID_CHASSIS {
+            assert_eq!(brado::chassi::check(bare), Ok(()));
+            assert_eq!(brado::chassi::check(masked), Ok(()));
+        }
+    }
+
+    #[test]
+    fn chassi_check_2_invalid_chassis() {
+        let invalid_chassis = [
+            (
+                "9BWZZZ373VT004251",
+                ValidationError::CheckDigitMismatch {
+                    expected: String::from("2"),
+                    found: String::from("3"),
+                },
+            ),
+            (
+                "94DBCAN10EB123456",
+                ValidationError::CheckDigitMismatch {
+                    expected: String::from("X"),
+                    found: String::from("0"),
+                },
+            ),
+            (
+                "9BWZZZ372VT00425",
+                ValidationError::InvalidLength {
+                    expected: 17,
+                    found: 16,
+                },
+            ),
+            (
+                "9BWZZZ372VT0Q4251",
+                ValidationError::InvalidCharacter {
+                    position: 12,
+                    character: 'Q',
+                },
+            ),
+            ("9BW-ZZZ372-VT004251", ValidationError::InvalidMask),
+        ];
+        for (invalid_chassi, error) in invalid_chassis {
+            assert_eq!(brado::chassi::check(invalid_chassi), Err(error));
+        }
+    }
+
+    #[test]
+    fn chassi_compute_check_digits_1_valid_bases() {
+        for (bare, _) in VALID_CHASSIS {
+            let base = format!("{}{}", &bare[..8], &bare[9..]);
+            assert_eq!(
+                brado::chassi::compute_check_digits(&base),
+                Ok(String::from(&bare[8..9])),
+            );
+        }
+    }
+
+    #[test]
+    fn chassi_compute_check_digits_2_invalid_bases() {
+        assert_eq!(
+            brado::chassi::compute_check_digits("9BWZZZ37VT00425"),
+            Err(ValidationError::InvalidLength {
+                expected: 16,
+                found: 15,
+            }),
+        );
+        assert_eq!(
+            brado::chassi::compute_check_digits("9BWZZZ37VT0O4251"),
+            Err(ValidationError::InvalidCharacter {
+                position: 11,
+                character: 'O',
+            }),
+        );
+    }
+
+    #[test]
+    fn chassi_is_bare_1_valid_bare() {
+        for (bare, _) in VALID_CHASSIS {
+            assert_eq!(brado::chassi::is_bare(bare), true);
+        }
+        assert_eq!(brado::chassi::is_bare("9BWZZZ373VT004251"), true);
+    }
+
+    #[test]
+    fn chassi_is_bare_2_invalid_bare() {
+        let invalid_bares = [
+            "9BW ZZZ372 VT004251",
+            "9BWZZZ372VT00425",
+            "9BWZZZ372VT0O4251",
+        ];
+        for invalid_bare in invalid_bares {
+            assert_eq!(brado::chassi::is_bare(invalid_bare), false);
+        }
+    }
+
+    #[test]
+    fn chassi_is_masked_1_valid_masked() {
+        for (_, masked) in VALID_CHASSIS {
+            assert_eq!(brado::chassi::is_masked(masked), true);
+        }
+        assert_eq!(brado::chassi::is_masked("9BW ZZZ373 VT004251"), true);
+    }
+
+    #[test]
+    fn chassi_is_masked_2_invalid_masked() {
+        let invalid_masked = [
+            "9BWZZZ372VT004251",
+            "9BW-ZZZ372-VT004251",
+            "9BWZ ZZ372 VT004251",
+            "9BW ZZZ372VT004251",
+            "9BW ZZZ372 VT00425",
+        ];
+        for invalid_masked in invalid_masked {
+            assert_eq!(brado::chassi::is_masked(invalid_masked), false);
+        }
+    }
+
+    #[test]
+    fn chassi_mask_1_valid_mask() {
+        for (bare, masked) in VALID_CHASSIS {
+            assert_eq!(brado::chassi::mask(bare), Ok(String::from(masked)));
+            assert_eq!(brado::chassi::unmask(masked), bare);
+        }
+    }
+
+    #[test]
+    fn chassi_mask_2_invalid_mask() {
+        assert_eq!(
+            brado::chassi::mask("9BW ZZZ372 VT004251"),
+            Err(MaskError::AlreadyMasked {
+                kind: DocKind::Chassi,
+            }),
+        );
+        assert_eq!(
+            brado::chassi::mask("9BWZZZ372VT00425"),
+            Err(MaskError::InvalidLength {
+                kind: DocKind::Chassi,
+                expected: 17,
+                found: 16,
+            }),
+        );
+    }
+
+    #[test]
+    fn chassi_wmi_and_region_1() {
+        let expected = [
+            ("9BW", ManufacturerRegion::SouthAmerica, true),
+            ("9BW", ManufacturerRegion::SouthAmerica, true),
+            ("9BG", ManufacturerRegion::SouthAmerica, true),
+            ("93H", ManufacturerRegion::SouthAmerica, true),
+            ("94D", ManufacturerRegion::SouthAmerica, true),
+            ("1M8", ManufacturerRegion::NorthAmerica, false),
+            ("111", ManufacturerRegion::NorthAmerica, false),
+        ];
+        for ((bare, masked), (wmi, region, brazilian)) in
+            VALID_CHASSIS.iter().zip(expected)
+        {
+            assert_eq!(brado::chassi::wmi(bare), Some(String::from(wmi)));
+            assert_eq!(brado::chassi::region(masked), Some(region));
+            assert_eq!(brado::chassi::is_brazilian(bare), Some(brazilian));
+        }
+        assert_eq!(brado::chassi::wmi("9BWZZZ372VT0O4251"), None);
+        assert_eq!(brado::chassi::region("9BWZZZ372VT0O4251"), None);
+        assert_eq!(brado::chassi::is_brazilian("9BWZZZ372VT0O4251"), None);
+    }
+
+    #[test]
+    fn chassi_structure_1_without_check_digit() {
+        let chassis = [
+            ("9BWZZZ377VT004251", "9BW", ManufacturerRegion::SouthAmerica),
+            ("9BD17164G95302640", "9BD", ManufacturerRegion::SouthAmerica),
+            (
+                "9BW ZZZ373 VT004251",
+                "9BW",
+                ManufacturerRegion::SouthAmerica,
+            ),
+        ];
+        for (chassi, wmi, region) in chassis {
+            assert_eq!(brado::chassi::validate(chassi), false);
+            assert_eq!(brado::chassi::validate_structure(chassi), true);
+            assert_eq!(brado::chassi::check_structure(chassi), Ok(()));
+            assert_eq!(brado::chassi::wmi(chassi), Some(String::from(wmi)));
+            assert_eq!(brado::chassi::region(chassi), Some(region));
+            assert_eq!(brado::chassi::is_brazilian(chassi), Some(true));
+            assert!(brado::chassi::model_year(chassi).is_some());
+        }
+        assert_eq!(brado::chassi::model_year("9BD17164G95302640"), Some(2009));
+        for (bare, masked) in VALID_CHASSIS {
+            assert_eq!(brado::chassi::validate_structure(bare), true);
+            assert_eq!(brado::chassi::validate_structure(masked), true);
+        }
+    }
+
+    #[test]
+    fn chassi_structure_2_invalid() {
+        let invalid_chassis = [
+            (
+                "9BWZZZ372VT0O4251",
+                ValidationError::InvalidCharacter {
+                    position: 12,
+                    character: 'O',
+                },
+            ),
+            (
+                "9BWZZZ372VT00425",
+                ValidationError::InvalidLength {
+                    expected: 17,
+                    found: 16,
+                },
+            ),
+            ("9BWZ ZZ372 VT004251", ValidationError::InvalidMask),
+        ];
+        for (invalid_chassi, error) in invalid_chassis {
+            assert_eq!(
+                brado::chassi::check_structure(invalid_chassi),
+                Err(error)
+            );
+            assert_eq!(
+                brado::chassi::validate_structure(invalid_chassi),
+                false
+            );
+        }
+    }
+
+    #[test]
+    fn chassi_region_2_from_char() {
+        let regions = [
+            ('A', Some(ManufacturerRegion::Africa)),
+            ('H', Some(ManufacturerRegion::Africa)),
+            ('J', Some(ManufacturerRegion::Asia)),
+            ('R', Some(ManufacturerRegion::Asia)),
+            ('S', Some(ManufacturerRegion::Europe)),
+            ('Z', Some(ManufacturerRegion::Europe)),
+            ('5', Some(ManufacturerRegion::NorthAmerica)),
+            ('6', Some(ManufacturerRegion::Oceania)),
+            ('8', Some(ManufacturerRegion::SouthAmerica)),
+            ('0', Some(ManufacturerRegion::SouthAmerica)),
+            ('I', None),
+            ('O', None),
+            ('a', None),
+        ];
+        for (c, region) in regions {
+            assert_eq!(ManufacturerRegion::from_char(c), region);
+        }
+    }
+
+    #[test]
+    fn chassi_model_year_1() {
+        assert_eq!(brado::chassi::model_year("9BWSU19F98B302158"), Some(2008));
+        assert_eq!(brado::chassi::model_year("9BGKS48U9DG123456"), Some(2013));
+        assert_eq!(brado::chassi::model_year("93HGE6887EZ100001"), Some(2014));
+        assert_eq!(brado::chassi::model_year("1M8GDM9AXKP042788"), Some(2019));
+        assert_eq!(brado::chassi::model_year("9BWZZZ375ZT004251"), None);
+        assert_eq!(brado::chassi::model_year("9BWZZZ372VT0O4251"), None);
+    }
+
+    #[test]
+    fn chassi_newtype_1_valid() {
+        for (bare, masked) in VALID_CHASSIS {
+            let chassi: Chassi = masked.parse().unwrap();
+            assert_eq!(chassi.as_bare(), bare);
+            assert_eq!(chassi.to_masked(), masked);
+            assert_eq!(chassi.to_string(), masked);
+            assert_eq!(chassi.wmi(), &bare[..3]);
+            assert_eq!(Chassi::try_from(bare), Ok(chassi));
+        }
+    }
+
+    #[test]
+    fn chassi_newtype_2_invalid() {
+        assert_eq!(
+            "9BWZZZ373VT004251".parse::<Chassi>(),
+            Err(ValidationError::CheckDigitMismatch {
+                expected: String::from("2"),
+                found: String::from("3"),
+            }),
+        );
+        assert!(Chassi::try_from("9BWZZZ372VT00425").is_err());
+    }
+
+    #[test]
+    fn chassi_document_1() {
+        assert_eq!(ChassiDocument.kind(), DocKind::Chassi);
+        assert_eq!(ChassiDocument.name(), "Chassi");
+        assert_eq!(ChassiDocument.bare_size(), 17);
+        assert_eq!(ChassiDocument.validate("9BW ZZZ372 VT004251"), true);
+        assert_eq!(brado::docs::is_chassi("9BWZZZ372VT004251"), true);
+        assert_eq!(
+            brado::docs::identify("9BWZZZ372VT004251"),
+            vec![DocKind::Chassi],
+        );
+    }
+
+    #[test]
+    fn chassi_generate_1() {
+        for _ in 0..1000 {
+            let chassi = brado::chassi::generate();
+            assert_eq!(brado::chassi::validate(&chassi), true);
+            assert_eq!(brado::chassi::is_bare(&chassi), true);
+            assert_eq!(brado::chassi::is_brazilian(&chassi), Some(true));
+            assert!(brado::chassi::model_year(&chassi).is_some());
+        }
+    }
+
+    #[test]
+    fn chassi_generate_masked_1() {
+        for _ in 0..1000 {
+            let chassi = brado::chassi::generate_masked();
+            assert_eq!(brado::chassi::validate(&chassi), true);
+            assert_eq!(brado::chassi::is_masked(&chassi), true);
+        }
+    }
+
+    #[test]
+    fn chassi_generate_with_rng_1() {
+        for seed in 0..100 {
+            let chassi = brado::chassi::generate_with_rng(
+                &mut StdRng::seed_from_u64(seed),
+            );
+            assert_eq!(brado::chassi::validate(&chassi), true);
+            assert_eq!(
+                brado::chassi::generate_with_rng(&mut StdRng::seed_from_u64(
+                    seed
+                )),
+                chassi,
+            );
+        }
+    }
+
+    #[test]
+    fn chassi_generate_masked_with_rng_1() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut other = StdRng::seed_from_u64(42);
+        for _ in 0..100 {
+            let chassi = brado::chassi::generate_masked_with_rng(&mut rng);
+            assert_eq!(brado::chassi::is_masked(&chassi), true);
+            assert_eq!(
+                brado::chassi::generate_masked_with_rng(&mut other),
+                chassi,
+            );
+        }
+    }
+
+    #[test]
+    fn chassi_generate_for_year_1() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let last_year = brado::common::current_year() + 1;
+        for year in (last_year - 29)..=last_year {
+            let chassi =
+                brado::chassi::generate_for_year_with_rng(year, &mut rng)
+                    .unwrap();
+            assert_eq!(brado::chassi::validate(&chassi), true);
+            assert_eq!(brado::chassi::model_year(&chassi), Some(year));
+
+            let chassi = brado::chassi::generate_for_year(year).unwrap();
+            assert_eq!(brado::chassi::model_year(&chassi), Some(year));
+        }
+        let chassi = brado::chassi::generate_for_year(1997).unwrap();
+        assert_eq!(&chassi[9..10], "V");
+    }
+
+    #[test]
+    fn chassi_generate_for_year_2_invalid() {
+        let last_year = brado::common::current_year() + 1;
+        for year in [0, 1979, last_year + 1] {
+            assert_eq!(
+                brado::chassi::generate_for_year(year),
+                Err(ValidationError::InvalidYear { found: year }),
+            );
+        }
+    }
+}
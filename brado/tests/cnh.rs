@@ -1,6 +1,10 @@
 #[cfg(test)]
 mod cnh_tests {
     use brado;
+    use brado::common::{MaskError, ValidationError};
+    use brado::docs::DocKind;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
 
     #[test]
     fn cnh_validate_1_valid_cnhs() {
@@ -40,6 +44,90 @@ mod cnh_tests {
         }
     }
 
+    #[test]
+    fn cnh_check_1_valid_cnhs() {
+        let valid_cnhs = ["84718735264", "847 187 352 64"];
+        for valid_cnh in valid_cnhs {
+            assert_eq!(brado::cnh::check(valid_cnh), Ok(()));
+        }
+    }
+
+    #[test]
+    fn cnh_check_2_invalid_cnhs() {
+        let invalid_cnhs = [
+            (
+                "8471873526",
+                ValidationError::InvalidLength {
+                    expected: 11,
+                    found: 10,
+                },
+            ),
+            (
+                "84718735265",
+                ValidationError::CheckDigitMismatch {
+                    expected: String::from("64"),
+                    found: String::from("65"),
+                },
+            ),
+            ("847:187 352 64", ValidationError::InvalidMask),
+            (
+                "A4718735264",
+                ValidationError::InvalidCharacter {
+                    position: 0,
+                    character: 'A',
+                },
+            ),
+            ("11111111111", ValidationError::RepeatedDigits),
+        ];
+        for (invalid_cnh, error) in invalid_cnhs {
+            assert_eq!(brado::cnh::check(invalid_cnh), Err(error));
+        }
+    }
+
+    #[test]
+    fn cnh_compute_check_digits_1_valid_bases() {
+        let bases = [("847187352", "64")];
+        for (base, check_digits) in bases {
+            assert_eq!(
+                brado::cnh::compute_check_digits(base),
+                Ok(String::from(check_digits)),
+            );
+        }
+
+        for _ in 0..1000 {
+            let cnh = brado::cnh::generate();
+            let (base, check_digits) = cnh.split_at(cnh.len() - 2);
+            assert_eq!(
+                brado::cnh::compute_check_digits(base),
+                Ok(String::from(check_digits)),
+            );
+        }
+    }
+
+    #[test]
+    fn cnh_compute_check_digits_2_invalid_bases() {
+        let invalid_bases = [
+            (
+                "84718735",
+                ValidationError::InvalidLength {
+                    expected: 9,
+                    found: 8,
+                },
+            ),
+            (
+                "847 18735",
+                ValidationError::InvalidCharacter {
+                    position: 3,
+                    character: ' ',
+                },
+            ),
+            ("000000000", ValidationError::RepeatedDigits),
+        ];
+        for (base, error) in invalid_bases {
+            assert_eq!(brado::cnh::compute_check_digits(base), Err(error));
+        }
+    }
+
     #[test]
     fn cnh_is_bare_1_valid_bare() {
         let documents = [
@@ -118,16 +206,63 @@ mod cnh_tests {
     #[test]
     fn cnh_mask_2_invalid_mask() {
         let documents = [
-            "847 187 352 64", // Valid CNH
-            "847 187 352 63", // Invalid CNH
-            "8471873526",     // Invalid CNH
-            "847187352643",   // Invalid CNH
+            (
+                "847 187 352 64", // Valid CNH
+                MaskError::AlreadyMasked { kind: DocKind::Cnh },
+            ),
+            (
+                "847 187 352 63", // Invalid CNH
+                MaskError::AlreadyMasked { kind: DocKind::Cnh },
+            ),
+            (
+                "8471873526", // Invalid CNH
+                MaskError::InvalidLength {
+                    kind: DocKind::Cnh,
+                    expected: 11,
+                    found: 10,
+                },
+            ),
+            (
+                "847187352643", // Invalid CNH
+                MaskError::InvalidLength {
+                    kind: DocKind::Cnh,
+                    expected: 11,
+                    found: 12,
+                },
+            ),
+            (
+                "8471873526?", // Invalid CNH
+                MaskError::InvalidCharacter {
+                    kind: DocKind::Cnh,
+                    position: 10,
+                    character: '?',
+                },
+            ),
         ];
+        for (document, error) in documents {
+            assert_eq!(brado::cnh::mask(document), Err(error));
+        }
+    }
+
+    #[test]
+    fn cnh_newtype_1_valid() {
+        let documents = ["84718735264", "847 187 352 64"];
+        for document in documents {
+            let doc: brado::cnh::Cnh = document.parse().unwrap();
+            assert_eq!(doc.as_bare(), "84718735264");
+            assert_eq!(doc.to_masked(), "847 187 352 64");
+            assert_eq!(doc.to_string(), "847 187 352 64");
+            assert_eq!(brado::cnh::Cnh::try_from(document), Ok(doc));
+        }
+    }
+
+    #[test]
+    fn cnh_newtype_2_invalid() {
+        let documents = ["84718735265", "847 187 352 65", "8471873526"];
         for document in documents {
-            let result = brado::cnh::mask(document);
             assert_eq!(
-                result,
-                Err("The given string cannot be masked as CNH!"),
+                document.parse::<brado::cnh::Cnh>(),
+                Err(brado::cnh::check(document).unwrap_err()),
             );
         }
     }
@@ -149,4 +284,30 @@ mod cnh_tests {
             assert_eq!(brado::cnh::is_masked(&cnh), true);
         }
     }
+
+    #[test]
+    fn cnh_generate_with_rng_1() {
+        for seed in 0..1000 {
+            let cnh =
+                brado::cnh::generate_with_rng(&mut StdRng::seed_from_u64(seed));
+            assert_eq!(brado::cnh::validate(&cnh), true);
+            assert_eq!(brado::cnh::is_bare(&cnh), true);
+            assert_eq!(
+                brado::cnh::generate_with_rng(&mut StdRng::seed_from_u64(seed)),
+                cnh,
+            );
+        }
+    }
+
+    #[test]
+    fn cnh_generate_masked_with_rng_1() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut other = StdRng::seed_from_u64(42);
+        for _ in 0..1000 {
+            let cnh = brado::cnh::generate_masked_with_rng(&mut rng);
+            assert_eq!(brado::cnh::validate(&cnh), true);
+            assert_eq!(brado::cnh::is_masked(&cnh), true);
+            assert_eq!(brado::cnh::generate_masked_with_rng(&mut other), cnh);
+        }
+    }
 }
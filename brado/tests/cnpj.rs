@@ -1,6 +1,11 @@
 #[cfg(test)]
 mod cnpj_tests {
     use brado;
+    use brado::cnpj::CnpjFormat;
+    use brado::common::{MaskError, ValidationError};
+    use brado::docs::DocKind;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
 
     #[test]
     fn cnpj_validate_1_valid_cnpjs() {
@@ -43,6 +48,97 @@ mod cnpj_tests {
         }
     }
 
+    #[test]
+    fn cnpj_check_1_valid_cnpjs() {
+        let valid_cnpjs = ["05200851000100", "A5.200.851/0001-23"];
+        for valid_cnpj in valid_cnpjs {
+            assert_eq!(brado::cnpj::check(valid_cnpj), Ok(()));
+        }
+    }
+
+    #[test]
+    fn cnpj_check_2_invalid_cnpjs() {
+        let invalid_cnpjs = [
+            (
+                "0520085100010",
+                ValidationError::InvalidLength {
+                    expected: 14,
+                    found: 13,
+                },
+            ),
+            (
+                "05200851000101",
+                ValidationError::CheckDigitMismatch {
+                    expected: String::from("00"),
+                    found: String::from("01"),
+                },
+            ),
+            (
+                "052008510001A1",
+                ValidationError::InvalidCharacter {
+                    position: 12,
+                    character: 'A',
+                },
+            ),
+            ("05.200.851-0001/00", ValidationError::InvalidMask),
+            (
+                "05200851/00100",
+                ValidationError::InvalidCharacter {
+                    position: 8,
+                    character: '/',
+                },
+            ),
+            ("00000000000000", ValidationError::RepeatedDigits),
+        ];
+        for (invalid_cnpj, error) in invalid_cnpjs {
+            assert_eq!(brado::cnpj::check(invalid_cnpj), Err(error));
+        }
+    }
+
+    #[test]
+    fn cnpj_compute_check_digits_1_valid_bases() {
+        let bases = [("052008510001", "00"), ("12ABC34501DE", "35")];
+        for (base, check_digits) in bases {
+            assert_eq!(
+                brado::cnpj::compute_check_digits(base),
+                Ok(String::from(check_digits)),
+            );
+        }
+
+        for _ in 0..1000 {
+            let cnpj = brado::cnpj::generate();
+            let (base, check_digits) = cnpj.split_at(cnpj.len() - 2);
+            assert_eq!(
+                brado::cnpj::compute_check_digits(base),
+                Ok(String::from(check_digits)),
+            );
+        }
+    }
+
+    #[test]
+    fn cnpj_compute_check_digits_2_invalid_bases() {
+        let invalid_bases = [
+            (
+                "05200851",
+                ValidationError::InvalidLength {
+                    expected: 12,
+                    found: 8,
+                },
+            ),
+            (
+                "05200851000-",
+                ValidationError::InvalidCharacter {
+                    position: 11,
+                    character: '-',
+                },
+            ),
+            ("000000000000", ValidationError::RepeatedDigits),
+        ];
+        for (base, error) in invalid_bases {
+            assert_eq!(brado::cnpj::compute_check_digits(base), Err(error));
+        }
+    }
+
     #[test]
     fn cnpj_is_bare_1_valid_bare() {
         let documents = [
@@ -102,6 +198,9 @@ mod cnpj_tests {
             "05.200:851/0001-00",  // Invalid CNPJ
             "05.200.851:0001-00",  // Invalid CNPJ
             "05.200.851/0001:00",  // Invalid CNPJ
+            "0",                   // Invalid CNPJ
+            "",                    // Invalid CNPJ
+            "05.200.851/0001-0É",  // Invalid CNPJ
         ];
         for document in documents {
             assert_eq!(brado::cnpj::is_masked(document), false);
@@ -125,16 +224,68 @@ mod cnpj_tests {
     #[test]
     fn cnpj_mask_2_invalid_mask() {
         let documents = [
-            "05.200.851/0001-00", // Valid CNPJ
-            "05.200.851/0001-01", // Invalid CNPJ
-            "0520085100010",      // Invalid CNPJ
-            "052008510001001",    // Invalid CNPJ
+            (
+                "05.200.851/0001-00", // Valid CNPJ
+                MaskError::AlreadyMasked {
+                    kind: DocKind::Cnpj,
+                },
+            ),
+            (
+                "05.200.851/0001-01", // Invalid CNPJ
+                MaskError::AlreadyMasked {
+                    kind: DocKind::Cnpj,
+                },
+            ),
+            (
+                "0520085100010", // Invalid CNPJ
+                MaskError::InvalidLength {
+                    kind: DocKind::Cnpj,
+                    expected: 14,
+                    found: 13,
+                },
+            ),
+            (
+                "052008510001001", // Invalid CNPJ
+                MaskError::InvalidLength {
+                    kind: DocKind::Cnpj,
+                    expected: 14,
+                    found: 15,
+                },
+            ),
+            (
+                "0520085100010-", // Invalid CNPJ
+                MaskError::InvalidCharacter {
+                    kind: DocKind::Cnpj,
+                    position: 13,
+                    character: '-',
+                },
+            ),
         ];
+        for (document, error) in documents {
+            assert_eq!(brado::cnpj::mask(document), Err(error));
+        }
+    }
+
+    #[test]
+    fn cnpj_newtype_1_valid() {
+        let documents = ["05200851000100", "05.200.851/0001-00"];
+        for document in documents {
+            let doc: brado::cnpj::Cnpj = document.parse().unwrap();
+            assert_eq!(doc.as_bare(), "05200851000100");
+            assert_eq!(doc.to_masked(), "05.200.851/0001-00");
+            assert_eq!(doc.to_string(), "05.200.851/0001-00");
+            assert_eq!(brado::cnpj::Cnpj::try_from(document), Ok(doc));
+        }
+    }
+
+    #[test]
+    fn cnpj_newtype_2_invalid() {
+        let documents =
+            ["05200851000101", "05.200.851/0001-01", "0520085100010"];
         for document in documents {
-            let result = brado::cnpj::mask(document);
             assert_eq!(
-                result,
-                Err("The given string cannot be masked as CNPJ!"),
+                document.parse::<brado::cnpj::Cnpj>(),
+                Err(brado::cnpj::check(document).unwrap_err()),
             );
         }
     }
@@ -156,4 +307,312 @@ mod cnpj_tests {
             assert_eq!(brado::cnpj::is_masked(&cnpj), true);
         }
     }
+
+    #[test]
+    fn cnpj_generate_with_rng_1() {
+        for seed in 0..1000 {
+            let cnpj = brado::cnpj::generate_with_rng(
+                &mut StdRng::seed_from_u64(seed),
+            );
+            assert_eq!(brado::cnpj::validate(&cnpj), true);
+            assert_eq!(brado::cnpj::is_bare(&cnpj), true);
+            assert_eq!(
+                brado::cnpj::generate_with_rng(&mut StdRng::seed_from_u64(
+                    seed
+                )),
+                cnpj,
+            );
+        }
+    }
+
+    #[test]
+    fn cnpj_generate_masked_with_rng_1() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut other = StdRng::seed_from_u64(42);
+        for _ in 0..1000 {
+            let cnpj = brado::cnpj::generate_masked_with_rng(&mut rng);
+            assert_eq!(brado::cnpj::validate(&cnpj), true);
+            assert_eq!(brado::cnpj::is_masked(&cnpj), true);
+            assert_eq!(brado::cnpj::generate_masked_with_rng(&mut other), cnpj);
+        }
+    }
+
+    #[test]
+    fn cnpj_parse_1_valid_cnpjs() {
+        let documents = [
+            ("05200851000100", "05200851", "0001", "00"),
+            ("05.200.851/0001-00", "05200851", "0001", "00"),
+            ("05.200.851/0002-82", "05200851", "0002", "82"),
+            ("12.ABC.345/01DE-35", "12ABC345", "01DE", "35"),
+        ];
+        for (document, raiz, ordem, dv) in documents {
+            let parts = brado::cnpj::parse(document).unwrap();
+            assert_eq!(parts.raiz, raiz);
+            assert_eq!(parts.ordem, ordem);
+            assert_eq!(parts.dv, dv);
+        }
+    }
+
+    #[test]
+    fn cnpj_parse_2_invalid_cnpjs() {
+        let invalid_cnpjs = [
+            (
+                "05200851000101",
+                ValidationError::CheckDigitMismatch {
+                    expected: String::from("00"),
+                    found: String::from("01"),
+                },
+            ),
+            (
+                "0520085100010",
+                ValidationError::InvalidLength {
+                    expected: 14,
+                    found: 13,
+                },
+            ),
+        ];
+        for (invalid_cnpj, error) in invalid_cnpjs {
+            assert_eq!(brado::cnpj::parse(invalid_cnpj), Err(error));
+        }
+    }
+
+    #[test]
+    fn cnpj_is_matriz_1() {
+        let documents = [
+            ("05200851000100", true),
+            ("05.200.851/0001-00", true),
+            ("12ABC345000188", true),
+            ("05.200.851/0002-82", false),
+            ("12.ABC.345/01DE-35", false),
+            ("05200851000101", false),
+        ];
+        for (document, is_matriz) in documents {
+            assert_eq!(brado::cnpj::is_matriz(document), is_matriz);
+        }
+    }
+
+    #[test]
+    fn cnpj_branch_1_valid_branches() {
+        let branches = [
+            ("05200851", "0001", "05200851000100"),
+            ("05200851", "0002", "05200851000282"),
+            ("12ABC345", "01DE", "12ABC34501DE35"),
+        ];
+        for (raiz, ordem, cnpj) in branches {
+            assert_eq!(
+                brado::cnpj::branch(raiz, ordem),
+                Ok(String::from(cnpj))
+            );
+        }
+
+        for _ in 0..1000 {
+            let cnpj = brado::cnpj::generate();
+            let parts = brado::cnpj::parse(&cnpj).unwrap();
+            for ordem in ["0001", "0002", "0153", "A1B2"] {
+                let filial = brado::cnpj::branch(&parts.raiz, ordem).unwrap();
+                assert_eq!(brado::cnpj::validate(&filial), true);
+                let filial_parts = brado::cnpj::parse(&filial).unwrap();
+                assert_eq!(filial_parts.raiz, parts.raiz);
+                assert_eq!(filial_parts.ordem, ordem);
+            }
+        }
+    }
+
+    #[test]
+    fn cnpj_branch_2_invalid_branches() {
+        let invalid_branches = [
+            (
+                "0520085",
+                "0001",
+                ValidationError::InvalidLength {
+                    expected: 8,
+                    found: 7,
+                },
+            ),
+            (
+                "05200851",
+                "00001",
+                ValidationError::InvalidLength {
+                    expected: 4,
+                    found: 5,
+                },
+            ),
+            (
+                "05200851",
+                "00-1",
+                ValidationError::InvalidCharacter {
+                    position: 2,
+                    character: '-',
+                },
+            ),
+            (
+                "05200851",
+                "0000",
+                ValidationError::InvalidPrefix {
+                    found: String::from("0000"),
+                },
+            ),
+        ];
+        for (raiz, ordem, error) in invalid_branches {
+            assert_eq!(brado::cnpj::branch(raiz, ordem), Err(error));
+        }
+    }
+
+    #[test]
+    fn cnpj_newtype_3_branch() {
+        let matriz: brado::cnpj::Cnpj = "05.200.851/0001-00".parse().unwrap();
+        assert_eq!(matriz.is_matriz(), true);
+
+        let filial = matriz.branch("0002").unwrap();
+        assert_eq!(filial.as_bare(), "05200851000282");
+        assert_eq!(filial.is_matriz(), false);
+        assert_eq!(filial.parts().raiz, matriz.parts().raiz);
+        assert_eq!(filial.parts().ordem, "0002");
+        assert!(matriz.branch("0000").is_err());
+    }
+
+    #[test]
+    fn cnpj_detect_format_1() {
+        let documents = [
+            ("05200851000100", Some(CnpjFormat::Numeric)),
+            ("05.200.851/0001-00", Some(CnpjFormat::Numeric)),
+            ("12ABC34501DE35", Some(CnpjFormat::Alphanumeric)),
+            ("12.ABC.345/01DE-35", Some(CnpjFormat::Alphanumeric)),
+            ("05200851000101", None),
+            ("12ABC34501DE36", None),
+        ];
+        for (document, format) in documents {
+            assert_eq!(brado::cnpj::detect_format(document), format);
+        }
+    }
+
+    #[test]
+    fn cnpj_generate_numeric_1() {
+        for _ in 0..1000 {
+            let cnpj = brado::cnpj::generate_numeric();
+            assert_eq!(brado::cnpj::validate(&cnpj), true);
+            assert_eq!(brado::cnpj::is_bare(&cnpj), true);
+            assert_eq!(
+                brado::cnpj::detect_format(&cnpj),
+                Some(CnpjFormat::Numeric)
+            );
+        }
+    }
+
+    #[test]
+    fn cnpj_generate_alphanumeric_1() {
+        for _ in 0..1000 {
+            let cnpj = brado::cnpj::generate_alphanumeric();
+            assert_eq!(brado::cnpj::validate(&cnpj), true);
+            assert_eq!(brado::cnpj::is_bare(&cnpj), true);
+            let cnpj: brado::cnpj::Cnpj = cnpj.parse().unwrap();
+            assert_eq!(cnpj.format(), CnpjFormat::Alphanumeric);
+        }
+    }
+
+    #[test]
+    fn cnpj_generate_2_alphabet() {
+        let mut symbols: Vec<char> = (0..1000)
+            .flat_map(|_| {
+                brado::cnpj::generate()
+                    .chars()
+                    .take(12)
+                    .collect::<Vec<char>>()
+            })
+            .collect();
+        symbols.sort();
+        symbols.dedup();
+        let alphabet: Vec<char> = ('0'..='9').chain('A'..='Z').collect();
+        assert_eq!(symbols, alphabet);
+    }
+
+    #[test]
+    fn cnpj_check_3_strict_alphabet() {
+        let invalid_cnpjs = [
+            (
+                "12abc34501de35",
+                ValidationError::InvalidCharacter {
+                    position: 2,
+                    character: 'a',
+                },
+            ),
+            (
+                "05200851@00100",
+                ValidationError::InvalidCharacter {
+                    position: 8,
+                    character: '@',
+                },
+            ),
+            (
+                "05200851[00100",
+                ValidationError::InvalidCharacter {
+                    position: 8,
+                    character: '[',
+                },
+            ),
+            (
+                "05200851É00100",
+                ValidationError::InvalidCharacter {
+                    position: 8,
+                    character: 'É',
+                },
+            ),
+            (
+                "0520085100010A",
+                ValidationError::InvalidCharacter {
+                    position: 13,
+                    character: 'A',
+                },
+            ),
+            ("05.200.851/0001-A0", ValidationError::InvalidMask),
+            ("05.200.851:0001-00", ValidationError::InvalidMask),
+        ];
+        for (invalid_cnpj, error) in invalid_cnpjs {
+            assert_eq!(brado::cnpj::check(invalid_cnpj), Err(error));
+        }
+    }
+
+    #[test]
+    fn cnpj_check_case_insensitive_1() {
+        let valid_cnpjs = [
+            "05200851000100",
+            "12ABC34501DE35",
+            "12abc34501de35",
+            "12.abc.345/01De-35",
+        ];
+        for valid_cnpj in valid_cnpjs {
+            assert_eq!(brado::cnpj::check_case_insensitive(valid_cnpj), Ok(()));
+            assert_eq!(
+                brado::cnpj::validate_case_insensitive(valid_cnpj),
+                true
+            );
+        }
+
+        let invalid_cnpjs = [
+            (
+                "12abc34501de36",
+                ValidationError::CheckDigitMismatch {
+                    expected: String::from("35"),
+                    found: String::from("36"),
+                },
+            ),
+            (
+                "12abç34501de35",
+                ValidationError::InvalidCharacter {
+                    position: 4,
+                    character: 'ç',
+                },
+            ),
+        ];
+        for (invalid_cnpj, error) in invalid_cnpjs {
+            assert_eq!(
+                brado::cnpj::check_case_insensitive(invalid_cnpj),
+                Err(error)
+            );
+            assert_eq!(
+                brado::cnpj::validate_case_insensitive(invalid_cnpj),
+                false
+            );
+        }
+    }
 }
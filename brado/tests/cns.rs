@@ -1,6 +1,11 @@
 #[cfg(test)]
 mod cns_tests {
     use brado;
+    use brado::cns::{Cns, CnsKind};
+    use brado::common::{MaskError, ValidationError};
+    use brado::docs::DocKind;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
 
     #[test]
     fn cns_validate_1_valid_cnss() {
@@ -37,6 +42,109 @@ mod cns_tests {
         }
     }
 
+    #[test]
+    fn cns_check_1_valid_cnss() {
+        let valid_cnss = ["144082627260004", "812 2973 4650 0000"];
+        for valid_cns in valid_cnss {
+            assert_eq!(brado::cns::check(valid_cns), Ok(()));
+        }
+    }
+
+    #[test]
+    fn cns_check_2_invalid_cnss() {
+        let invalid_cnss = [
+            (
+                "14408262726000",
+                ValidationError::InvalidLength {
+                    expected: 15,
+                    found: 14,
+                },
+            ),
+            (
+                "144082627260005",
+                ValidationError::CheckDigitMismatch {
+                    expected: String::from("0004"),
+                    found: String::from("0005"),
+                },
+            ),
+            ("812297346500001", ValidationError::InvalidChecksum),
+            (
+                "344082627260004",
+                ValidationError::InvalidFirstDigit { found: 3 },
+            ),
+            ("144:0826 2726 0004", ValidationError::InvalidMask),
+            (
+                "14408262726000A",
+                ValidationError::InvalidCharacter {
+                    position: 14,
+                    character: 'A',
+                },
+            ),
+        ];
+        for (invalid_cns, error) in invalid_cnss {
+            assert_eq!(brado::cns::check(invalid_cns), Err(error));
+        }
+    }
+
+    #[test]
+    fn cns_compute_check_digits_1_valid_bases() {
+        let bases = [("14408262726", "0004"), ("81229734650000", "0")];
+        for (base, check_digits) in bases {
+            assert_eq!(
+                brado::cns::compute_check_digits(base),
+                Ok(String::from(check_digits)),
+            );
+        }
+
+        for _ in 0..1000 {
+            let cns = brado::cns::generate();
+            let size = match cns.starts_with(['1', '2']) {
+                true => 11,
+                false => 14,
+            };
+            let (base, check_digits) = cns.split_at(size);
+            assert_eq!(
+                brado::cns::compute_check_digits(base),
+                Ok(String::from(check_digits)),
+            );
+        }
+    }
+
+    #[test]
+    fn cns_compute_check_digits_2_invalid_bases() {
+        let invalid_bases = [
+            (
+                "1440826272",
+                ValidationError::InvalidLength {
+                    expected: 11,
+                    found: 10,
+                },
+            ),
+            (
+                "14408262726000",
+                ValidationError::InvalidLength {
+                    expected: 11,
+                    found: 14,
+                },
+            ),
+            (
+                "8122973465000",
+                ValidationError::InvalidLength {
+                    expected: 14,
+                    found: 13,
+                },
+            ),
+            (
+                "34408262726",
+                ValidationError::InvalidFirstDigit { found: 3 },
+            ),
+            ("81229734650006", ValidationError::InvalidChecksum),
+        ];
+        for (base, error) in invalid_bases {
+            assert_eq!(brado::cns::compute_check_digits(base), Err(error));
+        }
+    }
+
     #[test]
     fn cns_is_bare_1_valid_bare() {
         let documents = [
@@ -115,16 +223,64 @@ mod cns_tests {
     #[test]
     fn cns_mask_2_invalid_mask() {
         let documents = [
-            "144 0826 2726 0004", // Valid CNS
-            "144 0826 2726 0003", // Invalid CNS
-            "14408262726000",     // Invalid CNS
-            "1440826272600043",   // Invalid CNS
+            (
+                "144 0826 2726 0004", // Valid CNS
+                MaskError::AlreadyMasked { kind: DocKind::Cns },
+            ),
+            (
+                "144 0826 2726 0003", // Invalid CNS
+                MaskError::AlreadyMasked { kind: DocKind::Cns },
+            ),
+            (
+                "14408262726000", // Invalid CNS
+                MaskError::InvalidLength {
+                    kind: DocKind::Cns,
+                    expected: 15,
+                    found: 14,
+                },
+            ),
+            (
+                "1440826272600043", // Invalid CNS
+                MaskError::InvalidLength {
+                    kind: DocKind::Cns,
+                    expected: 15,
+                    found: 16,
+                },
+            ),
+            (
+                "14408262726000?", // Invalid CNS
+                MaskError::InvalidCharacter {
+                    kind: DocKind::Cns,
+                    position: 14,
+                    character: '?',
+                },
+            ),
         ];
+        for (document, error) in documents {
+            assert_eq!(brado::cns::mask(document), Err(error));
+        }
+    }
+
+    #[test]
+    fn cns_newtype_1_valid() {
+        let documents = ["144082627260004", "144 0826 2726 0004"];
+        for document in documents {
+            let doc: brado::cns::Cns = document.parse().unwrap();
+            assert_eq!(doc.as_bare(), "144082627260004");
+            assert_eq!(doc.to_masked(), "144 0826 2726 0004");
+            assert_eq!(doc.to_string(), "144 0826 2726 0004");
+            assert_eq!(brado::cns::Cns::try_from(document), Ok(doc));
+        }
+    }
+
+    #[test]
+    fn cns_newtype_2_invalid() {
+        let documents =
+            ["144082627260005", "144 0826 2726 0005", "14408262726000"];
         for document in documents {
-            let result = brado::cns::mask(document);
             assert_eq!(
-                result,
-                Err("The given string cannot be masked as CNS!"),
+                document.parse::<brado::cns::Cns>(),
+                Err(brado::cns::check(document).unwrap_err()),
             );
         }
     }
@@ -146,4 +302,134 @@ mod cns_tests {
             assert_eq!(brado::cns::is_masked(&cns), true);
         }
     }
+
+    #[test]
+    fn cns_generate_with_rng_1() {
+        for seed in 0..1000 {
+            let cns =
+                brado::cns::generate_with_rng(&mut StdRng::seed_from_u64(seed));
+            assert_eq!(brado::cns::validate(&cns), true);
+            assert_eq!(brado::cns::is_bare(&cns), true);
+            assert_eq!(
+                brado::cns::generate_with_rng(&mut StdRng::seed_from_u64(seed)),
+                cns,
+            );
+        }
+    }
+
+    #[test]
+    fn cns_generate_masked_with_rng_1() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut other = StdRng::seed_from_u64(42);
+        for _ in 0..1000 {
+            let cns = brado::cns::generate_masked_with_rng(&mut rng);
+            assert_eq!(brado::cns::validate(&cns), true);
+            assert_eq!(brado::cns::is_masked(&cns), true);
+            assert_eq!(brado::cns::generate_masked_with_rng(&mut other), cns);
+        }
+    }
+
+    #[test]
+    fn cns_kind_1() {
+        let valid_cnss = [
+            ("144082627260004", Some(CnsKind::Definitive)),
+            ("144 0826 2726 0004", Some(CnsKind::Definitive)),
+            ("812297346500000", Some(CnsKind::Provisional)),
+            ("144082627260005", None),
+            ("344082627260004", None),
+        ];
+        for (cns, kind) in valid_cnss {
+            assert_eq!(brado::cns::kind(cns), kind);
+        }
+        let cns: Cns = "812297346500000".parse().unwrap();
+        assert_eq!(cns.kind(), CnsKind::Provisional);
+    }
+
+    #[test]
+    fn cns_generate_definitive_1() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..1000 {
+            let cns = brado::cns::generate_definitive_with_rng(&mut rng);
+            assert_eq!(brado::cns::validate(&cns), true);
+            assert_eq!(brado::cns::kind(&cns), Some(CnsKind::Definitive));
+        }
+    }
+
+    #[test]
+    fn cns_generate_provisional_1() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..1000 {
+            let cns = brado::cns::generate_provisional_with_rng(&mut rng);
+            assert_eq!(brado::cns::validate(&cns), true);
+            assert_eq!(brado::cns::kind(&cns), Some(CnsKind::Provisional));
+        }
+    }
+
+    #[test]
+    fn cns_from_nis_1() {
+        assert_eq!(
+            brado::cns::from_nis("12062470390"),
+            Ok(String::from("120624703900004")),
+        );
+        assert_eq!(
+            brado::cns::from_nis("120.62470.39-0"),
+            Ok(String::from("120624703900004")),
+        );
+        for _ in 0..1000 {
+            let nis = brado::nis::generate();
+            match brado::cns::from_nis(&nis) {
+                Ok(cns) => {
+                    assert_eq!(&cns[..11], nis);
+                    assert_eq!(
+                        brado::cns::kind(&cns),
+                        Some(CnsKind::Definitive),
+                    );
+                }
+                Err(e) => assert!(matches!(
+                    e,
+                    ValidationError::InvalidFirstDigit { found: 0 | 3..=9 },
+                )),
+            }
+        }
+    }
+
+    #[test]
+    fn cns_from_nis_2_invalid() {
+        assert_eq!(
+            brado::cns::from_nis("40865658047"),
+            Err(ValidationError::InvalidFirstDigit { found: 4 }),
+        );
+        assert!(brado::cns::from_nis("12062470391").is_err());
+        assert_eq!(
+            brado::cns::from_nis("1206247039"),
+            Err(ValidationError::InvalidLength {
+                expected: 11,
+                found: 10,
+            }),
+        );
+    }
+
+    #[test]
+    fn cns_generate_provisional_2_all_residues() {
+        let weighted_sum = |digits: &[u32]| -> u32 {
+            digits
+                .iter()
+                .enumerate()
+                .map(|(i, d)| d * (15 - i as u32))
+                .sum()
+        };
+        let mut residues = [false; 11];
+        for seed in 0..20_000 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let cns = brado::cns::generate_provisional_with_rng(&mut rng);
+            let digits: Vec<u32> =
+                cns.chars().map(|c| c.to_digit(10).unwrap()).collect();
+            assert_eq!(digits.len(), 15);
+            assert!([7, 8, 9].contains(&digits[0]));
+            assert_eq!(weighted_sum(&digits) % 11, 0);
+            assert_eq!(brado::cns::validate(&cns), true);
+            residues[(weighted_sum(&digits[..13]) % 11) as usize] = true;
+        }
+        assert!(residues.iter().all(|&r| r));
+    }
 }
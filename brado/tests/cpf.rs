@@ -1,6 +1,11 @@
 #[cfg(test)]
 mod cpf_tests {
     use brado;
+    use brado::common::{MaskError, Uf, ValidationError};
+    use brado::cpf::FiscalRegion;
+    use brado::docs::DocKind;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
 
     #[test]
     fn cpf_validate_1_valid_cpfs() {
@@ -40,6 +45,90 @@ mod cpf_tests {
         }
     }
 
+    #[test]
+    fn cpf_check_1_valid_cpfs() {
+        let valid_cpfs = ["63929247011", "639.292.470-11"];
+        for valid_cpf in valid_cpfs {
+            assert_eq!(brado::cpf::check(valid_cpf), Ok(()));
+        }
+    }
+
+    #[test]
+    fn cpf_check_2_invalid_cpfs() {
+        let invalid_cpfs = [
+            (
+                "6392924701",
+                ValidationError::InvalidLength {
+                    expected: 11,
+                    found: 10,
+                },
+            ),
+            (
+                "63929247010",
+                ValidationError::CheckDigitMismatch {
+                    expected: String::from("11"),
+                    found: String::from("10"),
+                },
+            ),
+            ("639.292.470:11", ValidationError::InvalidMask),
+            (
+                "6392924701A",
+                ValidationError::InvalidCharacter {
+                    position: 10,
+                    character: 'A',
+                },
+            ),
+            ("111.111.111-11", ValidationError::RepeatedDigits),
+        ];
+        for (invalid_cpf, error) in invalid_cpfs {
+            assert_eq!(brado::cpf::check(invalid_cpf), Err(error));
+        }
+    }
+
+    #[test]
+    fn cpf_compute_check_digits_1_valid_bases() {
+        let bases = [("639292470", "11")];
+        for (base, check_digits) in bases {
+            assert_eq!(
+                brado::cpf::compute_check_digits(base),
+                Ok(String::from(check_digits)),
+            );
+        }
+
+        for _ in 0..1000 {
+            let cpf = brado::cpf::generate();
+            let (base, check_digits) = cpf.split_at(cpf.len() - 2);
+            assert_eq!(
+                brado::cpf::compute_check_digits(base),
+                Ok(String::from(check_digits)),
+            );
+        }
+    }
+
+    #[test]
+    fn cpf_compute_check_digits_2_invalid_bases() {
+        let invalid_bases = [
+            (
+                "63929247",
+                ValidationError::InvalidLength {
+                    expected: 9,
+                    found: 8,
+                },
+            ),
+            (
+                "639.29247",
+                ValidationError::InvalidCharacter {
+                    position: 3,
+                    character: '.',
+                },
+            ),
+            ("111111111", ValidationError::RepeatedDigits),
+        ];
+        for (base, error) in invalid_bases {
+            assert_eq!(brado::cpf::compute_check_digits(base), Err(error));
+        }
+    }
+
     #[test]
     fn cpf_is_bare_1_valid_bare() {
         let documents = [
@@ -118,20 +207,92 @@ mod cpf_tests {
     #[test]
     fn cpf_mask_2_invalid_mask() {
         let documents = [
-            "639.292.470-11", // Valid CPF
-            "639.292.470-10", // Invalid CPF
-            "6392924701",     // Invalid CPF
-            "639292470110",   // Invalid CPF
+            (
+                "639.292.470-11", // Valid CPF
+                MaskError::AlreadyMasked { kind: DocKind::Cpf },
+            ),
+            (
+                "639.292.470-10", // Invalid CPF
+                MaskError::AlreadyMasked { kind: DocKind::Cpf },
+            ),
+            (
+                "6392924701", // Invalid CPF
+                MaskError::InvalidLength {
+                    kind: DocKind::Cpf,
+                    expected: 11,
+                    found: 10,
+                },
+            ),
+            (
+                "639292470110", // Invalid CPF
+                MaskError::InvalidLength {
+                    kind: DocKind::Cpf,
+                    expected: 11,
+                    found: 12,
+                },
+            ),
+            (
+                "6392924701?", // Invalid CPF
+                MaskError::InvalidCharacter {
+                    kind: DocKind::Cpf,
+                    position: 10,
+                    character: '?',
+                },
+            ),
         ];
+        for (document, error) in documents {
+            assert_eq!(brado::cpf::mask(document), Err(error));
+        }
+    }
+
+    #[test]
+    fn cpf_mask_3_error_message() {
+        let error = brado::cpf::mask("639.292.470-11").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "The given string cannot be masked as CPF: already masked!",
+        );
+        let error = brado::cpf::mask("6392924701").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "The given string cannot be masked as CPF: expected 11 characters, found 10!",
+        );
+    }
+
+    #[test]
+    fn cpf_newtype_1_valid() {
+        let documents = ["63929247011", "639.292.470-11"];
+        for document in documents {
+            let doc: brado::cpf::Cpf = document.parse().unwrap();
+            assert_eq!(doc.as_bare(), "63929247011");
+            assert_eq!(doc.to_masked(), "639.292.470-11");
+            assert_eq!(doc.to_string(), "639.292.470-11");
+            assert_eq!(brado::cpf::Cpf::try_from(document), Ok(doc));
+        }
+    }
+
+    #[test]
+    fn cpf_newtype_2_invalid() {
+        let documents = ["63929247010", "639.292.470-10", "6392924701"];
         for document in documents {
-            let result = brado::cpf::mask(document);
             assert_eq!(
-                result,
-                Err("The given string cannot be masked as CPF!"),
+                document.parse::<brado::cpf::Cpf>(),
+                Err(brado::cpf::check(document).unwrap_err()),
             );
         }
     }
 
+    #[test]
+    fn cpf_newtype_3_ord() {
+        let mut cpfs: Vec<brado::cpf::Cpf> = ["639.292.470-11", "00000000191"]
+            .iter()
+            .map(|document| document.parse().unwrap())
+            .collect();
+        cpfs.sort();
+        let cpfs: Vec<&str> = cpfs.iter().map(|cpf| cpf.as_bare()).collect();
+        assert_eq!(cpfs, vec!["00000000191", "63929247011"]);
+    }
+
     #[test]
     fn cpf_generate_1() {
         for _ in 0..1000 {
@@ -149,4 +310,72 @@ mod cpf_tests {
             assert_eq!(brado::cpf::is_masked(&cpf), true);
         }
     }
+
+    #[test]
+    fn cpf_generate_with_rng_1() {
+        for seed in 0..1000 {
+            let cpf =
+                brado::cpf::generate_with_rng(&mut StdRng::seed_from_u64(seed));
+            assert_eq!(brado::cpf::validate(&cpf), true);
+            assert_eq!(brado::cpf::is_bare(&cpf), true);
+            assert_eq!(
+                brado::cpf::generate_with_rng(&mut StdRng::seed_from_u64(seed)),
+                cpf,
+            );
+        }
+    }
+
+    #[test]
+    fn cpf_generate_masked_with_rng_1() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut other = StdRng::seed_from_u64(42);
+        for _ in 0..1000 {
+            let cpf = brado::cpf::generate_masked_with_rng(&mut rng);
+            assert_eq!(brado::cpf::validate(&cpf), true);
+            assert_eq!(brado::cpf::is_masked(&cpf), true);
+            assert_eq!(brado::cpf::generate_masked_with_rng(&mut other), cpf);
+        }
+    }
+
+    #[test]
+    fn cpf_fiscal_region_1() {
+        let documents = [
+            ("63929247011", Some(FiscalRegion::Tenth)),
+            ("639.292.470-11", Some(FiscalRegion::Tenth)),
+            ("63929247010", None),
+            ("639292470", None),
+        ];
+        for (document, region) in documents {
+            assert_eq!(brado::cpf::fiscal_region(document), region);
+        }
+    }
+
+    #[test]
+    fn cpf_fiscal_region_2_states() {
+        for uf in Uf::ALL {
+            let region = FiscalRegion::from_uf(*uf);
+            assert_eq!(region.states().contains(uf), true);
+        }
+        let states: usize = FiscalRegion::ALL
+            .iter()
+            .map(|region| region.states().len())
+            .sum();
+        assert_eq!(states, Uf::ALL.len());
+        assert_eq!(FiscalRegion::from_uf(Uf::Sp), FiscalRegion::Eighth);
+        assert_eq!(FiscalRegion::from_digit(7), Some(FiscalRegion::Seventh));
+        assert_eq!(FiscalRegion::from_digit(10), None);
+    }
+
+    #[test]
+    fn cpf_generate_for_region_1() {
+        for region in FiscalRegion::ALL {
+            for _ in 0..100 {
+                let cpf = brado::cpf::generate_for_region(*region);
+                assert_eq!(brado::cpf::validate(&cpf), true);
+                assert_eq!(brado::cpf::fiscal_region(&cpf), Some(*region));
+                let cpf: brado::cpf::Cpf = cpf.parse().unwrap();
+                assert_eq!(cpf.fiscal_region(), *region);
+            }
+        }
+    }
 }
@@ -1,6 +1,10 @@
 #[cfg(test)]
 mod docs_tests {
     use brado;
+    use brado::common::Document;
+    use brado::docs::{Candidate, DocKind, Hint, Reason};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
 
     /* CPF */
 
@@ -401,4 +405,235 @@ mod docs_tests {
         let certidao: &str = "21924201552023106304243115818536";
         assert_eq!(brado::docs::is_certidao(certidao), true);
     }
+
+    /* Document */
+
+    fn generate_valid<D: Document + Default>() -> bool {
+        let document = D::default();
+        let doc = document.generate();
+        document.validate(&doc)
+            && document.is_bare(&doc)
+            && document.mask(&doc).map(|masked| document.unmask(&masked))
+                == Ok(doc)
+    }
+
+    #[test]
+    fn docs_document_generic() {
+        assert_eq!(generate_valid::<brado::cpf::CpfDocument>(), true);
+        assert_eq!(generate_valid::<brado::cnpj::CnpjDocument>(), true);
+        assert_eq!(generate_valid::<brado::cnh::CnhDocument>(), true);
+        assert_eq!(generate_valid::<brado::cns::CnsDocument>(), true);
+        assert_eq!(generate_valid::<brado::nis::NisDocument>(), true);
+        assert_eq!(
+            generate_valid::<brado::eleitoral::EleitoralDocument>(),
+            true
+        );
+        assert_eq!(generate_valid::<brado::renavam::RenavamDocument>(), true);
+        assert_eq!(generate_valid::<brado::certidao::CertidaoDocument>(), true);
+        assert_eq!(generate_valid::<brado::placa::PlacaDocument>(), true);
+        assert_eq!(generate_valid::<brado::chassi::ChassiDocument>(), true);
+        assert_eq!(generate_valid::<brado::cep::CepDocument>(), true);
+    }
+
+    #[test]
+    fn docs_documents() {
+        let documents = brado::docs::documents();
+        assert_eq!(documents.len(), 10);
+        for document in documents {
+            for _ in 0..100 {
+                let doc = document.generate_masked();
+                assert_eq!(document.validate(&doc), true);
+                assert_eq!(
+                    document.mask(&document.unmask(&doc)),
+                    Ok(doc.clone())
+                );
+                assert_eq!(document.unmask(&doc).len(), document.bare_size());
+            }
+        }
+    }
+
+    #[test]
+    fn docs_documents_with_rng() {
+        for document in brado::docs::documents() {
+            let mut rng = StdRng::seed_from_u64(42);
+            let mut other = StdRng::seed_from_u64(42);
+            for _ in 0..100 {
+                let doc = document.generate_with_rng(&mut rng);
+                assert_eq!(document.validate(&doc), true);
+                assert_eq!(document.is_bare(&doc), true);
+                assert_eq!(document.generate_with_rng(&mut other), doc);
+
+                let doc = document.generate_masked_with_rng(&mut rng);
+                assert_eq!(document.validate(&doc), true);
+                assert_eq!(
+                    document.mask(&document.unmask(&doc)),
+                    Ok(doc.clone())
+                );
+                assert_eq!(document.generate_masked_with_rng(&mut other), doc);
+            }
+        }
+    }
+
+    #[test]
+    fn docs_documents_names() {
+        let names: Vec<&str> = brado::docs::documents()
+            .iter()
+            .map(|document| document.name())
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "CPF",
+                "CNPJ",
+                "CNH",
+                "CNS",
+                "NIS/NIT/PIS/PASEP",
+                "Título Eleitoral",
+                "RENAVAM",
+                "Certidão",
+                "Placa",
+                "Chassi",
+            ]
+        );
+    }
+
+    /* Identify */
+
+    #[test]
+    fn docs_identify_1_documents() {
+        let documents = [
+            ("63929247011", vec![DocKind::Cpf, DocKind::Cnh]),
+            ("639.292.470-11", vec![DocKind::Cpf]),
+            ("05200851000100", vec![DocKind::Cnpj]),
+            ("84718735264", vec![DocKind::Cnh]),
+            ("847 187 352 64", vec![DocKind::Cnh]),
+            ("144082627260004", vec![DocKind::Cns]),
+            ("40865658047", vec![DocKind::Nis, DocKind::Renavam]),
+            ("408.65658.04-7", vec![DocKind::Nis]),
+            ("773537801651", vec![DocKind::Eleitoral]),
+            ("79072338363", vec![DocKind::Nis, DocKind::Renavam]),
+            ("7907233836-3", vec![DocKind::Renavam]),
+            ("21924201552023106304243115818536", vec![DocKind::Certidao]),
+            ("ABC-1234", vec![DocKind::Placa]),
+            ("ABC1C34", vec![DocKind::Placa]),
+        ];
+        for (document, kinds) in documents {
+            assert_eq!(brado::docs::identify(document), kinds);
+        }
+    }
+
+    #[test]
+    fn docs_identify_2_invalid_documents() {
+        let documents = ["", "63929247010", "AAAAAAAAAAA", "639.292.470-1"];
+        for document in documents {
+            assert_eq!(brado::docs::identify(document), vec![]);
+        }
+    }
+
+    #[test]
+    fn docs_identify_3_generated() {
+        for kind in DocKind::ALL {
+            for _ in 0..100 {
+                let document = kind.document().generate();
+                assert_eq!(
+                    brado::docs::identify(&document).contains(kind),
+                    true
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn docs_doc_kind_metadata() {
+        let sizes: Vec<(&str, usize)> = DocKind::ALL
+            .iter()
+            .map(|kind| (kind.name(), kind.bare_size()))
+            .collect();
+        assert_eq!(
+            sizes,
+            vec![
+                ("CPF", 11),
+                ("CNPJ", 14),
+                ("CNH", 11),
+                ("CNS", 15),
+                ("NIS/NIT/PIS/PASEP", 11),
+                ("Título Eleitoral", 12),
+                ("RENAVAM", 11),
+                ("Certidão", 32),
+                ("Placa", 7),
+                ("Chassi", 17),
+            ]
+        );
+        assert_eq!(DocKind::Cnpj.to_string(), "CNPJ");
+        assert_eq!(
+            DocKind::Renavam.description(),
+            "Registro Nacional de Veículos Automotores"
+        );
+    }
+
+    /* Classify */
+
+    fn kinds(candidates: &[Candidate]) -> Vec<DocKind> {
+        candidates.iter().map(|candidate| candidate.kind).collect()
+    }
+
+    #[test]
+    fn docs_classify_1_masks() {
+        let documents = [
+            ("639.292.470-11", DocKind::Cpf),
+            ("847 187 352 64", DocKind::Cnh),
+            ("408.65658.04-7", DocKind::Nis),
+            ("7907233836-3", DocKind::Renavam),
+        ];
+        for (document, kind) in documents {
+            let candidates = brado::docs::classify(document, &[]);
+            assert_eq!(kinds(&candidates), vec![kind]);
+            assert_eq!(
+                candidates[0].reasons,
+                vec![Reason::Valid, Reason::Unambiguous, Reason::MaskMatches]
+            );
+            assert_eq!(candidates[0].score, 5);
+        }
+    }
+
+    #[test]
+    fn docs_classify_2_bare_collisions() {
+        let candidates = brado::docs::classify("63929247011", &[]);
+        assert_eq!(kinds(&candidates), vec![DocKind::Cpf, DocKind::Cnh]);
+        for candidate in candidates {
+            assert_eq!(candidate.reasons, vec![Reason::Valid]);
+            assert_eq!(candidate.score, 1);
+        }
+    }
+
+    #[test]
+    fn docs_classify_3_hints() {
+        let candidates =
+            brado::docs::classify("63929247011", &[Hint::Prefer(DocKind::Cnh)]);
+        assert_eq!(kinds(&candidates), vec![DocKind::Cnh, DocKind::Cpf]);
+        assert_eq!(
+            candidates[0].reasons,
+            vec![Reason::Valid, Reason::Preferred]
+        );
+        assert_eq!(candidates[0].score, 4);
+
+        let candidates =
+            brado::docs::classify("79072338363", &[Hint::Avoid(DocKind::Nis)]);
+        assert_eq!(kinds(&candidates), vec![DocKind::Renavam, DocKind::Nis]);
+        assert_eq!(candidates[1].reasons, vec![Reason::Valid, Reason::Avoided]);
+        assert_eq!(candidates[1].score, -2);
+
+        let candidates = brado::docs::classify(
+            "639.292.470-11",
+            &[Hint::Prefer(DocKind::Cnh)],
+        );
+        assert_eq!(kinds(&candidates), vec![DocKind::Cpf]);
+    }
+
+    #[test]
+    fn docs_classify_4_invalid() {
+        let candidates =
+            brado::docs::classify("63929247010", &[Hint::Prefer(DocKind::Cpf)]);
+        assert_eq!(candidates, vec![]);
+    }
 }
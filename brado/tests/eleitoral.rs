@@ -1,10 +1,20 @@
 #[cfg(test)]
 mod eleitoral_tests {
     use brado;
+    use brado::common::{MaskError, Uf, ValidationError};
+    use brado::docs::DocKind;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
 
     #[test]
     fn eleitoral_validate_1_valid_eleitorais() {
-        let valid_eleitorais = ["773537801651", "7735 3780 1651"];
+        let valid_eleitorais = [
+            "773537801651",
+            "7735 3780 1651",
+            "100000010116", // SP: first check digit rest 0 -> 1
+            "400000000281", // MG: second check digit rest 0 -> 1
+            "773537802852", // ZZ (exterior)
+        ];
         for valid_eleitoral in valid_eleitorais {
             assert_eq!(brado::eleitoral::validate(valid_eleitoral), true);
         }
@@ -25,12 +35,113 @@ mod eleitoral_tests {
             "7735 3780:1651",
             "AAAAAAAAAAAA",
             "AAAA AAAA AAAA",
+            "100000010106", // SP without the rest 0 -> 1 rule
+            "400000000280", // MG without the rest 0 -> 1 rule
+            "773537800051", // State code 00
+            "773537802951", // State code 29
         ];
         for invalid_eleitoral in invalid_eleitorais {
             assert_eq!(brado::eleitoral::validate(invalid_eleitoral), false);
         }
     }
 
+    #[test]
+    fn eleitoral_check_1_valid_eleitorais() {
+        let valid_eleitorais = ["773537801651", "7735 3780 1651"];
+        for valid_eleitoral in valid_eleitorais {
+            assert_eq!(brado::eleitoral::check(valid_eleitoral), Ok(()));
+        }
+    }
+
+    #[test]
+    fn eleitoral_check_2_invalid_eleitorais() {
+        let invalid_eleitorais = [
+            (
+                "77353780165",
+                ValidationError::InvalidLength {
+                    expected: 12,
+                    found: 11,
+                },
+            ),
+            (
+                "773537801650",
+                ValidationError::CheckDigitMismatch {
+                    expected: String::from("51"),
+                    found: String::from("50"),
+                },
+            ),
+            ("7735:3780 1651", ValidationError::InvalidMask),
+            (
+                "77353780165A",
+                ValidationError::InvalidCharacter {
+                    position: 11,
+                    character: 'A',
+                },
+            ),
+            (
+                "773537800051",
+                ValidationError::InvalidStateCode { found: 0 },
+            ),
+            (
+                "7735 3780 2951",
+                ValidationError::InvalidStateCode { found: 29 },
+            ),
+        ];
+        for (invalid_eleitoral, error) in invalid_eleitorais {
+            assert_eq!(brado::eleitoral::check(invalid_eleitoral), Err(error));
+        }
+    }
+
+    #[test]
+    fn eleitoral_compute_check_digits_1_valid_bases() {
+        let bases = [
+            ("7735378016", "51"),
+            ("1000000101", "16"),
+            ("4000000002", "81"),
+        ];
+        for (base, check_digits) in bases {
+            assert_eq!(
+                brado::eleitoral::compute_check_digits(base),
+                Ok(String::from(check_digits)),
+            );
+        }
+
+        for _ in 0..1000 {
+            let eleitoral = brado::eleitoral::generate();
+            let (base, check_digits) = eleitoral.split_at(eleitoral.len() - 2);
+            assert_eq!(
+                brado::eleitoral::compute_check_digits(base),
+                Ok(String::from(check_digits)),
+            );
+        }
+    }
+
+    #[test]
+    fn eleitoral_compute_check_digits_2_invalid_bases() {
+        let invalid_bases = [
+            (
+                "77353780",
+                ValidationError::InvalidLength {
+                    expected: 10,
+                    found: 8,
+                },
+            ),
+            (
+                "7735 37801",
+                ValidationError::InvalidCharacter {
+                    position: 4,
+                    character: ' ',
+                },
+            ),
+        ];
+        for (base, error) in invalid_bases {
+            assert_eq!(
+                brado::eleitoral::compute_check_digits(base),
+                Err(error)
+            );
+        }
+    }
+
     #[test]
     fn eleitoral_is_bare_1_valid_bare() {
         let documents = [
@@ -106,16 +217,71 @@ mod eleitoral_tests {
     #[test]
     fn eleitoral_mask_2_invalid_mask() {
         let documents = [
-            "7735 3780 1651", // Valid Título Eleitoral
-            "7735 3780 1650", // Invalid Título Eleitoral
-            "77353780165",    // Invalid Título Eleitoral
-            "7735378016510",  // Invalid Título Eleitoral
+            (
+                "7735 3780 1651", // Valid Título Eleitoral
+                MaskError::AlreadyMasked {
+                    kind: DocKind::Eleitoral,
+                },
+            ),
+            (
+                "7735 3780 1650", // Invalid Título Eleitoral
+                MaskError::AlreadyMasked {
+                    kind: DocKind::Eleitoral,
+                },
+            ),
+            (
+                "77353780165", // Invalid Título Eleitoral
+                MaskError::InvalidLength {
+                    kind: DocKind::Eleitoral,
+                    expected: 12,
+                    found: 11,
+                },
+            ),
+            (
+                "7735378016510", // Invalid Título Eleitoral
+                MaskError::InvalidLength {
+                    kind: DocKind::Eleitoral,
+                    expected: 12,
+                    found: 13,
+                },
+            ),
+            (
+                "77353780165?", // Invalid Título Eleitoral
+                MaskError::InvalidCharacter {
+                    kind: DocKind::Eleitoral,
+                    position: 11,
+                    character: '?',
+                },
+            ),
         ];
+        for (document, error) in documents {
+            assert_eq!(brado::eleitoral::mask(document), Err(error));
+        }
+    }
+
+    #[test]
+    fn eleitoral_newtype_1_valid() {
+        let documents = ["773537801651", "7735 3780 1651"];
+        for document in documents {
+            let doc: brado::eleitoral::TituloEleitoral =
+                document.parse().unwrap();
+            assert_eq!(doc.as_bare(), "773537801651");
+            assert_eq!(doc.to_masked(), "7735 3780 1651");
+            assert_eq!(doc.to_string(), "7735 3780 1651");
+            assert_eq!(
+                brado::eleitoral::TituloEleitoral::try_from(document),
+                Ok(doc)
+            );
+        }
+    }
+
+    #[test]
+    fn eleitoral_newtype_2_invalid() {
+        let documents = ["773537801650", "7735 3780 1650", "77353780165"];
         for document in documents {
-            let result = brado::eleitoral::mask(document);
             assert_eq!(
-                result,
-                Err("The given string cannot be masked as Título Eleitoral!"),
+                document.parse::<brado::eleitoral::TituloEleitoral>(),
+                Err(brado::eleitoral::check(document).unwrap_err()),
             );
         }
     }
@@ -137,4 +303,88 @@ mod eleitoral_tests {
             assert_eq!(brado::eleitoral::is_masked(&eleitoral), true);
         }
     }
+
+    #[test]
+    fn eleitoral_generate_with_rng_1() {
+        for seed in 0..1000 {
+            let eleitoral = brado::eleitoral::generate_with_rng(
+                &mut StdRng::seed_from_u64(seed),
+            );
+            assert_eq!(brado::eleitoral::validate(&eleitoral), true);
+            assert_eq!(brado::eleitoral::is_bare(&eleitoral), true);
+            assert_eq!(
+                brado::eleitoral::generate_with_rng(
+                    &mut StdRng::seed_from_u64(seed)
+                ),
+                eleitoral,
+            );
+        }
+    }
+
+    #[test]
+    fn eleitoral_generate_masked_with_rng_1() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut other = StdRng::seed_from_u64(42);
+        for _ in 0..1000 {
+            let eleitoral =
+                brado::eleitoral::generate_masked_with_rng(&mut rng);
+            assert_eq!(brado::eleitoral::validate(&eleitoral), true);
+            assert_eq!(brado::eleitoral::is_masked(&eleitoral), true);
+            assert_eq!(
+                brado::eleitoral::generate_masked_with_rng(&mut other),
+                eleitoral
+            );
+        }
+    }
+
+    #[test]
+    fn eleitoral_uf_1() {
+        let documents = [
+            ("773537801651", Some(Uf::Rn)),
+            ("7735 3780 1651", Some(Uf::Rn)),
+            ("100000010116", Some(Uf::Sp)),
+            ("400000000281", Some(Uf::Mg)),
+            ("773537802852", None),
+            ("773537801650", None),
+        ];
+        for (document, uf) in documents {
+            assert_eq!(brado::eleitoral::uf(document), uf);
+        }
+    }
+
+    #[test]
+    fn eleitoral_is_exterior_1() {
+        assert_eq!(brado::eleitoral::is_exterior("773537802852"), true);
+        assert_eq!(brado::eleitoral::is_exterior("7735 3780 2852"), true);
+        assert_eq!(brado::eleitoral::is_exterior("773537801651"), false);
+        assert_eq!(brado::eleitoral::is_exterior("773537802850"), false);
+    }
+
+    #[test]
+    fn eleitoral_generate_for_uf_1() {
+        for uf in Uf::ALL {
+            for _ in 0..100 {
+                let eleitoral = brado::eleitoral::generate_for_uf(*uf);
+                assert_eq!(brado::eleitoral::validate(&eleitoral), true);
+                assert_eq!(brado::eleitoral::uf(&eleitoral), Some(*uf));
+                let eleitoral: brado::eleitoral::TituloEleitoral =
+                    eleitoral.parse().unwrap();
+                assert_eq!(eleitoral.uf(), Some(*uf));
+                assert_eq!(eleitoral.is_exterior(), false);
+            }
+        }
+    }
+
+    #[test]
+    fn eleitoral_generate_2_state_codes() {
+        for _ in 0..1000 {
+            let eleitoral = brado::eleitoral::generate();
+            let code: u16 = eleitoral[8..10].parse().unwrap();
+            assert_eq!((1..=28).contains(&code), true);
+            assert_eq!(
+                brado::eleitoral::uf(&eleitoral).is_none(),
+                brado::eleitoral::is_exterior(&eleitoral),
+            );
+        }
+    }
 }
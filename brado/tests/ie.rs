@@ -0,0 +1,320 @@
+#[cfg(test)]
+mod ie_tests {
+    use brado;
+    use brado::common::{Document, MaskError, Uf, ValidationError};
+    use brado::docs::DocKind;
+    use brado::ie::{Ie, IeDocument};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    const VALID_IES: [(Uf, &str, &str); 33] = [
+        (Uf::Ac, "0100482300112", "01.004.823/001-12"),
+        (Uf::Al, "240000048", "240000048"),
+        (Uf::Ap, "030123459", "030123459"),
+        (Uf::Am, "999999990", "99.999.999-0"),
+        (Uf::Ba, "100000306", "1000003-06"),
+        (Uf::Ba, "12345663", "123456-63"),
+        (Uf::Ce, "060000015", "06000001-5"),
+        (Uf::Df, "0730000100109", "07300001001-09"),
+        (Uf::Es, "999999990", "999.999.99-0"),
+        (Uf::Go, "109876547", "10.987.654-7"),
+        (Uf::Ma, "120000385", "120000385"),
+        (Uf::Mt, "00130000019", "0013000001-9"),
+        (Uf::Ms, "283115947", "283115947"),
+        (Uf::Mg, "0623079040081", "062.307.904/0081"),
+        (Uf::Pa, "159999995", "15-999999-5"),
+        (Uf::Pb, "060000015", "06000001-5"),
+        (Uf::Pr, "1234567850", "12345678-50"),
+        (Uf::Pe, "032141840", "0321418-40"),
+        (Uf::Pe, "18100100000049", "18.1.001.0000004-9"),
+        (Uf::Pi, "012345679", "012345679"),
+        (Uf::Rj, "99999993", "99.999.99-3"),
+        (Uf::Rn, "200400401", "20.040.040-1"),
+        (Uf::Rn, "2000400400", "20.0.040.040-0"),
+        (Uf::Rs, "2243658792", "224/3658792"),
+        (Uf::Ro, "00000000625213", "0000000062521-3"),
+        (Uf::Ro, "101625213", "101.62521-3"),
+        (Uf::Rr, "240066281", "24006628-1"),
+        (Uf::Sc, "251040852", "251.040.852"),
+        (Uf::Sp, "110042490114", "110.042.490.114"),
+        (Uf::Sp, "P011004243002", "P-01100424.3/002"),
+        (Uf::Se, "271234563", "27123456-3"),
+        (Uf::To, "290227836", "29022783-6"),
+        (Uf::To, "29010227836", "29.01.022783-6"),
+    ];
+
+    #[test]
+    fn ie_validate_1_valid_ies() {
+        for (uf, bare, masked) in VALID_IES {
+            assert_eq!(brado::ie::validate(uf, bare), true);
+            assert_eq!(brado::ie::validate(uf, masked), true);
+        }
+    }
+
+    #[test]
+    fn ie_validate_2_invalid_ies() {
+        let invalid_ies = [
+            (Uf::Sp, "110042490115"),
+            (Uf::Sp, "110042490104"),
+            (Uf::Sp, "11004249011"),
+            (Uf::Sp, "110.042.490-114"),
+            (Uf::Sp, "P-01100424.4/002"),
+            (Uf::Sp, "P111004243002"),
+            (Uf::Mg, "110042490114"),
+            (Uf::Mg, "0623079040082"),
+            (Uf::Ba, "12345664"),
+            (Uf::Ac, "0200482300112"),
+            (Uf::Al, "250000048"),
+            (Uf::To, "29040227836"),
+            (Uf::Rj, "AAAAAAAA"),
+        ];
+        for (uf, invalid_ie) in invalid_ies {
+            assert_eq!(brado::ie::validate(uf, invalid_ie), false);
+        }
+    }
+
+    #[test]
+    fn ie_check_1_valid_ies() {
+        for (uf, bare, masked) in VALID_IES {
+            assert_eq!(brado::ie::check(uf, bare), Ok(()));
+            assert_eq!(brado::ie::check(uf, masked), Ok(()));
+        }
+    }
+
+    #[test]
+    fn ie_check_2_invalid_ies() {
+        let invalid_ies = [
+            (
+                Uf::Sp,
+                "110042490115",
+                ValidationError::CheckDigitMismatch {
+                    expected: String::from("04"),
+                    found: String::from("05"),
+                },
+            ),
+            (
+                Uf::Sp,
+                "11004249011",
+                ValidationError::InvalidLength {
+                    expected: 12,
+                    found: 11,
+                },
+            ),
+            (Uf::Sp, "110.042.490-114", ValidationError::InvalidMask),
+            (
+                Uf::Sp,
+                "11004249011A",
+                ValidationError::InvalidCharacter {
+                    position: 11,
+                    character: 'A',
+                },
+            ),
+            (
+                Uf::Sp,
+                "P111004243002",
+                ValidationError::InvalidPrefix {
+                    found: String::from("1"),
+                },
+            ),
+            (
+                Uf::Ac,
+                "0200482300112",
+                ValidationError::InvalidPrefix {
+                    found: String::from("02"),
+                },
+            ),
+            (
+                Uf::Al,
+                "241000048",
+                ValidationError::InvalidPrefix {
+                    found: String::from("241"),
+                },
+            ),
+            (
+                Uf::To,
+                "29040227836",
+                ValidationError::InvalidPrefix {
+                    found: String::from("2904"),
+                },
+            ),
+        ];
+        for (uf, invalid_ie, error) in invalid_ies {
+            assert_eq!(brado::ie::check(uf, invalid_ie), Err(error));
+        }
+    }
+
+    #[test]
+    fn ie_is_bare_1_valid_bare() {
+        for (uf, bare, _) in VALID_IES {
+            assert_eq!(brado::ie::is_bare(uf, bare), true);
+        }
+        assert_eq!(brado::ie::is_bare(Uf::Sp, "110042490115"), true);
+    }
+
+    #[test]
+    fn ie_is_bare_2_invalid_bare() {
+        let invalid_bares = [
+            (Uf::Sp, "110.042.490.114"),
+            (Uf::Sp, "P-01100424.3/002"),
+            (Uf::Sp, "11004249011"),
+            (Uf::Mg, "110042490114"),
+            (Uf::Rj, "AAAAAAAA"),
+        ];
+        for (uf, invalid_bare) in invalid_bares {
+            assert_eq!(brado::ie::is_bare(uf, invalid_bare), false);
+        }
+    }
+
+    #[test]
+    fn ie_is_masked_1_valid_masked() {
+        for (uf, bare, masked) in VALID_IES {
+            assert_eq!(brado::ie::is_masked(uf, masked), bare != masked);
+        }
+        assert_eq!(brado::ie::is_masked(Uf::Sp, "110.042.490.115"), true);
+    }
+
+    #[test]
+    fn ie_is_masked_2_invalid_masked() {
+        let invalid_masked = [
+            (Uf::Sp, "110042490114"),
+            (Uf::Sp, "110.042.490-114"),
+            (Uf::Mg, "110.042.490.114"),
+            (Uf::Al, "240000048"),
+        ];
+        for (uf, invalid_masked) in invalid_masked {
+            assert_eq!(brado::ie::is_masked(uf, invalid_masked), false);
+        }
+    }
+
+    #[test]
+    fn ie_mask_1_valid_mask() {
+        for (uf, bare, masked) in VALID_IES {
+            assert_eq!(brado::ie::mask(uf, bare), Ok(String::from(masked)));
+            assert_eq!(brado::ie::unmask(masked), bare);
+        }
+    }
+
+    #[test]
+    fn ie_mask_2_invalid_mask() {
+        assert_eq!(
+            brado::ie::mask(Uf::Sp, "110.042.490.114"),
+            Err(MaskError::AlreadyMasked {
+                kind: DocKind::Ie(Uf::Sp),
+            }),
+        );
+        assert_eq!(
+            brado::ie::mask(Uf::Sp, "11004249011"),
+            Err(MaskError::InvalidLength {
+                kind: DocKind::Ie(Uf::Sp),
+                expected: 12,
+                found: 11,
+            }),
+        );
+        assert_eq!(
+            brado::ie::mask(Uf::Sp, "11004249011A"),
+            Err(MaskError::InvalidCharacter {
+                kind: DocKind::Ie(Uf::Sp),
+                position: 11,
+                character: 'A',
+            }),
+        );
+    }
+
+    #[test]
+    fn ie_newtype_1_valid() {
+        for (uf, bare, masked) in VALID_IES {
+            let ie = Ie::new(uf, masked).unwrap();
+            assert_eq!(ie.uf(), uf);
+            assert_eq!(ie.as_bare(), bare);
+            assert_eq!(ie.to_masked(), masked);
+            assert_eq!(ie.to_string(), masked);
+            assert_eq!(Ie::new(uf, bare), Ok(ie));
+        }
+    }
+
+    #[test]
+    fn ie_newtype_2_invalid() {
+        assert_eq!(
+            Ie::new(Uf::Sp, "110042490115"),
+            Err(ValidationError::CheckDigitMismatch {
+                expected: String::from("04"),
+                found: String::from("05"),
+            }),
+        );
+        assert!(Ie::new(Uf::Mg, "110.042.490.114").is_err());
+    }
+
+    #[test]
+    fn ie_document_1() {
+        let document = IeDocument(Uf::Mg);
+        assert_eq!(document.kind(), DocKind::Ie(Uf::Mg));
+        assert_eq!(document.name(), "IE");
+        assert_eq!(document.bare_size(), 13);
+        assert_eq!(document.validate("062.307.904/0081"), true);
+        assert_eq!(DocKind::Ie(Uf::Sp).bare_size(), 12);
+        assert_eq!(DocKind::Ie(Uf::Sp).validate("110.042.490.114"), true);
+        assert_eq!(brado::docs::is_ie(Uf::Sp, "110.042.490.114"), true);
+        assert_eq!(DocKind::ALL.contains(&DocKind::Ie(Uf::Sp)), false);
+    }
+
+    #[test]
+    fn ie_generate_1() {
+        for &uf in Uf::ALL {
+            for _ in 0..100 {
+                let ie = brado::ie::generate(uf);
+                assert_eq!(brado::ie::validate(uf, &ie), true);
+                assert_eq!(brado::ie::is_bare(uf, &ie), true);
+            }
+        }
+    }
+
+    #[test]
+    fn ie_generate_masked_1() {
+        for &uf in Uf::ALL {
+            for _ in 0..100 {
+                let ie = brado::ie::generate_masked(uf);
+                assert_eq!(brado::ie::validate(uf, &ie), true);
+                assert_eq!(
+                    brado::ie::is_masked(uf, &ie),
+                    brado::ie::mask(uf, &ie).is_err(),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn ie_generate_with_rng_1() {
+        for &uf in Uf::ALL {
+            for seed in 0..100 {
+                let ie = brado::ie::generate_with_rng(
+                    uf,
+                    &mut StdRng::seed_from_u64(seed),
+                );
+                assert_eq!(brado::ie::validate(uf, &ie), true);
+                assert_eq!(
+                    brado::ie::generate_with_rng(
+                        uf,
+                        &mut StdRng::seed_from_u64(seed),
+                    ),
+                    ie,
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn ie_generate_masked_with_rng_1() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut other = StdRng::seed_from_u64(42);
+        for &uf in Uf::ALL {
+            for _ in 0..100 {
+                let ie = brado::ie::generate_masked_with_rng(uf, &mut rng);
+                assert_eq!(brado::ie::validate(uf, &ie), true);
+                assert_eq!(
+                    brado::ie::generate_masked_with_rng(uf, &mut other),
+                    ie,
+                );
+            }
+        }
+    }
+}
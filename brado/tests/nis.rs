@@ -1,6 +1,10 @@
 #[cfg(test)]
 mod nis_tests {
     use brado;
+    use brado::common::{MaskError, ValidationError};
+    use brado::docs::DocKind;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
 
     #[test]
     fn nis_validate_1_valid_niss() {
@@ -40,6 +44,90 @@ mod nis_tests {
         }
     }
 
+    #[test]
+    fn nis_check_1_valid_niss() {
+        let valid_niss = ["40865658047", "408.65658.04-7"];
+        for valid_nis in valid_niss {
+            assert_eq!(brado::nis::check(valid_nis), Ok(()));
+        }
+    }
+
+    #[test]
+    fn nis_check_2_invalid_niss() {
+        let invalid_niss = [
+            (
+                "4086565804",
+                ValidationError::InvalidLength {
+                    expected: 11,
+                    found: 10,
+                },
+            ),
+            (
+                "40865658046",
+                ValidationError::CheckDigitMismatch {
+                    expected: String::from("7"),
+                    found: String::from("6"),
+                },
+            ),
+            ("408.65658.04:7", ValidationError::InvalidMask),
+            (
+                "408656580A7",
+                ValidationError::InvalidCharacter {
+                    position: 9,
+                    character: 'A',
+                },
+            ),
+            ("00000000000", ValidationError::RepeatedDigits),
+        ];
+        for (invalid_nis, error) in invalid_niss {
+            assert_eq!(brado::nis::check(invalid_nis), Err(error));
+        }
+    }
+
+    #[test]
+    fn nis_compute_check_digits_1_valid_bases() {
+        let bases = [("4086565804", "7")];
+        for (base, check_digits) in bases {
+            assert_eq!(
+                brado::nis::compute_check_digits(base),
+                Ok(String::from(check_digits)),
+            );
+        }
+
+        for _ in 0..1000 {
+            let nis = brado::nis::generate();
+            let (base, check_digits) = nis.split_at(nis.len() - 1);
+            assert_eq!(
+                brado::nis::compute_check_digits(base),
+                Ok(String::from(check_digits)),
+            );
+        }
+    }
+
+    #[test]
+    fn nis_compute_check_digits_2_invalid_bases() {
+        let invalid_bases = [
+            (
+                "408656580",
+                ValidationError::InvalidLength {
+                    expected: 10,
+                    found: 9,
+                },
+            ),
+            (
+                "408656580A",
+                ValidationError::InvalidCharacter {
+                    position: 9,
+                    character: 'A',
+                },
+            ),
+            ("0000000000", ValidationError::RepeatedDigits),
+        ];
+        for (base, error) in invalid_bases {
+            assert_eq!(brado::nis::compute_check_digits(base), Err(error));
+        }
+    }
+
     #[test]
     fn nis_is_bare_1_valid_bare() {
         let documents = [
@@ -118,16 +206,63 @@ mod nis_tests {
     #[test]
     fn nis_mask_2_invalid_mask() {
         let documents = [
-            "408.65658.04-7", // Valid NIS/NIT/PIS/PASEP
-            "408.65658.04-6", // Invalid NIS/NIT/PIS/PASEP
-            "4086565804",     // Invalid NIS/NIT/PIS/PASEP
-            "408656580476",   // Invalid NIS/NIT/PIS/PASEP
+            (
+                "408.65658.04-7", // Valid NIS/NIT/PIS/PASEP
+                MaskError::AlreadyMasked { kind: DocKind::Nis },
+            ),
+            (
+                "408.65658.04-6", // Invalid NIS/NIT/PIS/PASEP
+                MaskError::AlreadyMasked { kind: DocKind::Nis },
+            ),
+            (
+                "4086565804", // Invalid NIS/NIT/PIS/PASEP
+                MaskError::InvalidLength {
+                    kind: DocKind::Nis,
+                    expected: 11,
+                    found: 10,
+                },
+            ),
+            (
+                "408656580476", // Invalid NIS/NIT/PIS/PASEP
+                MaskError::InvalidLength {
+                    kind: DocKind::Nis,
+                    expected: 11,
+                    found: 12,
+                },
+            ),
+            (
+                "4086565804?", // Invalid NIS/NIT/PIS/PASEP
+                MaskError::InvalidCharacter {
+                    kind: DocKind::Nis,
+                    position: 10,
+                    character: '?',
+                },
+            ),
         ];
+        for (document, error) in documents {
+            assert_eq!(brado::nis::mask(document), Err(error));
+        }
+    }
+
+    #[test]
+    fn nis_newtype_1_valid() {
+        let documents = ["40865658047", "408.65658.04-7"];
+        for document in documents {
+            let doc: brado::nis::Nis = document.parse().unwrap();
+            assert_eq!(doc.as_bare(), "40865658047");
+            assert_eq!(doc.to_masked(), "408.65658.04-7");
+            assert_eq!(doc.to_string(), "408.65658.04-7");
+            assert_eq!(brado::nis::Nis::try_from(document), Ok(doc));
+        }
+    }
+
+    #[test]
+    fn nis_newtype_2_invalid() {
+        let documents = ["40865658046", "408.65658.04-6", "4086565804"];
         for document in documents {
-            let result = brado::nis::mask(document);
             assert_eq!(
-                result,
-                Err("The given string cannot be masked as NIS/NIT/PIS/PASEP!"),
+                document.parse::<brado::nis::Nis>(),
+                Err(brado::nis::check(document).unwrap_err()),
             );
         }
     }
@@ -149,4 +284,30 @@ mod nis_tests {
             assert_eq!(brado::nis::is_masked(&nis), true);
         }
     }
+
+    #[test]
+    fn nis_generate_with_rng_1() {
+        for seed in 0..1000 {
+            let nis =
+                brado::nis::generate_with_rng(&mut StdRng::seed_from_u64(seed));
+            assert_eq!(brado::nis::validate(&nis), true);
+            assert_eq!(brado::nis::is_bare(&nis), true);
+            assert_eq!(
+                brado::nis::generate_with_rng(&mut StdRng::seed_from_u64(seed)),
+                nis,
+            );
+        }
+    }
+
+    #[test]
+    fn nis_generate_masked_with_rng_1() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut other = StdRng::seed_from_u64(42);
+        for _ in 0..1000 {
+            let nis = brado::nis::generate_masked_with_rng(&mut rng);
+            assert_eq!(brado::nis::validate(&nis), true);
+            assert_eq!(brado::nis::is_masked(&nis), true);
+            assert_eq!(brado::nis::generate_masked_with_rng(&mut other), nis);
+        }
+    }
 }
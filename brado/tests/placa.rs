@@ -0,0 +1,381 @@
+#[cfg(test)]
+mod placa_tests {
+    use brado;
+    use brado::common::{Document, MaskError, ValidationError};
+    use brado::docs::DocKind;
+    use brado::placa::{Placa, PlacaDocument, PlacaFormat};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    const VALID_PLACAS: [(&str, &str, PlacaFormat); 4] = [
+        ("ABC1234", "ABC-1234", PlacaFormat::Legacy),
+        ("ZZZ0000", "ZZZ-0000", PlacaFormat::Legacy),
+        ("ABC1C34", "ABC1C34", PlacaFormat::Mercosul),
+        ("RIO2A18", "RIO2A18", PlacaFormat::Mercosul),
+    ];
+
+    #[test]
+    fn placa_validate_1_valid_placas() {
+        for (bare, masked, _) in VALID_PLACAS {
+            assert_eq!(brado::placa::validate(bare), true);
+            assert_eq!(brado::placa::validate(masked), true);
+        }
+    }
+
+    #[test]
+    fn placa_validate_2_invalid_placas() {
+        let invalid_placas = [
+            "ABC123",
+            "ABC12345",
+            "AB12345",
+            "ABCD123",
+            "ABC12C4",
+            "ABCA234",
+            "abc1234",
+            "abc1c34",
+            "ABC-1C34",
+            "RIO-2A18",
+            "ABC-123",
+            "ABC--1234",
+            "AB-C1234",
+            "ABC1234-",
+            "ABC 1234",
+            "ABC:1234",
+            "1234567",
+        ];
+        for invalid_placa in invalid_placas {
+            assert_eq!(brado::placa::validate(invalid_placa), false);
+        }
+    }
+
+    #[test]
+    fn placa_check_1_valid_placas() {
+        for (bare, masked, _) in VALID_PLACAS {
+            assert_eq!(brado::placa::check(bare), Ok(()));
+            assert_eq!(brado::placa::check(masked), Ok(()));
+        }
+    }
+
+    #[test]
+    fn placa_check_2_invalid_placas() {
+        let invalid_placas = [
+            (
+                "ABC123",
+                ValidationError::InvalidLength {
+                    expected: 7,
+                    found: 6,
+                },
+            ),
+            (
+                "AB12345",
+                ValidationError::InvalidCharacter {
+                    position: 2,
+                    character: '1',
+                },
+            ),
+            (
+                "ABC12C4",
+                ValidationError::InvalidCharacter {
+                    position: 5,
+                    character: 'C',
+                },
+            ),
+            (
+                "ABC-12C4",
+                ValidationError::InvalidCharacter {
+                    position: 5,
+                    character: 'C',
+                },
+            ),
+            (
+                "abc1234",
+                ValidationError::InvalidCharacter {
+                    position: 0,
+                    character: 'a',
+                },
+            ),
+            ("AB-C1234", ValidationError::InvalidMask),
+            ("ABC-1C34", ValidationError::InvalidMask),
+        ];
+        for (invalid_placa, error) in invalid_placas {
+            assert_eq!(brado::placa::check(invalid_placa), Err(error));
+        }
+    }
+
+    #[test]
+    fn placa_is_bare_1_valid_bare() {
+        for (bare, _, _) in VALID_PLACAS {
+            assert_eq!(brado::placa::is_bare(bare), true);
+        }
+        assert_eq!(brado::placa::is_bare("1234ABC"), true);
+    }
+
+    #[test]
+    fn placa_is_bare_2_invalid_bare() {
+        let invalid_bares = ["ABC-1234", "ABC123", "ABC12345", "abc1234"];
+        for invalid_bare in invalid_bares {
+            assert_eq!(brado::placa::is_bare(invalid_bare), false);
+        }
+    }
+
+    #[test]
+    fn placa_is_masked_1_valid_masked() {
+        for (_, masked, format) in VALID_PLACAS {
+            assert_eq!(
+                brado::placa::is_masked(masked),
+                format == PlacaFormat::Legacy
+            );
+        }
+        assert_eq!(brado::placa::is_masked("123-45BC"), true);
+    }
+
+    #[test]
+    fn placa_is_masked_2_invalid_masked() {
+        let invalid_masked = [
+            "ABC1234",
+            "AB-C1234",
+            "ABC-123",
+            "ABC--1234",
+            "ABC.1234",
+            "ABC-1C34",
+        ];
+        for invalid_masked in invalid_masked {
+            assert_eq!(brado::placa::is_masked(invalid_masked), false);
+        }
+    }
+
+    #[test]
+    fn placa_mask_1_valid_mask() {
+        for (bare, masked, _) in VALID_PLACAS {
+            assert_eq!(brado::placa::mask(bare), Ok(String::from(masked)));
+            assert_eq!(brado::placa::unmask(masked), bare);
+        }
+    }
+
+    #[test]
+    fn placa_mask_2_invalid_mask() {
+        assert_eq!(
+            brado::placa::mask("ABC-1234"),
+            Err(MaskError::AlreadyMasked {
+                kind: DocKind::Placa,
+            }),
+        );
+        assert_eq!(
+            brado::placa::mask("ABC-1C34"),
+            Err(MaskError::InvalidLength {
+                kind: DocKind::Placa,
+                expected: 7,
+                found: 8,
+            }),
+        );
+        assert_eq!(
+            brado::placa::mask("ABC123"),
+            Err(MaskError::InvalidLength {
+                kind: DocKind::Placa,
+                expected: 7,
+                found: 6,
+            }),
+        );
+        assert_eq!(
+            brado::placa::mask("ABC123:"),
+            Err(MaskError::InvalidCharacter {
+                kind: DocKind::Placa,
+                position: 6,
+                character: ':',
+            }),
+        );
+    }
+
+    #[test]
+    fn placa_detect_format_1() {
+        for (bare, masked, format) in VALID_PLACAS {
+            assert_eq!(brado::placa::detect_format(bare), Some(format));
+            assert_eq!(brado::placa::detect_format(masked), Some(format));
+        }
+        assert_eq!(brado::placa::detect_format("ABC12C4"), None);
+    }
+
+    #[test]
+    fn placa_to_mercosul_1() {
+        let digits = "0123456789";
+        let letters = "ABCDEFGHIJ";
+        for (digit, letter) in digits.chars().zip(letters.chars()) {
+            let legacy = format!("ABC1{}34", digit);
+            let mercosul = format!("ABC1{}34", letter);
+            assert_eq!(
+                brado::placa::to_mercosul(&legacy),
+                Ok(mercosul.clone())
+            );
+            assert_eq!(brado::placa::to_legacy(&mercosul), Ok(legacy.clone()));
+            assert_eq!(
+                brado::placa::to_mercosul(&mercosul),
+                Ok(mercosul.clone()),
+            );
+            assert_eq!(brado::placa::to_legacy(&legacy), Ok(legacy));
+        }
+        assert_eq!(
+            brado::placa::to_mercosul("ABC-1234"),
+            Ok(String::from("ABC1C34")),
+        );
+        assert_eq!(
+            brado::placa::to_legacy("ABC1C34"),
+            Ok(String::from("ABC1234")),
+        );
+    }
+
+    #[test]
+    fn placa_to_mercosul_2_invalid() {
+        assert_eq!(
+            brado::placa::to_legacy("ABC1K34"),
+            Err(ValidationError::InvalidCharacter {
+                position: 4,
+                character: 'K',
+            }),
+        );
+        assert_eq!(
+            brado::placa::to_legacy("ABC1Z34"),
+            Err(ValidationError::InvalidCharacter {
+                position: 4,
+                character: 'Z',
+            }),
+        );
+        assert!(brado::placa::to_mercosul("AB12345").is_err());
+        assert!(brado::placa::to_legacy("ABC123").is_err());
+    }
+
+    #[test]
+    fn placa_newtype_1_valid() {
+        for (bare, masked, format) in VALID_PLACAS {
+            let placa: Placa = masked.parse().unwrap();
+            assert_eq!(
+                placa.to_mercosul().to_string(),
+                placa.to_mercosul().as_bare()
+            );
+            assert_eq!(placa.as_bare(), bare);
+            assert_eq!(placa.to_masked(), masked);
+            assert_eq!(placa.to_string(), masked);
+            assert_eq!(placa.format(), format);
+            assert_eq!(placa.to_mercosul().format(), PlacaFormat::Mercosul);
+            assert_eq!(Placa::try_from(bare), Ok(placa));
+        }
+    }
+
+    #[test]
+    fn placa_newtype_2_invalid() {
+        assert_eq!(
+            "AB12345".parse::<Placa>(),
+            Err(ValidationError::InvalidCharacter {
+                position: 2,
+                character: '1',
+            }),
+        );
+        assert!(Placa::try_from("ABC123").is_err());
+    }
+
+    #[test]
+    fn placa_document_1() {
+        assert_eq!(PlacaDocument.kind(), DocKind::Placa);
+        assert_eq!(PlacaDocument.name(), "Placa");
+        assert_eq!(PlacaDocument.bare_size(), 7);
+        assert_eq!(PlacaDocument.validate("ABC1C34"), true);
+        assert_eq!(PlacaDocument.validate("ABC-1C34"), false);
+        assert_eq!(brado::docs::is_placa("ABC-1234"), true);
+        assert_eq!(brado::docs::identify("ABC1C34"), vec![DocKind::Placa]);
+    }
+
+    #[test]
+    fn placa_generate_1() {
+        for _ in 0..1000 {
+            let placa = brado::placa::generate();
+            assert_eq!(brado::placa::validate(&placa), true);
+            assert_eq!(brado::placa::is_bare(&placa), true);
+        }
+    }
+
+    #[test]
+    fn placa_generate_masked_1() {
+        for _ in 0..1000 {
+            let placa = brado::placa::generate_masked();
+            assert_eq!(brado::placa::validate(&placa), true);
+            assert_eq!(
+                brado::placa::is_masked(&placa),
+                brado::placa::detect_format(&placa)
+                    == Some(PlacaFormat::Legacy),
+            );
+        }
+    }
+
+    #[test]
+    fn placa_generate_with_rng_1() {
+        let mut formats = vec![];
+        for seed in 0..100 {
+            let placa = brado::placa::generate_with_rng(
+                &mut StdRng::seed_from_u64(seed),
+            );
+            assert_eq!(brado::placa::validate(&placa), true);
+            assert_eq!(
+                brado::placa::generate_with_rng(&mut StdRng::seed_from_u64(
+                    seed
+                )),
+                placa,
+            );
+            formats.push(brado::placa::detect_format(&placa).unwrap());
+        }
+        for format in PlacaFormat::ALL {
+            assert!(formats.contains(format));
+        }
+    }
+
+    #[test]
+    fn placa_generate_masked_with_rng_1() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut other = StdRng::seed_from_u64(42);
+        for _ in 0..100 {
+            let placa = brado::placa::generate_masked_with_rng(&mut rng);
+            assert_eq!(brado::placa::validate(&placa), true);
+            assert_eq!(
+                brado::placa::is_masked(&placa),
+                brado::placa::detect_format(&placa)
+                    == Some(PlacaFormat::Legacy),
+            );
+            assert_eq!(
+                brado::placa::generate_masked_with_rng(&mut other),
+                placa,
+            );
+        }
+    }
+
+    #[test]
+    fn placa_generate_legacy_1() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..1000 {
+            let placa = brado::placa::generate_legacy_with_rng(&mut rng);
+            assert_eq!(
+                brado::placa::detect_format(&placa),
+                Some(PlacaFormat::Legacy),
+            );
+            let placa = brado::placa::generate_legacy();
+            assert_eq!(
+                brado::placa::detect_format(&placa),
+                Some(PlacaFormat::Legacy),
+            );
+        }
+    }
+
+    #[test]
+    fn placa_generate_mercosul_1() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..1000 {
+            let placa = brado::placa::generate_mercosul_with_rng(&mut rng);
+            assert_eq!(
+                brado::placa::detect_format(&placa),
+                Some(PlacaFormat::Mercosul),
+            );
+            let placa = brado::placa::generate_mercosul();
+            assert_eq!(
+                brado::placa::detect_format(&placa),
+                Some(PlacaFormat::Mercosul),
+            );
+        }
+    }
+}
@@ -1,6 +1,10 @@
 #[cfg(test)]
 mod renavam_tests {
     use brado;
+    use brado::common::{MaskError, ValidationError};
+    use brado::docs::DocKind;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
 
     #[test]
     fn renavam_validate_1_valid_renavams() {
@@ -30,6 +34,88 @@ mod renavam_tests {
         }
     }
 
+    #[test]
+    fn renavam_check_1_valid_renavams() {
+        let valid_renavams = ["79072338363", "7907233836-3"];
+        for valid_renavam in valid_renavams {
+            assert_eq!(brado::renavam::check(valid_renavam), Ok(()));
+        }
+    }
+
+    #[test]
+    fn renavam_check_2_invalid_renavams() {
+        let invalid_renavams = [
+            (
+                "7907233836",
+                ValidationError::InvalidLength {
+                    expected: 11,
+                    found: 10,
+                },
+            ),
+            (
+                "79072338362",
+                ValidationError::CheckDigitMismatch {
+                    expected: String::from("3"),
+                    found: String::from("2"),
+                },
+            ),
+            ("7907233836:3", ValidationError::InvalidMask),
+            (
+                "7907233836A",
+                ValidationError::InvalidCharacter {
+                    position: 10,
+                    character: 'A',
+                },
+            ),
+        ];
+        for (invalid_renavam, error) in invalid_renavams {
+            assert_eq!(brado::renavam::check(invalid_renavam), Err(error));
+        }
+    }
+
+    #[test]
+    fn renavam_compute_check_digits_1_valid_bases() {
+        let bases = [("7907233836", "3")];
+        for (base, check_digits) in bases {
+            assert_eq!(
+                brado::renavam::compute_check_digits(base),
+                Ok(String::from(check_digits)),
+            );
+        }
+
+        for _ in 0..1000 {
+            let renavam = brado::renavam::generate();
+            let (base, check_digits) = renavam.split_at(renavam.len() - 1);
+            assert_eq!(
+                brado::renavam::compute_check_digits(base),
+                Ok(String::from(check_digits)),
+            );
+        }
+    }
+
+    #[test]
+    fn renavam_compute_check_digits_2_invalid_bases() {
+        let invalid_bases = [
+            (
+                "790723383",
+                ValidationError::InvalidLength {
+                    expected: 10,
+                    found: 9,
+                },
+            ),
+            (
+                "790723383A",
+                ValidationError::InvalidCharacter {
+                    position: 9,
+                    character: 'A',
+                },
+            ),
+        ];
+        for (base, error) in invalid_bases {
+            assert_eq!(brado::renavam::compute_check_digits(base), Err(error));
+        }
+    }
+
     #[test]
     fn renavam_is_bare_1_valid_bare() {
         let documents = [
@@ -100,16 +186,67 @@ mod renavam_tests {
     #[test]
     fn renavam_mask_2_invalid_mask() {
         let documents = [
-            "7907233836-3", // Valid RENAVAM
-            "7907233836-2", // Invalid RENAVAM
-            "7907233836",   // Invalid RENAVAM
-            "790723383632", // Invalid RENAVAM
+            (
+                "7907233836-3", // Valid RENAVAM
+                MaskError::AlreadyMasked {
+                    kind: DocKind::Renavam,
+                },
+            ),
+            (
+                "7907233836-2", // Invalid RENAVAM
+                MaskError::AlreadyMasked {
+                    kind: DocKind::Renavam,
+                },
+            ),
+            (
+                "7907233836", // Invalid RENAVAM
+                MaskError::InvalidLength {
+                    kind: DocKind::Renavam,
+                    expected: 11,
+                    found: 10,
+                },
+            ),
+            (
+                "790723383632", // Invalid RENAVAM
+                MaskError::InvalidLength {
+                    kind: DocKind::Renavam,
+                    expected: 11,
+                    found: 12,
+                },
+            ),
+            (
+                "7907233836?", // Invalid RENAVAM
+                MaskError::InvalidCharacter {
+                    kind: DocKind::Renavam,
+                    position: 10,
+                    character: '?',
+                },
+            ),
         ];
+        for (document, error) in documents {
+            assert_eq!(brado::renavam::mask(document), Err(error));
+        }
+    }
+
+    #[test]
+    fn renavam_newtype_1_valid() {
+        let documents = ["79072338363", "7907233836-3"];
+        for document in documents {
+            let doc: brado::renavam::Renavam = document.parse().unwrap();
+            assert_eq!(doc.as_bare(), "79072338363");
+            assert_eq!(doc.to_masked(), "7907233836-3");
+            assert_eq!(doc.to_string(), "7907233836-3");
+            assert_eq!(brado::renavam::Renavam::try_from(document), Ok(doc));
+        }
+    }
+
+    #[test]
+    fn renavam_newtype_2_invalid() {
+        let documents = ["79072338362", "7907233836-2", "7907233836"];
         for document in documents {
-            let result = brado::renavam::mask(document);
             assert_eq!(
-                result,
-                Err("The given string cannot be masked as RENAVAM!"),
+                document.parse::<brado::renavam::Renavam>(),
+                Err(brado::renavam::check(document).unwrap_err()),
             );
         }
     }
@@ -131,4 +268,110 @@ mod renavam_tests {
             assert_eq!(brado::renavam::is_masked(&renavam), true);
         }
     }
+
+    #[test]
+    fn renavam_generate_with_rng_1() {
+        for seed in 0..1000 {
+            let renavam = brado::renavam::generate_with_rng(
+                &mut StdRng::seed_from_u64(seed),
+            );
+            assert_eq!(brado::renavam::validate(&renavam), true);
+            assert_eq!(brado::renavam::is_bare(&renavam), true);
+            assert_eq!(
+                brado::renavam::generate_with_rng(&mut StdRng::seed_from_u64(
+                    seed
+                )),
+                renavam,
+            );
+        }
+    }
+
+    #[test]
+    fn renavam_generate_masked_with_rng_1() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut other = StdRng::seed_from_u64(42);
+        for _ in 0..1000 {
+            let renavam = brado::renavam::generate_masked_with_rng(&mut rng);
+            assert_eq!(brado::renavam::validate(&renavam), true);
+            assert_eq!(brado::renavam::is_masked(&renavam), true);
+            assert_eq!(
+                brado::renavam::generate_masked_with_rng(&mut other),
+                renavam
+            );
+        }
+    }
+
+    #[test]
+    fn renavam_normalize_1() {
+        let valid_renavams = [
+            ("639920578", "00639920578"),
+            ("00639920578", "00639920578"),
+            ("0063992057-8", "00639920578"),
+            ("79072338363", "79072338363"),
+            ("7907233836-3", "79072338363"),
+        ];
+        for (renavam, normalized) in valid_renavams {
+            assert_eq!(
+                brado::renavam::normalize(renavam),
+                Ok(String::from(normalized)),
+            );
+            assert_eq!(brado::renavam::validate_lenient(renavam), true);
+            assert_eq!(brado::renavam::check_lenient(renavam), Ok(()));
+        }
+    }
+
+    #[test]
+    fn renavam_normalize_2_invalid() {
+        assert_eq!(
+            brado::renavam::normalize("639920579"),
+            Err(ValidationError::CheckDigitMismatch {
+                expected: String::from("8"),
+                found: String::from("9"),
+            }),
+        );
+        assert_eq!(
+            brado::renavam::normalize("63992057A"),
+            Err(ValidationError::InvalidCharacter {
+                position: 8,
+                character: 'A',
+            }),
+        );
+        assert_eq!(
+            brado::renavam::normalize("6399205780"),
+            Err(ValidationError::InvalidLength {
+                expected: 11,
+                found: 10,
+            }),
+        );
+        assert_eq!(
+            brado::renavam::normalize("6399205"),
+            Err(ValidationError::InvalidLength {
+                expected: 9,
+                found: 7,
+            }),
+        );
+        assert_eq!(
+            brado::renavam::normalize(""),
+            Err(ValidationError::InvalidLength {
+                expected: 9,
+                found: 0,
+            }),
+        );
+        assert_eq!(brado::renavam::validate_lenient("639920579"), false);
+        assert_eq!(brado::renavam::validate("639920578"), false);
+    }
+
+    #[test]
+    fn renavam_generate_legacy_1() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..1000 {
+            let renavam = brado::renavam::generate_legacy_with_rng(&mut rng);
+            assert_eq!(renavam.len(), 9);
+            assert_eq!(brado::renavam::validate_lenient(&renavam), true);
+            assert_eq!(
+                brado::renavam::normalize(&renavam),
+                Ok(format!("00{}", renavam)),
+            );
+        }
+    }
 }
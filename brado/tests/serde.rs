@@ -0,0 +1,126 @@
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use brado;
+    use brado::common::{MaskError, Uf, ValidationError};
+    use brado::docs::DocKind;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Pessoa {
+        cpf: brado::cpf::Cpf,
+        #[serde(with = "brado::masked")]
+        cnpj: brado::cnpj::Cnpj,
+    }
+
+    #[test]
+    fn serde_serialize_1_bare() {
+        let cpf: brado::cpf::Cpf = "639.292.470-11".parse().unwrap();
+        assert_eq!(serde_json::to_string(&cpf).unwrap(), r#""63929247011""#);
+
+        let cns: brado::cns::Cns = "144 0826 2726 0004".parse().unwrap();
+        assert_eq!(
+            serde_json::to_string(&cns).unwrap(),
+            r#""144082627260004""#
+        );
+    }
+
+    #[test]
+    fn serde_serialize_2_masked() {
+        let pessoa = Pessoa {
+            cpf: "63929247011".parse().unwrap(),
+            cnpj: "05200851000100".parse().unwrap(),
+        };
+        assert_eq!(
+            serde_json::to_string(&pessoa).unwrap(),
+            r#"{"cpf":"63929247011","cnpj":"05.200.851/0001-00"}"#,
+        );
+    }
+
+    #[test]
+    fn serde_deserialize_1_valid() {
+        let documents = [r#""63929247011""#, r#""639.292.470-11""#];
+        for document in documents {
+            let cpf: brado::cpf::Cpf = serde_json::from_str(document).unwrap();
+            assert_eq!(cpf.as_bare(), "63929247011");
+        }
+
+        let json = r#"{"cpf":"639.292.470-11","cnpj":"05200851000100"}"#;
+        let pessoa: Pessoa = serde_json::from_str(json).unwrap();
+        assert_eq!(pessoa.cpf.as_bare(), "63929247011");
+        assert_eq!(pessoa.cnpj.as_bare(), "05200851000100");
+    }
+
+    #[test]
+    fn serde_deserialize_2_invalid() {
+        let result =
+            serde_json::from_str::<brado::cpf::Cpf>(r#""639.292.470-10""#);
+        let error = ValidationError::CheckDigitMismatch {
+            expected: String::from("11"),
+            found: String::from("10"),
+        };
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .starts_with(&error.to_string()));
+
+        let json = r#"{"cpf":"63929247011","cnpj":"05.200.851/0001-01"}"#;
+        assert!(serde_json::from_str::<Pessoa>(json).is_err());
+
+        let result =
+            serde_json::from_str::<brado::nis::Nis>(r#""40865658046""#);
+        let error = ValidationError::CheckDigitMismatch {
+            expected: String::from("7"),
+            found: String::from("6"),
+        };
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .starts_with(&error.to_string()));
+    }
+
+    #[test]
+    fn serde_errors() {
+        let error = ValidationError::InvalidLength {
+            expected: 11,
+            found: 10,
+        };
+        let json = serde_json::to_string(&error).unwrap();
+        assert_eq!(json, r#"{"InvalidLength":{"expected":11,"found":10}}"#);
+        assert_eq!(
+            serde_json::from_str::<ValidationError>(&json).unwrap(),
+            error
+        );
+
+        let error = MaskError::AlreadyMasked { kind: DocKind::Cpf };
+        let json = serde_json::to_string(&error).unwrap();
+        assert_eq!(json, r#"{"AlreadyMasked":{"kind":"Cpf"}}"#);
+        assert_eq!(serde_json::from_str::<MaskError>(&json).unwrap(), error);
+    }
+
+    #[test]
+    fn serde_uf() {
+        for &uf in Uf::ALL {
+            let json = serde_json::to_string(&uf).unwrap();
+            assert_eq!(json, format!(r#""{}""#, uf.sigla()));
+            assert_eq!(serde_json::from_str::<Uf>(&json).unwrap(), uf);
+        }
+    }
+
+    #[test]
+    fn serde_ie() {
+        let ie = brado::ie::Ie::new(Uf::Sp, "110.042.490.114").unwrap();
+        let json = serde_json::to_string(&ie).unwrap();
+        assert_eq!(json, r#"{"uf":"SP","ie":"110042490114"}"#);
+        assert_eq!(serde_json::from_str::<brado::ie::Ie>(&json).unwrap(), ie);
+
+        let json = r#"{"uf":"MG","ie":"110042490114"}"#;
+        assert!(serde_json::from_str::<brado::ie::Ie>(json).is_err());
+
+        let error = MaskError::AlreadyMasked {
+            kind: DocKind::Ie(Uf::Sp),
+        };
+        let json = serde_json::to_string(&error).unwrap();
+        assert_eq!(json, r#"{"AlreadyMasked":{"kind":{"Ie":"SP"}}}"#);
+        assert_eq!(serde_json::from_str::<MaskError>(&json).unwrap(), error);
+    }
+}
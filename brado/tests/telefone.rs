@@ -0,0 +1,423 @@
+#[cfg(test)]
+mod telefone_tests {
+    use brado;
+    use brado::common::{Document, MaskError, Uf, ValidationError};
+    use brado::docs::DocKind;
+    use brado::telefone::{Telefone, TelefoneDocument, TelefoneKind};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    const VALID_TELEFONES: [(&str, &str, TelefoneKind); 8] = [
+        ("11912345678", "(11) 91234-5678", TelefoneKind::Mobile),
+        ("21987654321", "(21) 98765-4321", TelefoneKind::Mobile),
+        ("99991234567", "(99) 99123-4567", TelefoneKind::Mobile),
+        ("1131234567", "(11) 3123-4567", TelefoneKind::Landline),
+        ("6125550000", "(61) 2555-0000", TelefoneKind::Landline),
+        ("4854321098", "(48) 5432-1098", TelefoneKind::Landline),
+        ("08001234567", "0800 123 4567", TelefoneKind::TollFree),
+        ("03007654321", "0300 765 4321", TelefoneKind::SharedCost),
+    ];
+
+    #[test]
+    fn telefone_validate_1_valid_telefones() {
+        for (bare, masked, kind) in VALID_TELEFONES {
+            assert_eq!(brado::telefone::validate(bare), true);
+            assert_eq!(brado::telefone::validate(masked), true);
+            if kind.is_geographic() {
+                let e164 = format!("+55{}", bare);
+                let international = format!("+55 {}", masked);
+                assert_eq!(brado::telefone::validate(&e164), true);
+                assert_eq!(brado::telefone::validate(&international), true);
+            }
+        }
+    }
+
+    #[test]
+    fn telefone_validate_2_invalid_telefones() {
+        let invalid_telefones = [
+            "10912345678",
+            "(20) 91234-5678",
+            "(11) 81234-5678",
+            "(11) 1234-5678",
+            "(11) 6123-4567",
+            "(11) 9123-4567",
+            "1191234567",
+            "119123456789",
+            "119123456",
+            "(11) 9123-45678",
+            "(11) 91234 5678",
+            "11-91234-5678",
+            "(11)  91234-5678",
+            "0900 123 4567",
+            "0800 1234 567",
+            "0800123456",
+            "+5508001234567",
+            "+55 0800 123 4567",
+            "+55(11) 91234-5678",
+            "+1 (11) 91234-5678",
+            "(11) 91234-567A",
+        ];
+        for invalid_telefone in invalid_telefones {
+            assert_eq!(brado::telefone::validate(invalid_telefone), false);
+        }
+    }
+
+    #[test]
+    fn telefone_validate_3_alternative_masks() {
+        let telefones = [
+            ("(11)91234-5678", "11912345678"),
+            ("11 91234-5678", "11912345678"),
+            ("(11)3123-4567", "1131234567"),
+            ("11 3123-4567", "1131234567"),
+            ("+55 11912345678", "11912345678"),
+            ("+55 1131234567", "1131234567"),
+            ("+55 (11)91234-5678", "11912345678"),
+            ("+55 11 91234-5678", "11912345678"),
+        ];
+        for (telefone, bare) in telefones {
+            assert_eq!(brado::telefone::validate(telefone), true);
+            assert_eq!(brado::telefone::is_masked(telefone), true);
+            assert_eq!(brado::telefone::unmask(telefone), bare);
+        }
+    }
+
+    #[test]
+    fn telefone_check_1_valid_telefones() {
+        for (bare, masked, _) in VALID_TELEFONES {
+            assert_eq!(brado::telefone::check(bare), Ok(()));
+            assert_eq!(brado::telefone::check(masked), Ok(()));
+        }
+    }
+
+    #[test]
+    fn telefone_check_2_invalid_telefones() {
+        let invalid_telefones = [
+            (
+                "(20) 91234-5678",
+                ValidationError::InvalidPrefix {
+                    found: String::from("20"),
+                },
+            ),
+            (
+                "0900 123 4567",
+                ValidationError::InvalidPrefix {
+                    found: String::from("0900"),
+                },
+            ),
+            (
+                "+5508001234567",
+                ValidationError::InvalidPrefix {
+                    found: String::from("0800"),
+                },
+            ),
+            (
+                "(11) 81234-5678",
+                ValidationError::InvalidFirstDigit { found: 8 },
+            ),
+            (
+                "(11) 6123-4567",
+                ValidationError::InvalidFirstDigit { found: 6 },
+            ),
+            (
+                "0800123456",
+                ValidationError::InvalidLength {
+                    expected: 11,
+                    found: 10,
+                },
+            ),
+            (
+                "119123456",
+                ValidationError::InvalidLength {
+                    expected: 11,
+                    found: 9,
+                },
+            ),
+            (
+                "+55 (11) 91234-567A",
+                ValidationError::InvalidCharacter {
+                    position: 18,
+                    character: 'A',
+                },
+            ),
+            ("(11) 9123-45678", ValidationError::InvalidMask),
+            ("11-91234-5678", ValidationError::InvalidMask),
+            ("+55(11) 91234-5678", ValidationError::InvalidMask),
+        ];
+        for (invalid_telefone, error) in invalid_telefones {
+            assert_eq!(brado::telefone::check(invalid_telefone), Err(error));
+        }
+    }
+
+    #[test]
+    fn telefone_is_bare_1_valid_bare() {
+        for (bare, _, _) in VALID_TELEFONES {
+            assert_eq!(brado::telefone::is_bare(bare), true);
+        }
+        assert_eq!(brado::telefone::is_bare("10912345678"), true);
+        assert_eq!(brado::telefone::is_bare("+5511912345678"), true);
+        assert_eq!(brado::telefone::is_bare("+551131234567"), true);
+    }
+
+    #[test]
+    fn telefone_is_bare_2_invalid_bare() {
+        let invalid_bares = [
+            "(11) 91234-5678",
+            "+55 11912345678",
+            "+5508001234567",
+            "119123456",
+            "119123456789",
+        ];
+        for invalid_bare in invalid_bares {
+            assert_eq!(brado::telefone::is_bare(invalid_bare), false);
+        }
+    }
+
+    #[test]
+    fn telefone_is_masked_1_valid_masked() {
+        for (_, masked, _) in VALID_TELEFONES {
+            assert_eq!(brado::telefone::is_masked(masked), true);
+        }
+        assert_eq!(brado::telefone::is_masked("+55 11912345678"), true);
+        assert_eq!(brado::telefone::is_masked("+55 (11) 3123-4567"), true);
+        assert_eq!(brado::telefone::is_masked("(10) 81234-5678"), true);
+    }
+
+    #[test]
+    fn telefone_is_masked_2_invalid_masked() {
+        let invalid_masked = [
+            "11912345678",
+            "(11) 9123-45678",
+            "0800-123-4567",
+            "+5511912345678",
+        ];
+        for invalid_masked in invalid_masked {
+            assert_eq!(brado::telefone::is_masked(invalid_masked), false);
+        }
+    }
+
+    #[test]
+    fn telefone_mask_1_valid_mask() {
+        for (bare, masked, _) in VALID_TELEFONES {
+            assert_eq!(brado::telefone::mask(bare), Ok(String::from(masked)));
+            assert_eq!(brado::telefone::unmask(masked), bare);
+        }
+        assert_eq!(
+            brado::telefone::unmask("+55 (11) 91234-5678"),
+            "11912345678",
+        );
+        assert_eq!(brado::telefone::unmask("+5511912345678"), "11912345678");
+        assert_eq!(
+            brado::telefone::mask("+5511912345678"),
+            Ok(String::from("+55 (11) 91234-5678")),
+        );
+        assert_eq!(
+            brado::telefone::mask("+551131234567"),
+            Ok(String::from("+55 (11) 3123-4567")),
+        );
+    }
+
+    #[test]
+    fn telefone_mask_2_invalid_mask() {
+        for masked in ["(11) 91234-5678", "11 91234-5678", "+55 11912345678"] {
+            assert_eq!(
+                brado::telefone::mask(masked),
+                Err(MaskError::AlreadyMasked {
+                    kind: DocKind::Telefone,
+                }),
+            );
+        }
+        assert_eq!(
+            brado::telefone::mask("119123456"),
+            Err(MaskError::InvalidLength {
+                kind: DocKind::Telefone,
+                expected: 11,
+                found: 9,
+            }),
+        );
+        assert_eq!(
+            brado::telefone::mask("1191234567A"),
+            Err(MaskError::InvalidCharacter {
+                kind: DocKind::Telefone,
+                position: 10,
+                character: 'A',
+            }),
+        );
+    }
+
+    #[test]
+    fn telefone_kind_1() {
+        for (bare, masked, kind) in VALID_TELEFONES {
+            assert_eq!(brado::telefone::kind(bare), Some(kind));
+            assert_eq!(brado::telefone::kind(masked), Some(kind));
+        }
+        assert_eq!(brado::telefone::kind("(11) 81234-5678"), None);
+    }
+
+    #[test]
+    fn telefone_ddd_and_uf_1() {
+        let expected = [
+            (Some(11), Some(Uf::Sp)),
+            (Some(21), Some(Uf::Rj)),
+            (Some(99), Some(Uf::Ma)),
+            (Some(11), Some(Uf::Sp)),
+            (Some(61), Some(Uf::Df)),
+            (Some(48), Some(Uf::Sc)),
+            (None, None),
+            (None, None),
+        ];
+        for ((_, masked, _), (ddd, uf)) in VALID_TELEFONES.iter().zip(expected)
+        {
+            assert_eq!(brado::telefone::ddd(masked), ddd);
+            assert_eq!(brado::telefone::uf(masked), uf);
+        }
+        assert_eq!(brado::telefone::ddd("(20) 91234-5678"), None);
+    }
+
+    #[test]
+    fn telefone_to_e164_1() {
+        for (bare, masked, kind) in VALID_TELEFONES {
+            let result = brado::telefone::to_e164(masked);
+            if kind.is_geographic() {
+                assert_eq!(result, Ok(format!("+55{}", bare)));
+                assert_eq!(
+                    brado::telefone::to_e164(&result.unwrap()),
+                    Ok(format!("+55{}", bare))
+                );
+            } else {
+                assert_eq!(
+                    result,
+                    Err(ValidationError::InvalidPrefix {
+                        found: String::from(&bare[..4]),
+                    }),
+                );
+            }
+        }
+        assert_eq!(
+            brado::telefone::to_e164("(11) 81234-5678"),
+            Err(ValidationError::InvalidFirstDigit { found: 8 }),
+        );
+    }
+
+    #[test]
+    fn telefone_newtype_1_valid() {
+        for (bare, masked, kind) in VALID_TELEFONES {
+            let telefone: Telefone = masked.parse().unwrap();
+            assert_eq!(telefone.as_bare(), bare);
+            assert_eq!(telefone.to_masked(), masked);
+            assert_eq!(telefone.to_string(), masked);
+            assert_eq!(telefone.kind(), kind);
+            assert_eq!(telefone.ddd().is_some(), kind.is_geographic());
+            assert_eq!(telefone.to_e164().is_some(), kind.is_geographic());
+            assert_eq!(Telefone::try_from(bare), Ok(telefone));
+        }
+        let telefone: Telefone = "+55 (11) 91234-5678".parse().unwrap();
+        assert_eq!(telefone.as_bare(), "11912345678");
+    }
+
+    #[test]
+    fn telefone_newtype_2_invalid() {
+        assert_eq!(
+            "(11) 81234-5678".parse::<Telefone>(),
+            Err(ValidationError::InvalidFirstDigit { found: 8 }),
+        );
+        assert!(Telefone::try_from("119123456").is_err());
+    }
+
+    #[test]
+    fn telefone_document_1() {
+        assert_eq!(TelefoneDocument.kind(), DocKind::Telefone);
+        assert_eq!(TelefoneDocument.name(), "Telefone");
+        assert_eq!(TelefoneDocument.bare_size(), 11);
+        assert_eq!(TelefoneDocument.validate("(11) 91234-5678"), true);
+        assert_eq!(brado::docs::is_telefone("(11) 91234-5678"), true);
+        assert_eq!(DocKind::ALL.contains(&DocKind::Telefone), false);
+    }
+
+    #[test]
+    fn telefone_generate_1() {
+        for _ in 0..1000 {
+            let telefone = brado::telefone::generate();
+            assert_eq!(brado::telefone::validate(&telefone), true);
+            assert_eq!(brado::telefone::is_bare(&telefone), true);
+        }
+    }
+
+    #[test]
+    fn telefone_generate_masked_1() {
+        for _ in 0..1000 {
+            let telefone = brado::telefone::generate_masked();
+            assert_eq!(brado::telefone::validate(&telefone), true);
+            assert_eq!(brado::telefone::is_masked(&telefone), true);
+        }
+    }
+
+    #[test]
+    fn telefone_generate_with_rng_1() {
+        for seed in 0..100 {
+            let telefone = brado::telefone::generate_with_rng(
+                &mut StdRng::seed_from_u64(seed),
+            );
+            assert_eq!(brado::telefone::validate(&telefone), true);
+            assert_eq!(
+                brado::telefone::generate_with_rng(&mut StdRng::seed_from_u64(
+                    seed
+                )),
+                telefone,
+            );
+        }
+    }
+
+    #[test]
+    fn telefone_generate_masked_with_rng_1() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut other = StdRng::seed_from_u64(42);
+        for _ in 0..100 {
+            let telefone = brado::telefone::generate_masked_with_rng(&mut rng);
+            assert_eq!(brado::telefone::is_masked(&telefone), true);
+            assert_eq!(
+                brado::telefone::generate_masked_with_rng(&mut other),
+                telefone,
+            );
+        }
+    }
+
+    #[test]
+    fn telefone_generate_for_ddd_1() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for ddd in 11..=99 {
+            let result =
+                brado::telefone::generate_for_ddd_with_rng(ddd, &mut rng);
+            match result {
+                Ok(telefone) => {
+                    assert_eq!(brado::telefone::ddd(&telefone), Some(ddd));
+                    let telefone =
+                        brado::telefone::generate_for_ddd(ddd).unwrap();
+                    assert_eq!(brado::telefone::validate(&telefone), true);
+                }
+                Err(error) => assert_eq!(
+                    error,
+                    ValidationError::InvalidPrefix {
+                        found: ddd.to_string(),
+                    },
+                ),
+            }
+        }
+        assert!(brado::telefone::generate_for_ddd(20).is_err());
+        assert!(brado::telefone::generate_for_ddd(0).is_err());
+    }
+
+    #[test]
+    fn telefone_generate_for_kind_1() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for &kind in TelefoneKind::ALL {
+            for _ in 0..100 {
+                let telefone =
+                    brado::telefone::generate_for_kind_with_rng(kind, &mut rng);
+                assert_eq!(brado::telefone::kind(&telefone), Some(kind));
+
+                let telefone = brado::telefone::generate_for_kind(kind);
+                assert_eq!(brado::telefone::kind(&telefone), Some(kind));
+            }
+        }
+    }
+}